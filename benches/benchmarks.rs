@@ -1,23 +1,71 @@
 #![feature(test)]
 
-#[macro_use]
 extern crate fc_sort;
 extern crate rand;
 extern crate test;
 
+use fc_sort::merge;
 use fc_sort::merge_sort;
-use rand::random;
+use fc_sort::merge_sort_in_place;
+#[cfg(feature = "rayon")]
+use fc_sort::merge_sort_par;
+use fc_sort::merge_sort_with_buffer;
+use fc_sort::pdq_sort;
+use fc_sort::radix_sort;
+use fc_sort::random_tfvec;
+use fc_sort::top_k_largest;
+use fc_sort::TotalFloat;
 use test::Bencher;
 
+/// Leaves a list of TotalFloats in random order.
+fn shuffled(list: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    list
+}
+
+/// Puts a list of TotalFloats in ascending order.
+fn ascending(list: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    merge_sort(list)
+}
+
+/// Puts a list of TotalFloats in descending order.
+fn descending(list: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    let mut list = merge_sort(list);
+    list.reverse();
+    list
+}
+
+/// Puts a list of TotalFloats into ten ascending runs of overlapping values.
+fn sawtooth(list: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    let sorted = merge_sort(list);
+    (0..10)
+        .flat_map(|run| sorted.iter().skip(run).step_by(10).cloned())
+        .collect()
+}
+
+/// Mixes negative values, zeros of both signs and NaNs into a list of
+/// TotalFloats, so that no class of value is predictable.
+fn mixed(list: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    list.into_iter()
+        .map(|x| match (x.to_bits() % 5, x.to_bits() % 2) {
+            (0, _) => TotalFloat::NAN,
+            (1, 0) => TotalFloat::ZERO,
+            (1, _) => TotalFloat::NEG_ZERO,
+            (2, _) => -x,
+            _ => x,
+        })
+        .collect()
+}
+
 /// Defines a macro that generates code specifying benchmarks
 macro_rules! sort_n {
-    ( $($i:ident => $e:expr),+ ) => { $(
-        /// Benchmarks the sorting of a list of $e elements of TotalFloats.
+    ( $order:ident; $($i:ident => $e:expr),+ ) => { $(
+        /// Benchmarks the sorting of a list of $e elements of TotalFloats,
+        /// initially in the given order.
         #[bench]
         fn $i(b: &mut Bencher) {
             // Create list of $e totalfloats
-            let list: Vec<_> = (0..$e).map(|_| tf!(random::<f64>())).collect();
-            
+            let list = $order(random_tfvec(&mut rand::rng(), $e));
+
             // Benchmark the merge sort
             b.iter(|| {
                 merge_sort(list.clone())
@@ -28,8 +76,11 @@ macro_rules! sort_n {
 
 // Generates the benchmark functions for all specified amounts.
 sort_n! {
+    shuffled;
     sort_000_000 => 0,
     sort_000_001 => 1,
+    sort_001_000 => 1_000,
+    sort_010_000 => 10_000,
     sort_100_000 => 100_000,
     sort_200_000 => 200_000,
     sort_300_000 => 300_000,
@@ -40,3 +91,140 @@ sort_n! {
     sort_800_000 => 800_000,
     sort_900_000 => 900_000
 }
+
+// Generates the benchmark functions for presorted input, which should take
+// near-linear time.
+sort_n! {
+    ascending;
+    ascending_100_000 => 100_000,
+    ascending_500_000 => 500_000,
+    ascending_900_000 => 900_000
+}
+
+sort_n! {
+    sawtooth;
+    sawtooth_100_000 => 100_000,
+    sawtooth_500_000 => 500_000,
+    sawtooth_900_000 => 900_000
+}
+
+sort_n! {
+    mixed;
+    mixed_100_000 => 100_000,
+    mixed_500_000 => 500_000,
+    mixed_900_000 => 900_000
+}
+
+sort_n! {
+    descending;
+    descending_100_000 => 100_000,
+    descending_500_000 => 500_000,
+    descending_900_000 => 900_000
+}
+
+/// Benchmarks merging a sorted list of 10 TotalFloats with one of 1,000,000,
+/// most of which is a tail left after the short list runs out.
+#[bench]
+fn merge_10_with_1_000_000(b: &mut Bencher) {
+    let short = merge_sort(random_tfvec(&mut rand::rng(), 10));
+    let long = merge_sort(random_tfvec(&mut rand::rng(), 1_000_000));
+    b.iter(|| {
+        merge(short.clone(), long.clone())
+    });
+}
+
+/// Benchmarks finding the greatest 100 of 1,000,000 TotalFloats by a bounded
+/// heap, to compare with sorting them all in sort_full_1_000_000.
+#[bench]
+fn top_k_100_of_1_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 1_000_000);
+    b.iter(|| {
+        top_k_largest(list.iter().cloned(), 100)
+    });
+}
+
+/// Benchmarks finding the greatest 100 of 1,000,000 TotalFloats by sorting
+/// them all.
+#[bench]
+fn sort_full_1_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 1_000_000);
+    b.iter(|| {
+        merge_sort(list.clone()).split_off(1_000_000 - 100)
+    });
+}
+
+/// Benchmarks sorting 1,000,000 TotalFloats, allocating a scratch buffer for
+/// each sort.
+#[bench]
+fn sort_1_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 1_000_000);
+    let mut work = list.clone();
+    b.iter(|| {
+        work.copy_from_slice(&list);
+        merge_sort_in_place(&mut work);
+    });
+}
+
+/// Benchmarks sorting 1,000,000 TotalFloats, reusing one scratch buffer.
+#[bench]
+fn sort_with_buffer_1_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 1_000_000);
+    let mut work = list.clone();
+    let mut scratch = Vec::new();
+    b.iter(|| {
+        work.copy_from_slice(&list);
+        merge_sort_with_buffer(&mut work, &mut scratch);
+    });
+}
+
+/// Benchmarks sorting 1,000,000 TotalFloats in place without allocating, to
+/// compare with sort_1_000_000.
+#[bench]
+fn pdq_sort_1_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 1_000_000);
+    let mut work = list.clone();
+    b.iter(|| {
+        work.copy_from_slice(&list);
+        pdq_sort(&mut work);
+    });
+}
+
+/// Benchmarks the radix sort of 1,000,000 TotalFloats, to compare with
+/// sort_full_1_000_000.
+#[bench]
+fn radix_sort_1_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 1_000_000);
+    b.iter(|| {
+        radix_sort(list.clone())
+    });
+}
+
+/// Benchmarks the radix sort of 10,000,000 TotalFloats, to compare with
+/// seq_sort_10_000_000.
+#[bench]
+fn radix_sort_10_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 10_000_000);
+    b.iter(|| {
+        radix_sort(list.clone())
+    });
+}
+
+/// Benchmarks the sequential sort of 10,000,000 TotalFloats, to compare with
+/// par_sort_10_000_000.
+#[bench]
+fn seq_sort_10_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 10_000_000);
+    b.iter(|| {
+        merge_sort(list.clone())
+    });
+}
+
+/// Benchmarks the parallel sort of 10,000,000 TotalFloats.
+#[cfg(feature = "rayon")]
+#[bench]
+fn par_sort_10_000_000(b: &mut Bencher) {
+    let list = random_tfvec(&mut rand::rng(), 10_000_000);
+    b.iter(|| {
+        merge_sort_par(list.clone())
+    });
+}
@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use count_inversions;
+use extrema_non_nan;
+use merge_sort;
+use nan_count;
+use TotalFloat;
+
+// The most values sampled for the statistics that are not linear.
+const SAMPLE_LEN: usize = 256;
+
+/// A profile of how sorted a slice of TotalFloat values is, from `analyze`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SortednessReport {
+    /// The number of values.
+    pub len: usize,
+    /// The number of maximal ascending runs, which is 1 for sorted input and
+    /// the length for input in strictly descending order, or 0 if empty.
+    pub runs: usize,
+    /// The length of the longest ascending run.
+    pub longest_run: usize,
+    /// The estimated number of inversions, those pairs of values where the
+    /// earlier is greater, scaled up from those of the sample.
+    pub estimated_inversions: u64,
+    /// The number of NaN values.
+    pub nans: usize,
+    /// The number of values sampled, evenly spaced through the input. This is
+    /// all of them for input of up to 256 values.
+    pub sample_len: usize,
+    /// The number of sampled values equal to an earlier sampled value.
+    pub sample_duplicates: usize,
+    /// The least value that is not NaN, or None if there is none.
+    pub min: Option<TotalFloat>,
+    /// The greatest value that is not NaN, or None if there is none.
+    pub max: Option<TotalFloat>,
+}
+
+impl fmt::Display for SortednessReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "values:      {}", self.len)?;
+        writeln!(f,
+                 "runs:        {} (longest {})",
+                 self.runs,
+                 self.longest_run)?;
+        writeln!(f, "inversions:  ~{}", self.estimated_inversions)?;
+        writeln!(f, "NaNs:        {}", self.nans)?;
+        writeln!(f,
+                 "duplicates:  {} of {} sampled",
+                 self.sample_duplicates,
+                 self.sample_len)?;
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => {
+                write!(f, "range:       {} to {}", min, max)
+            }
+            _ => write!(f, "range:       none"),
+        }
+    }
+}
+
+/// Profiles how sorted a slice of TotalFloat values is, to help choose whether
+/// and how to sort it.
+///
+/// The runs, NaNs and extrema are found in a single pass each over all of the
+/// values, whereas the inversions and duplicates are counted over a sample of
+/// at most 256 values, so this takes `O(n)` time.
+pub fn analyze(values: &[TotalFloat]) -> SortednessReport {
+    let len = values.len();
+    let (mut runs, mut longest_run, mut run) = (0, 0, 0);
+    for (i, x) in values.iter().enumerate() {
+        if i == 0 || *x < values[i - 1] {
+            runs += 1;
+            run = 0;
+        }
+        run += 1;
+        longest_run = longest_run.max(run);
+    }
+
+    let sample: Vec<TotalFloat> = if len <= SAMPLE_LEN {
+        values.to_vec()
+    } else {
+        (0..SAMPLE_LEN).map(|i| values[i * len / SAMPLE_LEN]).collect()
+    };
+    let sample_len = sample.len();
+    // Scale the inversions of the sample by the ratio of the number of pairs
+    // of values to the number of pairs sampled.
+    let pairs = |n: usize| n as f64 * (n as f64 - 1.0) / 2.0;
+    let sample_inversions = count_inversions(&sample);
+    let estimated_inversions = if sample_len == len {
+        sample_inversions
+    } else {
+        (sample_inversions as f64 * pairs(len) / pairs(sample_len)) as u64
+    };
+    let sorted = merge_sort(sample);
+    let sample_duplicates = sorted.windows(2).filter(|w| w[0] == w[1]).count();
+
+    let extrema = extrema_non_nan(values);
+    SortednessReport {
+        len,
+        runs,
+        longest_run,
+        estimated_inversions,
+        nans: nan_count(values),
+        sample_len,
+        sample_duplicates,
+        min: extrema.map(|x| x.0),
+        max: extrema.map(|x| x.1),
+    }
+}
@@ -0,0 +1,42 @@
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use approx::UlpsEq;
+
+use NanPolicy;
+use TotalFloatWith;
+
+// Approximate Equality Implementations
+// These delegate to the implementations for f64, with tolerances given as f64,
+// except that (to be consistent with Eq) any two NaNs are approximately equal
+// to each other. A NaN is not approximately equal to anything else.
+impl<P: NanPolicy> AbsDiffEq for TotalFloatWith<P> {
+    type Epsilon = f64;
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (self.is_nan() && other.is_nan())
+            || self.inner.abs_diff_eq(&other.inner, epsilon)
+    }
+}
+
+impl<P: NanPolicy> RelativeEq for TotalFloatWith<P> {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64)
+                   -> bool {
+        (self.is_nan() && other.is_nan())
+            || self.inner.relative_eq(&other.inner, epsilon, max_relative)
+    }
+}
+
+impl<P: NanPolicy> UlpsEq for TotalFloatWith<P> {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+        (self.is_nan() && other.is_nan())
+            || self.inner.ulps_eq(&other.inner, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,61 @@
+use alloc::boxed::Box;
+use quickcheck::empty_shrinker;
+use quickcheck::single_shrinker;
+use quickcheck::Arbitrary;
+use quickcheck::Gen;
+
+use NanPolicy;
+use TotalFloatWith;
+
+const SIGN: u64 = 1 << 63;
+const EXPONENT: u64 = 0x7ff << 52;
+const MANTISSA: u64 = (1 << 52) - 1;
+const CANONICAL_NAN: u64 = 0x7ff8000000000000;
+
+// Arbitrary Implementation
+// Values are generated so that the cases which usually go untested turn up
+// often: a little under half are ordinary finite values, and the rest are
+// split between the infinities, the zeros, subnormals, and NaNs with random
+// payloads and sign bits.
+impl<P: NanPolicy + 'static> Arbitrary for TotalFloatWith<P> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let bits = u64::arbitrary(g);
+        let sign = bits & SIGN;
+        let kind = g.choose(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]).cloned();
+        let bits = match kind.unwrap_or(0) {
+            // Any finite bit pattern, of any magnitude.
+            0 | 1 if bits & EXPONENT == EXPONENT => bits & !(1 << 62),
+            0 | 1 => bits,
+            // Small values, scaled by the size of the generator.
+            2 | 3 => {
+                let size = g.size() as f64;
+                let unit = f64::from(i32::arbitrary(g)) / f64::from(i32::MAX);
+                (unit * size).to_bits()
+            }
+            4 => sign | EXPONENT,
+            5 => sign,
+            // A subnormal, which has a zero exponent and non-zero mantissa.
+            6 => sign | (bits & MANTISSA).max(1),
+            // A NaN, which has a full exponent and non-zero mantissa.
+            _ => sign | EXPONENT | (bits & MANTISSA).max(1),
+        };
+        Self::from_bits(bits)
+    }
+
+    // NaN shrinks to the canonical NaN, and everything else towards 0.0.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        if self.is_nan() {
+            if self.to_bits() == CANONICAL_NAN {
+                empty_shrinker()
+            } else {
+                single_shrinker(Self::from_bits(CANONICAL_NAN))
+            }
+        } else if self.to_bits() == 0 {
+            empty_shrinker()
+        } else if self.inner == 0.0 || self.is_infinite() {
+            single_shrinker(Self::ZERO)
+        } else {
+            Box::new(self.inner.shrink().map(Self::new))
+        }
+    }
+}
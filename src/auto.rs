@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use insertion_sort_by;
+#[cfg(feature = "rayon")]
+use merge_sort_par;
+use radix_sort;
+use sort_slice_with;
+use SortConfig;
+use TotalFloat;
+
+/// A sorting algorithm that `sort_auto_with` may choose.
+///
+/// All of them give exactly the result of `merge_sort`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// Binary insertion sort, for the shortest input.
+    Insertion,
+    /// The natural merge sort of `merge_sort`, for mostly sorted input.
+    Merge,
+    /// The radix sort of `radix_sort`, for long unsorted input.
+    Radix,
+    /// The parallel merge sort of `merge_sort_par`, for the longest unsorted
+    /// input. Without the `rayon` feature this is never chosen, and if forced
+    /// the natural merge sort is used instead.
+    Parallel,
+}
+
+// The number of neighbouring pairs sampled to judge how sorted input is.
+const SAMPLES: usize = 32;
+
+/// Sorts a list of TotalFloat values with whichever algorithm suits it best.
+///
+/// See `sort_auto_with` and `choose_algorithm`.
+pub fn sort_auto(input: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    sort_auto_with(input, &SortConfig::new())
+}
+
+/// Sorts a list of TotalFloat values with whichever algorithm suits it best,
+/// as tuned by the given configuration.
+///
+/// The result is exactly that of `merge_sort`, whichever algorithm is chosen.
+pub fn sort_auto_with(mut input: Vec<TotalFloat>,
+                      config: &SortConfig)
+                      -> Vec<TotalFloat> {
+    match choose_algorithm(&input, config) {
+        Algorithm::Insertion => {
+            insertion_sort_by(&mut input, 1, &mut TotalFloat::cmp);
+            input
+        }
+        Algorithm::Merge => {
+            sort_slice_with(&mut input, config, &mut TotalFloat::cmp);
+            input
+        }
+        Algorithm::Radix => radix_sort(input),
+        #[cfg(feature = "rayon")]
+        Algorithm::Parallel => merge_sort_par(input),
+        #[cfg(not(feature = "rayon"))]
+        Algorithm::Parallel => {
+            sort_slice_with(&mut input, config, &mut TotalFloat::cmp);
+            input
+        }
+    }
+}
+
+/// The algorithm that `sort_auto_with` would choose for the given input.
+///
+/// Unless the configuration forces an algorithm, input no longer than the
+/// insertion cutoff is insertion sorted. Otherwise neighbouring pairs at 32
+/// evenly spaced points are sampled, and if hardly any of them ascend (or
+/// hardly any descend) the input is merge sorted, which finds and merges
+/// existing runs. Input that is not mostly sorted is sorted in parallel
+/// from the parallel threshold (with the `rayon` feature), radix sorted from
+/// the radix threshold, and merge sorted below it.
+pub fn choose_algorithm(input: &[TotalFloat],
+                        config: &SortConfig)
+                        -> Algorithm {
+    let n = input.len();
+    if let Some(algorithm) = config.force {
+        return algorithm;
+    }
+    if n <= config.insertion_cutoff {
+        return Algorithm::Insertion;
+    }
+    let samples = SAMPLES.min(n - 1);
+    let (mut ascents, mut descents) = (0, 0);
+    for i in (0..samples).map(|i| i * (n - 1) / samples) {
+        match input[i].cmp(&input[i + 1]) {
+            Ordering::Less => ascents += 1,
+            Ordering::Greater => descents += 1,
+            Ordering::Equal => {}
+        }
+    }
+    if ascents.min(descents) <= samples / 8 {
+        return Algorithm::Merge;
+    }
+    #[cfg(feature = "rayon")]
+    {
+        if n >= config.parallel_threshold {
+            return Algorithm::Parallel;
+        }
+    }
+    if n >= config.radix_threshold {
+        Algorithm::Radix
+    } else {
+        Algorithm::Merge
+    }
+}
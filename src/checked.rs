@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+
+use merge;
+use TotalFloat;
+
+/// The error returned by `checked_merge` when one of its inputs is not in
+/// ascending order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnsortedInput {
+    /// The first input, `a`, is out of order at the given index.
+    A {
+        /// The index of the first value less than the one before it.
+        index: usize,
+    },
+    /// The second input, `b`, is out of order at the given index.
+    B {
+        /// The index of the first value less than the one before it.
+        index: usize,
+    },
+}
+
+impl fmt::Display for UnsortedInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, index) = match *self {
+            UnsortedInput::A { index } => ("a", index),
+            UnsortedInput::B { index } => ("b", index),
+        };
+        write!(f, "merge input {} is not sorted at index {}", name, index)
+    }
+}
+
+impl Error for UnsortedInput {}
+
+/// Merges two ordered lists of TotalFloat values, or returns an error if
+/// either is not in ascending order.
+///
+/// Both inputs are checked before anything is merged, `a` first, and the error
+/// gives the index of the first value that is less than the one before it. See
+/// `merge`, which only checks its inputs in debug builds.
+pub fn checked_merge(a: Vec<TotalFloat>,
+                     b: Vec<TotalFloat>)
+                     -> Result<Vec<TotalFloat>, UnsortedInput> {
+    if let Some(index) = unsorted_at(&a) {
+        return Err(UnsortedInput::A { index });
+    }
+    if let Some(index) = unsorted_at(&b) {
+        return Err(UnsortedInput::B { index });
+    }
+    Ok(merge(a, b))
+}
+
+// The index of the first value less than the one before it, if any.
+fn unsorted_at(input: &[TotalFloat]) -> Option<usize> {
+    input.windows(2)
+        .position(|w| w[0].cmp(&w[1]) == Ordering::Greater)
+        .map(|i| i + 1)
+}
@@ -0,0 +1,59 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::num::NonZeroUsize;
+
+use merge_sort_with_buffer;
+use TotalFloat;
+
+/// An iterator over sorted chunks of an iterator of f64 values, created by
+/// `sorted_chunks`.
+#[derive(Clone, Debug)]
+pub struct SortedChunks<I> {
+    iter: I,
+    chunk_size: usize,
+    // The scratch buffer of the sort, kept between chunks.
+    scratch: Vec<TotalFloat>,
+}
+
+/// Splits an iterator of f64 values into chunks of the given size, and yields
+/// each chunk sorted as TotalFloat values.
+///
+/// Only one chunk is held at a time, so this sorts input too large to hold in
+/// memory into runs, which can be written out and later merged, such as with
+/// `kmerge`. Every chunk is full but the last, which holds whatever is left.
+/// The sort's scratch buffer is reused for every chunk.
+pub fn sorted_chunks<I>(iter: I, chunk_size: NonZeroUsize) -> SortedChunks<I>
+    where I: Iterator<Item = f64>
+{
+    SortedChunks { iter, chunk_size: chunk_size.get(), scratch: Vec::new() }
+}
+
+impl<I> Iterator for SortedChunks<I>
+    where I: Iterator<Item = f64>
+{
+    type Item = Vec<TotalFloat>;
+
+    fn next(&mut self) -> Option<Vec<TotalFloat>> {
+        let mut chunk: Vec<TotalFloat> = self.iter
+            .by_ref()
+            .take(self.chunk_size)
+            .map(TotalFloat::new)
+            .collect();
+        if chunk.is_empty() {
+            return None;
+        }
+        merge_sort_with_buffer(&mut chunk, &mut self.scratch);
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let chunks = |n: usize| n.div_ceil(self.chunk_size);
+        (chunks(lo), hi.map(chunks))
+    }
+}
+
+impl<I> FusedIterator for SortedChunks<I>
+    where I: FusedIterator<Item = f64>
+{
+}
@@ -0,0 +1,36 @@
+use alloc::vec::Vec;
+
+use sort_slice_by;
+use TotalFloat;
+
+/// Sorts a slice of TotalFloat keys, and reorders a slice of values the same
+/// way, so that each value stays with its key.
+///
+/// The sort is stable, so values with equal keys (such as NaNs with different
+/// payloads) keep their relative order. Only the indices of the keys are
+/// sorted, and the values are then swapped into place, so the values are never
+/// copied or paired with their keys.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn co_sort<V>(keys: &mut [TotalFloat], values: &mut [V]) {
+    assert_eq!(keys.len(),
+               values.len(),
+               "co_sort requires as many values as keys");
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    sort_slice_by(&mut order, &mut |a: &usize, b: &usize| {
+        keys[*a].cmp(&keys[*b])
+    });
+    // Apply the permutation by swapping each key and value into place. Those
+    // swapped out of the way are followed along the indices of earlier swaps.
+    for i in 0..order.len() {
+        let mut index = order[i];
+        while index < i {
+            index = order[index];
+        }
+        order[i] = index;
+        keys.swap(i, index);
+        values.swap(i, index);
+    }
+}
@@ -0,0 +1,34 @@
+use alloc::collections::VecDeque;
+
+use insertion_sort_by;
+use sort_slice_by;
+use TotalFloat;
+
+/// The length of array, 32, at or below which `sort_array` sorts by
+/// insertion sort, without allocating.
+pub const ARRAY_INSERTION_LEN: usize = 32;
+
+/// Sorts a deque of TotalFloat values in place.
+///
+/// The deque is first made contiguous, which moves its values within its own
+/// buffer, and then sorted as by `merge_sort_in_place`.
+pub fn sort_deque(input: &mut VecDeque<TotalFloat>) {
+    sort_slice_by(input.make_contiguous(), &mut TotalFloat::cmp);
+}
+
+/// Sorts an array of TotalFloat values, returning it sorted.
+///
+/// Arrays of at most `ARRAY_INSERTION_LEN` values are sorted on the stack by
+/// insertion sort, making no heap allocation, so this can be used without an
+/// allocator. Longer arrays are sorted as by `merge_sort_in_place`. Either
+/// way the sort is stable, so NaNs with different payloads keep their
+/// relative order.
+pub fn sort_array<const N: usize>(mut input: [TotalFloat; N])
+                                  -> [TotalFloat; N] {
+    if N <= ARRAY_INSERTION_LEN {
+        insertion_sort_by(&mut input, 1, &mut TotalFloat::cmp);
+    } else {
+        sort_slice_by(&mut input, &mut TotalFloat::cmp);
+    }
+    input
+}
@@ -0,0 +1,133 @@
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+
+use NanPolicy;
+use TotalFloatWith;
+
+/// The error returned when a TotalFloat cannot be converted to an integer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IntConversionError {
+    /// The value was NaN.
+    Nan,
+    /// The value was positive or negative infinity.
+    Infinite,
+    /// The value was outside the range of the integer type.
+    OutOfRange,
+    /// The value had a fractional part.
+    NotAnInteger,
+}
+
+impl fmt::Display for IntConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match *self {
+            IntConversionError::Nan => "value is NaN",
+            IntConversionError::Infinite => "value is infinite",
+            IntConversionError::OutOfRange => {
+                "value is out of range of the integer type"
+            }
+            IntConversionError::NotAnInteger => "value has a fractional part",
+        };
+        write!(f, "{}", reason)
+    }
+}
+
+impl Error for IntConversionError {}
+
+// Checks that a float holds an integer in the range `[min, max)`, where both
+// bounds are exactly representable as f64. All of the ranges lie within that of
+// i128, so once in range, a value is an integer exactly when truncating it to
+// an i128 leaves it unchanged.
+fn check_integer(x: f64,
+                 min: f64,
+                 max: f64)
+                 -> Result<f64, IntConversionError> {
+    if x.is_nan() {
+        Err(IntConversionError::Nan)
+    } else if x.is_infinite() {
+        Err(IntConversionError::Infinite)
+    } else if x < min || x >= max {
+        Err(IntConversionError::OutOfRange)
+    } else if x as i128 as f64 != x {
+        Err(IntConversionError::NotAnInteger)
+    } else {
+        Ok(x)
+    }
+}
+
+// Integer Conversion Implementations
+// The bounds are given as the least value of the type, and one more than the
+// greatest, as both of those are powers of two and so exact as f64.
+macro_rules! impl_try_from_total {
+    ($($int:ty => $min:expr, $max:expr);*) => { $(
+        impl<P: NanPolicy> TryFrom<TotalFloatWith<P>> for $int {
+            type Error = IntConversionError;
+            fn try_from(from: TotalFloatWith<P>)
+                        -> Result<$int, IntConversionError> {
+                check_integer(from.inner, $min, $max).map(|x| x as $int)
+            }
+        }
+    )* }
+}
+
+impl_try_from_total! {
+    i32 => i32::MIN as f64, -(i32::MIN as f64);
+    i64 => i64::MIN as f64, -(i64::MIN as f64);
+    u64 => 0.0, 2.0 * (1u64 << 63) as f64;
+    usize => 0.0, 2.0 * (1usize << (usize::BITS - 1)) as f64
+}
+
+/// The error returned when an integer cannot be converted to a TotalFloat
+/// without rounding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InexactConversionError;
+
+impl fmt::Display for InexactConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "integer is not exactly representable as f64")
+    }
+}
+
+impl Error for InexactConversionError {}
+
+// Converts an integer to f64, failing if the conversion would round.
+fn exact_float(x: i128) -> Result<f64, InexactConversionError> {
+    let float = x as f64;
+    // A rounded-up i128::MAX gives 2^127, which would saturate back to
+    // i128::MAX when cast, so is ruled out before the round trip is checked.
+    if float < (1u128 << 127) as f64 && float as i128 == x {
+        Ok(float)
+    } else {
+        Err(InexactConversionError)
+    }
+}
+
+// Lossless Integer Conversion Implementations
+// These integer types all fit in the 53 bit mantissa of an f64.
+macro_rules! impl_from_int {
+    ($($int:ty),*) => { $(
+        impl<P: NanPolicy> From<$int> for TotalFloatWith<P> {
+            fn from(from: $int) -> Self {
+                Self::new(f64::from(from))
+            }
+        }
+    )* }
+}
+
+impl_from_int!(i8, i16, i32, u8, u16, u32);
+
+// Fallible Integer Conversion Implementations
+// Only the integers within 2^53 of zero, or those with enough trailing zeros,
+// are exactly representable as f64.
+macro_rules! impl_try_from_int {
+    ($($int:ty),*) => { $(
+        impl<P: NanPolicy> TryFrom<$int> for TotalFloatWith<P> {
+            type Error = InexactConversionError;
+            fn try_from(from: $int) -> Result<Self, InexactConversionError> {
+                exact_float(i128::from(from)).map(Self::new)
+            }
+        }
+    )* }
+}
+
+impl_try_from_int!(i64, u64, i128);
@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+
+use mean;
+use merge_sort;
+use TotalFloat;
+
+/// Removes consecutive equal values from a sorted Vec of TotalFloat values.
+///
+/// Equality is that of TotalFloat, so all NaNs are one value, and -0.0 and
+/// +0.0 are one value. Of each run of equal values the first occurrence is
+/// kept, with its bit pattern, and the rest removed.
+pub fn dedup_total(sorted: &mut Vec<TotalFloat>) {
+    sorted.dedup();
+}
+
+/// Sorts a list of TotalFloat values and removes duplicates.
+///
+/// Of each run of equal values in the sorted list, the first is kept. See
+/// `merge_sort` and `dedup_total`.
+pub fn sort_unique(input: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    let mut sorted = merge_sort(input);
+    dedup_total(&mut sorted);
+    sorted
+}
+
+/// Collapses clusters of nearly equal values in a sorted Vec of TotalFloat
+/// values, keeping the first value of each cluster.
+///
+/// Neighbouring values `a` and `b` are nearly equal if
+/// `|a - b| <= max(abs_tol, rel_tol * max(|a|, |b|))`. A cluster is a run in
+/// which each value is nearly equal to the one before it, so clusters chain:
+/// if `a` is nearly equal to `b`, and `b` to `c`, then all three form one
+/// cluster even if `a` and `c` are not nearly equal.
+///
+/// A NaN is only nearly equal to another NaN, and an infinity only to an equal
+/// infinity, whatever the tolerances.
+pub fn dedup_by_tolerance(sorted: &mut Vec<TotalFloat>,
+                          abs_tol: f64,
+                          rel_tol: f64) {
+    dedup_clusters(sorted, abs_tol, rel_tol, |cluster| cluster[0]);
+}
+
+/// Collapses clusters of nearly equal values in a sorted Vec of TotalFloat
+/// values, replacing each cluster with its mean.
+///
+/// Clusters are as for `dedup_by_tolerance`. A cluster of NaNs is replaced by
+/// its first NaN.
+pub fn dedup_by_tolerance_mean(sorted: &mut Vec<TotalFloat>,
+                               abs_tol: f64,
+                               rel_tol: f64) {
+    dedup_clusters(sorted, abs_tol, rel_tol, |cluster| {
+        if cluster[0].is_nan() {
+            cluster[0]
+        } else {
+            mean(cluster).unwrap()
+        }
+    });
+}
+
+// Replaces each cluster of a sorted Vec with a single value chosen from it.
+fn dedup_clusters<F>(sorted: &mut Vec<TotalFloat>,
+                     abs_tol: f64,
+                     rel_tol: f64,
+                     mut pick: F)
+    where F: FnMut(&[TotalFloat]) -> TotalFloat
+{
+    let n = sorted.len();
+    let mut start = 0;
+    let mut written = 0;
+    // A cluster ends at the end of the list, or when a value is not nearly
+    // equal to its predecessor. The chosen value is written back over the
+    // front of the list, which is never ahead of the cluster being read.
+    for i in 1..n + 1 {
+        if i == n ||
+           !nearly_equal(*sorted[i - 1], *sorted[i], abs_tol, rel_tol) {
+            sorted[written] = pick(&sorted[start..i]);
+            written += 1;
+            start = i;
+        }
+    }
+    sorted.truncate(written);
+}
+
+// Checks whether two neighbouring values are within the given tolerances.
+fn nearly_equal(a: f64, b: f64, abs_tol: f64, rel_tol: f64) -> bool {
+    if a.is_nan() || b.is_nan() {
+        a.is_nan() && b.is_nan()
+    } else if a.is_infinite() || b.is_infinite() {
+        a == b
+    } else {
+        (a - b).abs() <= abs_tol.max(rel_tol * a.abs().max(b.abs()))
+    }
+}
+
+/// Counts the runs of equal values in a sorted slice of TotalFloat values,
+/// returning each distinct value with the number of times it occurs.
+///
+/// Equality is as for `dedup_total`, so all NaNs are counted together, and
+/// the value given for each run is its first occurrence.
+pub fn value_counts(sorted: &[TotalFloat]) -> Vec<(TotalFloat, usize)> {
+    sorted.chunk_by(|a, b| a == b)
+        .map(|run| (run[0], run.len()))
+        .collect()
+}
+
+/// The most frequent value in a sorted slice of TotalFloat values with the
+/// number of times it occurs, or None if the slice is empty.
+///
+/// If several values are equally frequent, the least of them is chosen. See
+/// `value_counts`.
+pub fn mode(sorted: &[TotalFloat]) -> Option<(TotalFloat, usize)> {
+    let mut best: Option<(TotalFloat, usize)> = None;
+    for run in sorted.chunk_by(|a, b| a == b) {
+        // Only a strictly greater count replaces the best, so that the
+        // earliest, and least, of equally frequent values is kept.
+        if best.is_none_or(|(_, count)| run.len() > count) {
+            best = Some((run[0], run.len()));
+        }
+    }
+    best
+}
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use core::hash::BuildHasher;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use sort_slice_by;
+use SortOrder;
+use TotalFloat;
+
+/// Sorts key-value pairs, such as the entries of a map, by their f64 values
+/// under the total order of TotalFloat.
+///
+/// Entries with equal values, such as NaNs, are left in the order they were
+/// given, whichever the order of the sort.
+pub fn sort_entries_by_total_value<K, I>(entries: I,
+                                         order: SortOrder)
+                                         -> Vec<(K, TotalFloat)>
+    where I: IntoIterator<Item = (K, f64)>
+{
+    sort_entries(entries, order, |_, _| Ordering::Equal)
+}
+
+/// Sorts the entries of a HashMap by their values, under the total order of
+/// TotalFloat.
+///
+/// Entries with equal values are left in the iteration order of the map, which
+/// is arbitrary. See `sort_map_by_value_then_key` to break ties by key.
+#[cfg(feature = "std")]
+pub fn sort_map_by_value<K, V, S>(map: &HashMap<K, V, S>,
+                                  order: SortOrder)
+                                  -> Vec<(&K, TotalFloat)>
+    where V: Into<f64> + Copy,
+          S: BuildHasher
+{
+    sort_entries_by_total_value(map.iter().map(|(k, v)| (k, (*v).into())),
+                                order)
+}
+
+/// Sorts the entries of a HashMap by their values, under the total order of
+/// TotalFloat, and entries with equal values by their keys, ascending.
+#[cfg(feature = "std")]
+pub fn sort_map_by_value_then_key<K, V, S>(map: &HashMap<K, V, S>,
+                                           order: SortOrder)
+                                           -> Vec<(&K, TotalFloat)>
+    where K: Ord,
+          V: Into<f64> + Copy,
+          S: BuildHasher
+{
+    let entries = map.iter().map(|(k, v)| (k, (*v).into()));
+    sort_entries(entries, order, |a: &&K, b: &&K| a.cmp(b))
+}
+
+// Sorts entries by their values in the given order, and entries with equal
+// values by the tie break, which does not depend on the order.
+fn sort_entries<K, I, F>(entries: I,
+                         order: SortOrder,
+                         mut tie: F)
+                         -> Vec<(K, TotalFloat)>
+    where I: IntoIterator<Item = (K, f64)>,
+          F: FnMut(&K, &K) -> Ordering
+{
+    let mut entries: Vec<_> = entries.into_iter()
+        .map(|(k, v)| (k, TotalFloat::new(v)))
+        .collect();
+    sort_slice_by(&mut entries, &mut |a: &(K, TotalFloat), b: &(_, _)| {
+        let by_value = match order {
+            SortOrder::Ascending => a.1.cmp(&b.1),
+            SortOrder::Descending => b.1.cmp(&a.1),
+        };
+        by_value.then_with(|| tie(&a.0, &b.0))
+    });
+    entries
+}
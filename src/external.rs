@@ -0,0 +1,357 @@
+//! Sorting of f64 records too many to hold in memory.
+//!
+//! `external_sort` reads records in bounded runs, sorts each run in memory,
+//! spills the sorted runs to temporary files, and then merges them with
+//! `kmerge` into the output. At most one run is held in memory at a time,
+//! along with a buffer for each spilled run during the merge. Runs are merged
+//! at most 64 at a time, into new runs, until few enough remain to merge into
+//! the output, so that only so many files are ever open at once.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process;
+
+use kmerge;
+use merge_sort_with_buffer;
+use TotalFloat;
+
+// The number of temporary files created by this process, which keeps their
+// names distinct.
+static SPILLED: AtomicUsize = AtomicUsize::new(0);
+
+// The most spilled runs merged, and so open, at once.
+const MAX_OPEN: usize = 64;
+
+/// How records are written, in both the input and the output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordFormat {
+    /// Each record is eight bytes, the little-endian bits of an f64.
+    Binary,
+    /// Each record is a line of text, parsed as by `TotalFloat::from_str`.
+    /// Blank lines are skipped. NaN payloads are not kept, as they are all
+    /// written as `NaN`.
+    Text,
+}
+
+/// Options for `external_sort`.
+///
+/// Each setting has a builder method, as in
+/// `ExternalSortOpts::new().memory_limit(1 << 20).format(RecordFormat::Text)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExternalSortOpts {
+    memory_limit: usize,
+    format: RecordFormat,
+    temp_dir: Option<PathBuf>,
+}
+
+impl ExternalSortOpts {
+    /// The default options.
+    pub fn new() -> ExternalSortOpts {
+        ExternalSortOpts {
+            memory_limit: 64 << 20,
+            format: RecordFormat::Binary,
+            temp_dir: None,
+        }
+    }
+
+    /// Sets the most bytes of records, 64 MiB by default, held in memory in
+    /// each sorted run.
+    ///
+    /// Each record takes eight bytes, and a run always holds at least one.
+    /// The sort needs as much again for its scratch buffer.
+    pub fn memory_limit(mut self, bytes: usize) -> ExternalSortOpts {
+        self.memory_limit = bytes;
+        self
+    }
+
+    /// Sets the format of the records, binary by default.
+    pub fn format(mut self, format: RecordFormat) -> ExternalSortOpts {
+        self.format = format;
+        self
+    }
+
+    /// Sets the directory runs are spilled to, by default that of
+    /// `std::env::temp_dir`.
+    pub fn temp_dir<P: Into<PathBuf>>(mut self, dir: P) -> ExternalSortOpts {
+        self.temp_dir = Some(dir.into());
+        self
+    }
+}
+
+impl Default for ExternalSortOpts {
+    fn default() -> ExternalSortOpts {
+        ExternalSortOpts::new()
+    }
+}
+
+/// What `external_sort` did.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// The number of records sorted.
+    pub records: u64,
+    /// The number of sorted runs created. Input that fits in a single run is
+    /// written straight to the output, without spilling.
+    pub runs: usize,
+    /// The number of bytes written to temporary files, including those of
+    /// runs merged from other runs.
+    pub bytes_spilled: u64,
+    /// The number of NaN records.
+    pub nans: u64,
+}
+
+/// Sorts the f64 records of a reader under the total order of TotalFloat,
+/// writing them to a writer in the same format.
+///
+/// The records are read in runs of at most `memory_limit` bytes, each sorted
+/// with merge sort and written to a temporary file, which are then merged into
+/// the output and removed. The sort is stable, so with binary records, NaNs of
+/// different payloads keep their relative order.
+///
+/// # Errors
+///
+/// Returns any error from reading, writing or the temporary files, and an
+/// error of kind `InvalidData` for a record that cannot be read, such as a
+/// trailing partial binary record or a line of text that is not a number.
+/// The sort stops at the first error, so after an error in the final merge
+/// the output holds at most a prefix of the sorted records.
+pub fn external_sort<R, W>(input: R,
+                           output: W,
+                           opts: ExternalSortOpts)
+                           -> io::Result<Stats>
+    where R: Read,
+          W: Write
+{
+    let run_len = (opts.memory_limit / 8).max(1);
+    let mut input = Records::new(BufReader::new(input), opts.format);
+    let mut output = BufWriter::new(output);
+    let mut stats = Stats::default();
+    let mut spilled = Spilled(Vec::new());
+    let mut run = Vec::new();
+    let mut scratch = Vec::new();
+
+    let mut next = input.next_record()?;
+    while let Some(first) = next.take() {
+        run.clear();
+        run.push(first);
+        while run.len() < run_len {
+            match input.next_record()? {
+                Some(x) => run.push(x),
+                None => break,
+            }
+        }
+        // Peek at the record after a full run, so that a run ending exactly
+        // at the end of the input is still known to be the last.
+        if run.len() == run_len {
+            next = input.next_record()?;
+        }
+        let last = next.is_none();
+        stats.records += run.len() as u64;
+        stats.nans += run.iter().filter(|x| x.is_nan()).count() as u64;
+        stats.runs += 1;
+        merge_sort_with_buffer(&mut run, &mut scratch);
+        if last && spilled.0.is_empty() {
+            // Everything fits in one run, so there is nothing to merge.
+            for x in &run {
+                write_record(&mut output, *x, opts.format)?;
+            }
+            return output.flush().map(|_| stats);
+        }
+        stats.bytes_spilled += spill(&run, &opts, &mut spilled)?;
+    }
+    drop(run);
+    drop(scratch);
+
+    // Merge neighbouring runs in batches, in order so the merge stays stable,
+    // removing each batch once merged.
+    while spilled.0.len() > MAX_OPEN {
+        let mut merged = Spilled(Vec::new());
+        for batch in spilled.0.chunks(MAX_OPEN) {
+            let mut file = create_run(&opts, &mut merged)?;
+            stats.bytes_spilled +=
+                merge_runs(batch, &mut file, RecordFormat::Binary)? * 8;
+            file.flush()?;
+        }
+        spilled = merged;
+    }
+    merge_runs(&spilled.0, &mut output, opts.format)?;
+    output.flush().map(|_| stats)
+}
+
+// Writes a sorted run to a new temporary file, returning the number of bytes
+// written.
+fn spill(run: &[TotalFloat],
+         opts: &ExternalSortOpts,
+         spilled: &mut Spilled)
+         -> io::Result<u64> {
+    let mut file = create_run(opts, spilled)?;
+    for x in run {
+        write_record(&mut file, *x, RecordFormat::Binary)?;
+    }
+    file.flush()?;
+    Ok(run.len() as u64 * 8)
+}
+
+// Creates a new temporary file for a run, adding it to the spilled runs.
+fn create_run(opts: &ExternalSortOpts,
+              spilled: &mut Spilled)
+              -> io::Result<BufWriter<File>> {
+    let dir = opts.temp_dir.clone().unwrap_or_else(env::temp_dir);
+    loop {
+        let n = SPILLED.fetch_add(1, Ordering::Relaxed);
+        let path = dir.join(format!("fc-sort-{}-{}.run", process::id(), n));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => {
+                spilled.0.push(path);
+                return Ok(BufWriter::new(file));
+            }
+            Err(ref e) if e.kind() == ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// Merges spilled runs into an output in the given format, returning the
+// number of records written. The merge stops at the first error from reading
+// any of the runs, writing nothing more.
+fn merge_runs<W: Write>(paths: &[PathBuf],
+                        output: &mut W,
+                        format: RecordFormat)
+                        -> io::Result<u64> {
+    let error = RefCell::new(None);
+    let runs = paths.iter()
+        .map(|path| {
+            let file = File::open(path)?;
+            let file = BufReader::new(file);
+            let records = Records::new(file, RecordFormat::Binary);
+            Ok(RunIter { records, error: &error })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    let mut written = 0;
+    for x in kmerge(runs) {
+        // A run that fails stops yielding, so the merge would otherwise go on
+        // without it.
+        if let Some(e) = error.borrow_mut().take() {
+            return Err(e);
+        }
+        write_record(output, x, format)?;
+        written += 1;
+    }
+    match error.into_inner() {
+        Some(e) => Err(e),
+        None => Ok(written),
+    }
+}
+
+// The paths of the spilled runs, which are removed once they are merged, or
+// if the sort fails.
+struct Spilled(Vec<PathBuf>);
+
+impl Drop for Spilled {
+    fn drop(&mut self) {
+        for path in &self.0 {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn write_record<W: Write>(output: &mut W,
+                          value: TotalFloat,
+                          format: RecordFormat)
+                          -> io::Result<()> {
+    match format {
+        RecordFormat::Binary => {
+            output.write_all(&value.to_bits().to_le_bytes())
+        }
+        RecordFormat::Text => writeln!(output, "{}", value.inner),
+    }
+}
+
+// A reader of records, in either format.
+struct Records<R> {
+    reader: R,
+    format: RecordFormat,
+    line: String,
+}
+
+impl<R: BufRead> Records<R> {
+    fn new(reader: R, format: RecordFormat) -> Records<R> {
+        Records { reader, format, line: String::new() }
+    }
+
+    // Reads the next record, or None at the end of the input.
+    fn next_record(&mut self) -> io::Result<Option<TotalFloat>> {
+        match self.format {
+            RecordFormat::Binary => {
+                let mut bytes = [0; 8];
+                let mut filled = 0;
+                while filled < 8 {
+                    match self.reader.read(&mut bytes[filled..]) {
+                        Ok(0) => break,
+                        Ok(n) => filled += n,
+                        Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                let bits = u64::from_le_bytes(bytes);
+                match filled {
+                    0 => Ok(None),
+                    8 => Ok(Some(TotalFloat::from_bits(bits))),
+                    _ => Err(invalid_data("trailing partial f64 record")),
+                }
+            }
+            RecordFormat::Text => loop {
+                self.line.clear();
+                if self.reader.read_line(&mut self.line)? == 0 {
+                    return Ok(None);
+                }
+                let line = self.line.trim();
+                if !line.is_empty() {
+                    return line.parse()
+                        .map(Some)
+                        .map_err(|_| invalid_data("record is not a number"));
+                }
+            },
+        }
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+// An iterator over the records of a spilled run, for `kmerge`, which stops
+// at the first error and keeps it for the merge to return.
+struct RunIter<'a, R> {
+    records: Records<R>,
+    error: &'a RefCell<Option<io::Error>>,
+}
+
+impl<'a, R: BufRead> Iterator for RunIter<'a, R> {
+    type Item = TotalFloat;
+
+    fn next(&mut self) -> Option<TotalFloat> {
+        match self.records.next_record() {
+            Ok(x) => x,
+            Err(e) => {
+                self.error.borrow_mut().get_or_insert(e);
+                None
+            }
+        }
+    }
+}
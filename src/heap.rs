@@ -0,0 +1,47 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+
+use TotalFloat;
+
+/// Sorts a slice of TotalFloat values in place by heapsort.
+///
+/// This takes O(n log n) time whatever the input, and O(1) extra memory. The
+/// sort is not stable, so equal values (such as NaNs with different payloads,
+/// or zeros of different signs) may be reordered. It is usually slower than
+/// `pdq_sort`, which falls back to it on inputs that defeat its pivots.
+pub fn heap_sort(input: &mut [TotalFloat]) {
+    // Moves the value at node down a max-heap until neither of its children
+    // is greater.
+    let sift_down = |heap: &mut [TotalFloat], mut node: usize| {
+        loop {
+            let mut child = 2 * node + 1;
+            if child >= heap.len() {
+                break;
+            }
+            if child + 1 < heap.len() && heap[child] < heap[child + 1] {
+                child += 1;
+            }
+            if heap[node] >= heap[child] {
+                break;
+            }
+            heap.swap(node, child);
+            node = child;
+        }
+    };
+    let n = input.len();
+    for node in (0..n / 2).rev() {
+        sift_down(input, node);
+    }
+    for end in (1..n).rev() {
+        input.swap(0, end);
+        sift_down(&mut input[..end], 0);
+    }
+}
+
+/// Collects a list of TotalFloat values into a max-heap, so that the greatest
+/// can be popped one at a time.
+///
+/// This takes O(n) time. As NaN is least, NaNs are popped last.
+pub fn into_heap(input: Vec<TotalFloat>) -> BinaryHeap<TotalFloat> {
+    BinaryHeap::from(input)
+}
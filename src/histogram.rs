@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+
+use TotalFloat;
+
+/// How the bins of a Histogram are chosen.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Bins {
+    /// The given number of bins, of equal width, spanning the finite values.
+    EqualWidth(usize),
+    /// Bins between each pair of consecutive edges, which must be strictly
+    /// increasing and not NaN.
+    Explicit(Vec<f64>),
+    /// Bins of equal width spanning the finite values, their width chosen by
+    /// the Freedman-Diaconis rule as `2 * IQR / n^(1/3)`.
+    FreedmanDiaconis,
+}
+
+/// Counts of sorted TotalFloat values falling into each of a sequence of bins.
+///
+/// Each bin is half-open, including values equal to its lower edge but not its
+/// upper edge, except for the last bin, which also includes values equal to
+/// its upper edge. Values below the first edge or above the last edge are
+/// counted as underflow or overflow, and NaNs are counted separately.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    edges: Vec<f64>,
+    counts: Vec<usize>,
+    underflow: usize,
+    overflow: usize,
+    nans: usize,
+}
+
+impl Histogram {
+    /// Builds a Histogram of a sorted slice of TotalFloat values.
+    ///
+    /// Bins chosen from the data span only its finite values, so infinities
+    /// are counted as underflow or overflow. If all the finite values are
+    /// equal the bins span half either side of them, and if there are none
+    /// the bins span `[0, 1]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there would be no bins, or if explicit edges are not strictly
+    /// increasing or contain a NaN.
+    pub fn from_sorted(sorted: &[TotalFloat], bins: Bins) -> Histogram {
+        let edges = match bins {
+            Bins::EqualWidth(n) => equal_width_edges(sorted, n),
+            Bins::Explicit(edges) => {
+                assert!(edges.windows(2).all(|w| w[0] < w[1]),
+                        "histogram edges must be strictly increasing");
+                assert!(edges.iter().all(|x| !x.is_nan()),
+                        "histogram edges must not be NaN");
+                edges
+            }
+            Bins::FreedmanDiaconis => {
+                let n = freedman_diaconis_bins(sorted);
+                equal_width_edges(sorted, n)
+            }
+        };
+        assert!(edges.len() >= 2, "a histogram must have at least one bin");
+
+        let mut histogram = Histogram {
+            counts: alloc::vec![0; edges.len() - 1],
+            edges,
+            underflow: 0,
+            overflow: 0,
+            nans: 0,
+        };
+        for x in sorted {
+            match histogram.bin_of(x.inner) {
+                Some(i) => histogram.counts[i] += 1,
+                None if x.is_nan() => histogram.nans += 1,
+                None if x.inner < histogram.edges[0] => {
+                    histogram.underflow += 1
+                }
+                None => histogram.overflow += 1,
+            }
+        }
+        histogram
+    }
+
+    /// The index of the bin containing the given value, or None if it is NaN
+    /// or lies outside the bins.
+    pub fn bin_of(&self, value: f64) -> Option<usize> {
+        let last = self.edges.len() - 1;
+        // The number of edges at or below the value.
+        match self.edges.partition_point(|&e| e <= value) {
+            0 => None,
+            i if i <= last => Some(i - 1),
+            _ if value == self.edges[last] => Some(last - 1),
+            _ => None,
+        }
+    }
+
+    /// The edges of the bins, one more than the number of bins.
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// The number of values in each bin.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// The number of values below the first edge.
+    pub fn underflow(&self) -> usize {
+        self.underflow
+    }
+
+    /// The number of values above the last edge.
+    pub fn overflow(&self) -> usize {
+        self.overflow
+    }
+
+    /// The number of NaN values.
+    pub fn nan_count(&self) -> usize {
+        self.nans
+    }
+}
+
+// The finite values of a sorted slice, which form a contiguous run.
+fn finite(sorted: &[TotalFloat]) -> &[TotalFloat] {
+    let start = sorted.partition_point(|x| !x.is_finite() && *x < 0.0);
+    let end = sorted.partition_point(|x| x.is_finite() || *x < 0.0);
+    &sorted[start..end]
+}
+
+// The edges of n bins of equal width spanning the finite values of a sorted
+// slice.
+fn equal_width_edges(sorted: &[TotalFloat], n: usize) -> Vec<f64> {
+    let finite = finite(sorted);
+    let (lo, hi) = match (finite.first(), finite.last()) {
+        (Some(lo), Some(hi)) if lo < hi => (lo.inner, hi.inner),
+        (Some(x), _) => (x.inner - 0.5, x.inner + 0.5),
+        _ => (0.0, 1.0),
+    };
+    // Each edge weights the bounds rather than stepping by the width, so that
+    // no edge can overflow to infinity when the values span more than
+    // f64::MAX. The last edge is set exactly so that the greatest value is
+    // included.
+    let mut edges: Vec<f64> = (0..n)
+        .map(|i| {
+            let t = i as f64 / n as f64;
+            lo * (1.0 - t) + hi * t
+        })
+        .collect();
+    if n > 0 {
+        edges.push(hi);
+    }
+    edges
+}
+
+// The number of bins chosen by the Freedman-Diaconis rule for the finite
+// values of a sorted slice, or one if they have no spread. There are never
+// more bins than values, however far an outlier lies from the rest.
+fn freedman_diaconis_bins(sorted: &[TotalFloat]) -> usize {
+    let finite = finite(sorted);
+    let n = finite.len();
+    if n < 2 {
+        return 1;
+    }
+    // The range and IQR are halved, so that they cannot overflow to infinity
+    // when the values span more than f64::MAX, leaving their ratio as it was.
+    let half = |x: f64| x / 2.0;
+    let iqr = half(sorted_quantile(finite, 0.75)) -
+              half(sorted_quantile(finite, 0.25));
+    let range = half(finite[n - 1].inner) - half(finite[0].inner);
+    let width = 2.0 * iqr / cbrt(n as f64);
+    if width > 0.0 {
+        // Rounded up, without the float functions of std, once capped.
+        let bins = range / width;
+        if bins >= n as f64 {
+            return n;
+        }
+        let whole = bins as usize;
+        let bins = if (whole as f64) < bins { whole + 1 } else { whole };
+        bins.max(1)
+    } else {
+        1
+    }
+}
+
+// The quantile q of a non-empty sorted slice of finite values, interpolated
+// linearly. The neighbours are weighted rather than differenced, so that the
+// result cannot overflow to infinity.
+fn sorted_quantile(sorted: &[TotalFloat], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let i = position as usize;
+    let fraction = position - i as f64;
+    match sorted.get(i + 1) {
+        Some(upper) => {
+            sorted[i].inner * (1.0 - fraction) + upper.inner * fraction
+        }
+        None => sorted[i].inner,
+    }
+}
+
+// The cube root of a positive finite value, to within an ulp, without the
+// float functions of std. The guess from dividing the exponent by three is
+// refined by Newton's method, which converges in a few steps.
+fn cbrt(x: f64) -> f64 {
+    let mut y = f64::from_bits(x.to_bits() / 3 + 0x2a9f_7893_782d_a1ce);
+    for _ in 0..4 {
+        y -= (y * y * y - x) / (3.0 * y * y);
+    }
+    y
+}
@@ -0,0 +1,41 @@
+use alloc::vec::Vec;
+
+use sort_slice_counting;
+use Counter;
+use SortConfig;
+use TotalFloat;
+
+/// The number of inversions in a slice of TotalFloat values, that is, the
+/// number of pairs of values where the earlier is greater than the later.
+///
+/// This is 0 for sorted input and `n * (n - 1) / 2` for input of `n` distinct
+/// values in reverse order, and is found in `O(n log n)` time by merge sorting
+/// a copy. Equal values, such as any two NaNs, are not inversions.
+pub fn count_inversions(input: &[TotalFloat]) -> u64 {
+    merge_sort_counting(input.to_vec()).1
+}
+
+/// Sorts a list of TotalFloat values, returning the sorted list along with the
+/// number of inversions it had.
+///
+/// This is the sort of `merge_sort`, so the result is exactly the same, with
+/// the inversions tallied as each value is moved past those greater than it.
+/// See `count_inversions`.
+pub fn merge_sort_counting(mut input: Vec<TotalFloat>)
+                           -> (Vec<TotalFloat>, u64) {
+    let mut inversions = Inversions(0);
+    sort_slice_counting(&mut input,
+                        &SortConfig::new(),
+                        &mut TotalFloat::cmp,
+                        &mut inversions);
+    (input, inversions.0)
+}
+
+// Tallies the inversions put in order by a sort.
+struct Inversions(u64);
+
+impl Counter for Inversions {
+    fn inverted(&mut self, count: u64) {
+        self.0 += count;
+    }
+}
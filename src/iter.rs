@@ -0,0 +1,125 @@
+use alloc::vec;
+use core::cmp::Ordering;
+
+use merge_sort;
+use merge_sort_desc;
+use merge_sort_by_cached_total_key;
+use TotalFloat;
+
+/// A value which can be compared under the total order of TotalFloat.
+///
+/// This is implemented for f64 and TotalFloat, and references to them, so
+/// that TotalIteratorExt can be used on iterators over any of these.
+pub trait TotalKey {
+    /// The value as a TotalFloat.
+    fn total_key(&self) -> TotalFloat;
+}
+
+impl TotalKey for f64 {
+    fn total_key(&self) -> TotalFloat {
+        TotalFloat::new(*self)
+    }
+}
+
+impl TotalKey for TotalFloat {
+    fn total_key(&self) -> TotalFloat {
+        *self
+    }
+}
+
+impl<T: TotalKey + ?Sized> TotalKey for &T {
+    fn total_key(&self) -> TotalFloat {
+        (**self).total_key()
+    }
+}
+
+/// Extends iterators with minimum and maximum methods using the total order
+/// of TotalFloat.
+///
+/// As under the total order, NaN counts as least, so the total_max of
+/// `[NaN]` is NaN, but the total_max of `[NaN, 1.0]` is 1.0. As with
+/// `Iterator::min` and `Iterator::max`, the first of several equal minima and
+/// the last of several equal maxima is returned.
+pub trait TotalIteratorExt: Iterator + Sized {
+    /// The least item, or None if the iterator is empty.
+    fn total_min(self) -> Option<Self::Item>
+        where Self::Item: TotalKey
+    {
+        self.min_by(|a, b| a.total_key().cmp(&b.total_key()))
+    }
+
+    /// The greatest item, or None if the iterator is empty.
+    fn total_max(self) -> Option<Self::Item>
+        where Self::Item: TotalKey
+    {
+        self.max_by(|a, b| a.total_key().cmp(&b.total_key()))
+    }
+
+    /// The least and greatest items, found in a single pass, or None if the
+    /// iterator is empty.
+    fn total_minmax(self) -> Option<(Self::Item, Self::Item)>
+        where Self::Item: TotalKey + Clone
+    {
+        self.total_extrema_by_key(|x| x.total_key().inner)
+    }
+
+    /// The items with the least and greatest keys, found in a single pass,
+    /// or None if the iterator is empty.
+    ///
+    /// The key of each item is computed once.
+    fn total_extrema_by_key<F>(mut self,
+                               mut key: F)
+                               -> Option<(Self::Item, Self::Item)>
+        where Self::Item: Clone,
+              F: FnMut(&Self::Item) -> f64
+    {
+        let first = self.next()?;
+        let first_key = TotalFloat::new(key(&first));
+        let mut min = (first.clone(), first_key);
+        let mut max = (first, first_key);
+        for item in self {
+            let item_key = TotalFloat::new(key(&item));
+            // Ties keep the earlier minimum, but move to the later maximum.
+            if item_key.cmp(&min.1) == Ordering::Less {
+                min = (item.clone(), item_key);
+            }
+            if item_key.cmp(&max.1) != Ordering::Less {
+                max = (item, item_key);
+            }
+        }
+        Some((min.0, max.0))
+    }
+
+    /// Collects the items as TotalFloat values, and returns an iterator over
+    /// them in ascending order.
+    fn sorted_total(self) -> vec::IntoIter<TotalFloat>
+        where Self::Item: TotalKey
+    {
+        merge_sort(self.map(|x| x.total_key()).collect()).into_iter()
+    }
+
+    /// Collects the items as TotalFloat values, and returns an iterator over
+    /// them in descending order.
+    ///
+    /// As NaN is least, any NaNs come last. As with `merge_sort_desc` equal
+    /// values, such as NaNs with different payloads, keep their relative
+    /// order.
+    fn sorted_total_desc(self) -> vec::IntoIter<TotalFloat>
+        where Self::Item: TotalKey
+    {
+        merge_sort_desc(self.map(|x| x.total_key()).collect()).into_iter()
+    }
+
+    /// Collects the items, and returns an iterator over them in ascending
+    /// order of their keys.
+    ///
+    /// The key of each item is computed once. Items with equal keys keep
+    /// their relative order. See `merge_sort_by_cached_total_key`.
+    fn sorted_total_by_key<F>(self, key: F) -> vec::IntoIter<Self::Item>
+        where F: FnMut(&Self::Item) -> f64
+    {
+        merge_sort_by_cached_total_key(self.collect(), key).into_iter()
+    }
+}
+
+impl<I: Iterator> TotalIteratorExt for I {}
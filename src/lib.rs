@@ -1,7 +1,299 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::ops::Deref;
-use std::ops::DerefMut;
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "approx")]
+extern crate approx;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "num-traits")]
+extern crate num_traits;
+#[cfg(feature = "ordered-float")]
+extern crate ordered_float;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::iter::Product;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::mem;
+use core::mem::ManuallyDrop;
+use core::ops;
+use core::ops::Deref;
+use core::ops::DerefMut;
+use core::ptr;
+use core::slice;
+use core::str::FromStr;
+
+mod analysis;
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+mod auto;
+mod checked;
+mod chunks;
+mod co_sort;
+mod collections;
+mod convert;
+mod dedup;
+mod entries;
+#[cfg(feature = "std")]
+pub mod external;
+mod heap;
+mod histogram;
+mod inversions;
+mod iter;
+mod merge_iter;
+mod nans;
+mod not_nan;
+#[cfg(feature = "num-traits")]
+mod num;
+mod options;
+#[cfg(feature = "ordered-float")]
+mod ordered;
+#[cfg(feature = "rayon")]
+mod par;
+mod pdq;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "proptest")]
+pub mod proptest_support;
+mod radix;
+#[cfg(feature = "rand")]
+mod random;
+mod rank;
+mod reserving;
+mod results;
+mod running_stats;
+mod search;
+mod select;
+#[cfg(feature = "serde")]
+pub mod serde_lenient;
+#[cfg(feature = "serde")]
+pub mod serde_special;
+#[cfg(feature = "serde")]
+mod serialize;
+mod set_ops;
+mod sort_stats;
+mod sorted_slice;
+mod stats;
+mod streaming;
+mod strict;
+#[cfg(feature = "std")]
+mod threads;
+mod top_k;
+#[macro_use]
+mod total_float32;
+mod total_vec;
+
+pub use analysis::{analyze, SortednessReport};
+pub use auto::{choose_algorithm, sort_auto, sort_auto_with, Algorithm};
+pub use checked::{checked_merge, UnsortedInput};
+pub use chunks::{sorted_chunks, SortedChunks};
+pub use co_sort::co_sort;
+pub use collections::{sort_array, sort_deque, ARRAY_INSERTION_LEN};
+pub use convert::{InexactConversionError, IntConversionError};
+pub use dedup::{dedup_by_tolerance, dedup_by_tolerance_mean, dedup_total, mode,
+                sort_unique, value_counts};
+pub use entries::sort_entries_by_total_value;
+#[cfg(feature = "std")]
+pub use entries::{sort_map_by_value, sort_map_by_value_then_key};
+pub use heap::{heap_sort, into_heap};
+pub use histogram::{Bins, Histogram};
+pub use inversions::{count_inversions, merge_sort_counting};
+pub use iter::{TotalIteratorExt, TotalKey};
+pub use merge_iter::{kmerge, merge3, merge_iter, merge_k_tagged, KMergeIter,
+                     MergeIter};
+pub use nans::{merge_sort_dropping_nans, merge_sort_nan, nan_count,
+               partition_nans, strip_nans_in_place, try_merge, try_merge_sort,
+               NanFound, NanPlacement, NanRejected};
+pub use not_nan::{merge_sort_not_nan, NanError, NotNan};
+pub use options::{sort_options, NonePlacement};
+#[cfg(feature = "rayon")]
+pub use par::{merge_sort_par, merge_sort_par_in_place,
+              merge_sort_par_with_threshold, PAR_THRESHOLD};
+pub use pdq::pdq_sort;
+pub use radix::radix_sort;
+#[cfg(feature = "rand")]
+pub use random::{random_tfvec, UniformTotalFloat};
+pub use rank::{ranks, RankMethod};
+pub use reserving::{try_merge_sort_in_place_reserving,
+                    try_merge_sort_reserving, ReserveFailed};
+pub use results::{try_sort_results, try_sorted_by_key};
+pub use running_stats::RunningStats;
+pub use search::{binary_search, binary_search_f64, count_in_range,
+                 equal_range, equal_range_f64, lower_bound, lower_bound_f64,
+                 nearest, slice_in_range, upper_bound, upper_bound_f64};
+pub use select::{median, quantile, select_nth, Interpolation};
+pub use set_ops::{sorted_difference, sorted_intersection, sorted_is_subset,
+                  sorted_symmetric_difference, sorted_union};
+pub use sort_stats::{merge_sort_with_stats, SortStats};
+pub use sorted_slice::{as_sorted, SortedSlice};
+pub use stats::{extrema, extrema_non_nan, max_of, max_of_non_nan, mean,
+                mean_with, min_of, min_of_non_nan, sum_compensated,
+                sum_compensated_with, NanHandling};
+pub use streaming::StreamingSorter;
+pub use strict::StrictTotalFloat;
+#[cfg(feature = "std")]
+pub use threads::merge_sort_threads;
+pub use top_k::{top_k_largest, top_k_smallest};
+pub use total_float32::{merge_sort32, sort_f32, sort_f32_desc, TotalFloat32};
+pub use total_vec::TotalVec;
+
+// Used by the list macros, so that they work in crates without std.
+#[doc(hidden)]
+pub use alloc::vec::Vec as __Vec;
+
+/// A policy deciding where NaN values are placed in the total order of a
+/// TotalFloatWith.
+///
+/// Whichever policy is used, all NaN values are equal to each other.
+pub trait NanPolicy {
+    /// How a NaN compares against any value that is not NaN.
+    const NAN_ORDERING: Ordering;
+}
+
+/// The default NaN policy, under which NaN values are strictly less than all
+/// other values, including negative infinity.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NanLeast;
+
+impl NanPolicy for NanLeast {
+    const NAN_ORDERING: Ordering = Ordering::Less;
+}
+
+/// A NaN policy under which NaN values are strictly greater than all other
+/// values, including positive infinity.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NanGreatest;
+
+impl NanPolicy for NanGreatest {
+    const NAN_ORDERING: Ordering = Ordering::Greater;
+}
+
+/// The operations TotalFloatWith needs from the float type it wraps.
+///
+/// This is implemented for f32 and f64, and may be implemented for other
+/// float types (such as a half precision float) to allow them to be wrapped
+/// and sorted. Comparison of values that are not NaN is delegated to the
+/// PartialOrd implementation, which must be total over those values.
+pub trait RawFloat: Copy + PartialOrd {
+    /// The unsigned integer type holding the raw bits of the float.
+    type Bits: Copy;
+
+    /// Whether the value is Not a Number.
+    fn is_nan(self) -> bool;
+
+    /// The raw bit pattern of the value.
+    fn to_bits(self) -> Self::Bits;
+
+    /// Creates a value from its raw bit pattern.
+    fn from_bits(bits: Self::Bits) -> Self;
+
+    /// Compares two values, with all NaNs equal, and ordered `nan_ordering`
+    /// against every value that is not NaN.
+    ///
+    /// This is the comparison behind the Ord implementation of
+    /// TotalFloatWith. The default checks for NaNs and delegates the rest to
+    /// PartialOrd, while f32 and f64 compare integer keys made from their bits.
+    /// Neither can panic.
+    fn cmp_with_nan(self, other: Self, nan_ordering: Ordering) -> Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => nan_ordering,
+            (false, true) => nan_ordering.reverse(),
+            // PartialOrd must be total over values that are not NaN, so this
+            // only falls through to Equal when the values are equal.
+            (false, false) if self < other => Ordering::Less,
+            (false, false) if self > other => Ordering::Greater,
+            (false, false) => Ordering::Equal,
+        }
+    }
+}
+
+// RawFloat Implementations for the primitive floats
+// These call through to the inherent methods of the same names, except for
+// the comparison, which maps each value to a signed integer key whose ordering
+// matches, so that comparing two values needs no branches.
+macro_rules! impl_raw_float {
+    ($($float:ident, $bits:ident, $signed:ident);*) => { $(
+        impl RawFloat for $float {
+            type Bits = $bits;
+            fn is_nan(self) -> bool {
+                $float::is_nan(self)
+            }
+            fn to_bits(self) -> $bits {
+                $float::to_bits(self)
+            }
+            fn from_bits(bits: $bits) -> $float {
+                $float::from_bits(bits)
+            }
+            fn cmp_with_nan(self, other: $float, nan_ordering: Ordering)
+                            -> Ordering {
+                // No value that is not NaN has either extreme as its key.
+                let nan_key = match nan_ordering {
+                    Ordering::Greater => $signed::MAX,
+                    _ => $signed::MIN,
+                };
+                let key = |x: $float| {
+                    const SHIFT: u32 = $signed::BITS - 1;
+                    let bits = x.to_bits() as $signed;
+                    let nan = bits & $signed::MAX >
+                              $float::INFINITY.to_bits() as $signed;
+                    // Flipping every bit but the sign bit of negative values
+                    // gives the ordering of ieee_total_cmp, under which -0.0
+                    // is -1 and +0.0 is 0. Adding one to negative values then
+                    // makes the zeros equal.
+                    let mask = ((bits >> SHIFT) as $bits) >> 1;
+                    let bits = bits ^ mask as $signed;
+                    let bits = bits - (bits >> SHIFT);
+                    if nan { nan_key } else { bits }
+                };
+                key(self).cmp(&key(other))
+            }
+        }
+    )* }
+}
+
+impl_raw_float! {
+    f32, u32, i32;
+    f64, u64, i64
+}
+
+/// An abstraction over IEEE Floats providing Totality in Ordering and
+/// Reflexivity in Equality, with NaN values placed according to the policy
+/// `P`.
+///
+/// The wrapped float type `F` defaults to f64. All NaN values are treated as
+/// being equal to each other.
+///
+/// TotalFloatWith is guaranteed to have the same size, alignment and ABI as
+/// `F`, so buffers of TotalFloatWith may be passed anywhere a buffer of `F`
+/// (such as a `double*` across an FFI boundary) is expected, and vice versa.
+#[repr(transparent)]
+pub struct TotalFloatWith<P: NanPolicy = NanLeast, F: RawFloat = f64> {
+    pub inner: F,
+    policy: PhantomData<P>,
+}
 
 /// An abstraction over 64 Bit IEEE Floats providing Totality in Ordering and
 /// Reflexivity in Equality.
@@ -9,177 +301,1636 @@ use std::ops::DerefMut;
 /// NaN values are treated as being strictly less than all other values.
 /// Including negative infinity. All NaN values are treated as being equal to
 /// each other.
-#[derive(Copy, Clone)]
-pub struct TotalFloat {
-    pub inner: f64,
+///
+/// This is an alias rather than a defaulted `TotalFloat<F = f64>`, as type
+/// defaults are not used for inference in expressions, which would make
+/// paths such as `TotalFloat::NAN` ambiguous.
+pub type TotalFloat = TotalFloatWith<NanLeast>;
+
+impl<P: NanPolicy, F: RawFloat> TotalFloatWith<P, F> {
+    /// Creates a new TotalFloat wrapping the given float.
+    ///
+    /// Unlike `From` this is a const fn, so can be used to initialize
+    /// constants and statics.
+    pub const fn new(inner: F) -> Self {
+        TotalFloatWith {
+            inner,
+            policy: PhantomData,
+        }
+    }
+
+    /// Converts to the same value under a different NaN policy.
+    ///
+    /// This is free, as the representation does not depend on the policy.
+    pub const fn with_policy<Q: NanPolicy>(self) -> TotalFloatWith<Q, F> {
+        TotalFloatWith::new(self.inner)
+    }
 }
 
-// Implement Deref and DerefMut to allow us to use f64 methods on TotalFloat.
+impl<P: NanPolicy> TotalFloatWith<P> {
+    /// Not a Number.
+    pub const NAN: Self = Self::new(f64::NAN);
+    /// Positive infinity, which is greater than all other values except NaN
+    /// under NanGreatest.
+    pub const INFINITY: Self = Self::new(f64::INFINITY);
+    /// Negative infinity, which is less than all other values except NaN
+    /// under NanLeast.
+    pub const NEG_INFINITY: Self = Self::new(f64::NEG_INFINITY);
+    /// The smallest finite value.
+    pub const MIN: Self = Self::new(f64::MIN);
+    /// The largest finite value.
+    pub const MAX: Self = Self::new(f64::MAX);
+    /// The smallest positive normal value.
+    pub const MIN_POSITIVE: Self = Self::new(f64::MIN_POSITIVE);
+    /// The difference between 1.0 and the next largest representable value.
+    pub const EPSILON: Self = Self::new(f64::EPSILON);
+    /// Positive zero.
+    pub const ZERO: Self = Self::new(0.0);
+    /// Negative zero, which is equal to positive zero.
+    pub const NEG_ZERO: Self = Self::new(-0.0);
+
+    /// Creates a TotalFloat from its raw IEEE 754 bit pattern, preserving it
+    /// exactly (including any NaN payload).
+    pub const fn from_bits(bits: u64) -> Self {
+        Self::new(f64::from_bits(bits))
+    }
+
+    /// The raw IEEE 754 bit pattern of the inner f64.
+    pub const fn to_bits(self) -> u64 {
+        self.inner.to_bits()
+    }
+
+    /// A key whose unsigned integer ordering matches the total order of
+    /// TotalFloat, so that comparing the keys of two values gives the same
+    /// result as comparing the values themselves.
+    ///
+    /// All NaN values map to 0 under NanLeast (or `u64::MAX` under
+    /// NanGreatest), and both zeros map to the same key. This is useful for
+    /// radix-style processing of TotalFloat values.
+    pub fn total_bits_key(self) -> u64 {
+        if self.is_nan() {
+            return match P::NAN_ORDERING {
+                Ordering::Greater => u64::MAX,
+                _ => 0,
+            };
+        }
+        // Fold -0.0 into +0.0, as they are equal.
+        let bits = if self.inner == 0.0 { 0 } else { self.to_bits() };
+        // Negative values have all their bits flipped, so that larger
+        // magnitudes give smaller keys, while positive values just have their
+        // sign bit set so that they are above all negative values.
+        if bits >> 63 == 1 { !bits } else { bits | 1 << 63 }
+    }
+
+    /// Compares two values under the IEEE 754-2008 totalOrder predicate.
+    ///
+    /// Unlike the default ordering of TotalFloat, this distinguishes -0.0 from
+    /// +0.0 (-0.0 is less), places NaNs with the sign bit set below negative
+    /// infinity and NaNs without it above positive infinity, and orders NaNs
+    /// further by their payload. This does not depend on the NaN policy.
+    pub fn ieee_total_cmp(&self, other: &Self) -> Ordering {
+        // Flipping every bit but the sign bit of negative values makes the
+        // two's complement ordering of the bits match totalOrder.
+        let mut a = self.to_bits() as i64;
+        let mut b = other.to_bits() as i64;
+        a ^= (((a >> 63) as u64) >> 1) as i64;
+        b ^= (((b >> 63) as u64) >> 1) as i64;
+        a.cmp(&b)
+    }
+
+    /// Compares two values under a strict refinement of the default ordering.
+    ///
+    /// Values are first ordered as by `Ord`, with any ties (which are between
+    /// zeros of different signs, or NaNs with different bit patterns) broken
+    /// by `ieee_total_cmp`, so -0.0 is less than +0.0. Only identical bit
+    /// patterns compare equal. See `StrictTotalFloat`.
+    pub fn strict_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other).then_with(|| self.ieee_total_cmp(other))
+    }
+
+    /// Normalizes the representation of this value, mapping every NaN to the
+    /// canonical quiet NaN (0x7ff8000000000000) and -0.0 to +0.0.
+    ///
+    /// Values that compare equal are bit-identical after canonicalization.
+    pub fn canonicalize(self) -> Self {
+        if self.is_nan() {
+            Self::from_bits(0x7ff8000000000000)
+        } else if self.inner == 0.0 {
+            Self::ZERO
+        } else {
+            self
+        }
+    }
+
+    /// Converts to an i64, saturating rather than failing.
+    ///
+    /// The value is truncated toward zero, values beyond the range of i64
+    /// (including the infinities) saturate to `i64::MIN` or `i64::MAX`, and
+    /// NaN becomes 0. Use `i64::try_from` to detect these cases instead.
+    pub fn to_int_lossy(self) -> i64 {
+        self.inner as i64
+    }
+
+    /// Whether this value is -0.0.
+    pub fn is_negative_zero(self) -> bool {
+        self.inner == 0.0 && self.is_sign_negative()
+    }
+
+    /// A value with the magnitude of `self` and the sign of `sign`.
+    pub fn copysign(self, sign: Self) -> Self {
+        Self::from(self.inner.copysign(sign.inner))
+    }
+
+    /// The sign of this value under the total order.
+    ///
+    /// This is -1.0 for values less than zero, 1.0 for values greater than
+    /// zero, and 0.0 for both zeros. As NaN is less than all other values
+    /// under NanLeast, the sign of NaN is -1.0 (and 1.0 under NanGreatest).
+    pub fn signum_total(self) -> Self {
+        match self.cmp(&Self::ZERO) {
+            Ordering::Less => Self::new(-1.0),
+            Ordering::Equal => Self::ZERO,
+            Ordering::Greater => Self::new(1.0),
+        }
+    }
+
+    /// Reinterprets a reference to an f64 as a reference to a TotalFloat.
+    pub fn from_ref(float: &f64) -> &Self {
+        // This is sound as TotalFloatWith is repr(transparent) over f64.
+        unsafe { &*(float as *const f64 as *const Self) }
+    }
+
+    /// Reinterprets a mutable reference to an f64 as a mutable reference to a
+    /// TotalFloat.
+    pub fn from_mut(float: &mut f64) -> &mut Self {
+        // This is sound as TotalFloatWith is repr(transparent) over f64.
+        unsafe { &mut *(float as *mut f64 as *mut Self) }
+    }
+
+    /// Reinterprets a slice of f64 as a slice of TotalFloat, without copying.
+    pub fn slice_from(slice: &[f64]) -> &[Self] {
+        // This is sound as TotalFloatWith is repr(transparent) over f64, so
+        // the two slices have identical layouts.
+        unsafe {
+            slice::from_raw_parts(slice.as_ptr() as *const Self, slice.len())
+        }
+    }
+
+    /// Reinterprets a mutable slice of f64 as a mutable slice of TotalFloat,
+    /// without copying.
+    pub fn slice_from_mut(slice: &mut [f64]) -> &mut [Self] {
+        // This is sound as TotalFloatWith is repr(transparent) over f64, so
+        // the two slices have identical layouts.
+        unsafe {
+            slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut Self,
+                                      slice.len())
+        }
+    }
+}
+
+// Generates inherent methods that forward to the f64 method of the same name,
+// but return a TotalFloat, so that chained computations stay in the wrapper.
+macro_rules! forward_math {
+    ($($(#[$attr:meta])* fn $method:ident($($arg:ident: $ty:ty),*);)*) => { $(
+        $(#[$attr])*
+        pub fn $method(self, $($arg: $ty),*) -> Self {
+            Self::from(self.inner.$method($($arg.into()),*))
+        }
+    )* }
+}
+
+// Math Implementations
+// These take precedence over the f64 methods reachable through Deref.
+impl<P: NanPolicy> TotalFloatWith<P> {
+    forward_math! {
+        /// The absolute value.
+        fn abs();
+        /// The reciprocal, `1 / self`.
+        fn recip();
+    }
+
+    /// The lesser of two values under the total order.
+    ///
+    /// Under NanLeast, as NaN is less than all other values, a NaN will always
+    /// be returned if either value is NaN.
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// The greater of two values under the total order.
+    ///
+    /// Under NanLeast, as NaN is less than all other values, a NaN will only
+    /// be returned if both values are NaN.
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    /// Restricts a value to the range `[lo, hi]` under the total order.
+    ///
+    /// Values already in range (including those equal to a bound, such as -0.0
+    /// against a bound of 0.0) are returned unchanged. Under NanLeast, as NaN
+    /// is less than all other values, a NaN is clamped to `lo`.
+    ///
+    /// In debug builds this panics if `lo > hi`.
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        debug_assert!(lo <= hi, "clamp requires lo <= hi");
+        if self < lo {
+            lo
+        } else if self > hi {
+            hi
+        } else {
+            self
+        }
+    }
+}
+
+// Math Implementations requiring std
+// These are not available in core, as they need the platform's maths library.
+#[cfg(feature = "std")]
+impl<P: NanPolicy> TotalFloatWith<P> {
+    forward_math! {
+        /// The square root, which is NaN for negative values.
+        fn sqrt();
+        /// Raises to an integer power.
+        fn powi(n: i32);
+        /// Raises to a floating point power.
+        fn powf(n: Self);
+        /// The exponential function, `e^self`.
+        fn exp();
+        /// The natural logarithm.
+        fn ln();
+        /// The base 10 logarithm.
+        fn log10();
+        /// The largest integer less than or equal to the value.
+        fn floor();
+        /// The smallest integer greater than or equal to the value.
+        fn ceil();
+        /// The nearest integer, rounding half-way cases away from zero.
+        fn round();
+        /// The integer part of the value.
+        fn trunc();
+        /// The fractional part of the value.
+        fn fract();
+        /// Computes `self * a + b` with only one rounding error.
+        fn mul_add(a: Self, b: Self);
+    }
+}
+
+// Copy and Clone are implemented by hand, as deriving them would require the
+// policy to be Copy and Clone too.
+impl<P: NanPolicy, F: RawFloat> Copy for TotalFloatWith<P, F> {}
+impl<P: NanPolicy, F: RawFloat> Clone for TotalFloatWith<P, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// Implement Deref and DerefMut to allow us to use the methods of the wrapped
+// float on TotalFloat.
 //
 // Deref and DerefMut allow for coercion to their Target type implicitly when
 // passing the source type to a function, or when calling an instance method.
 //
 // See https://doc.rust-lang.org/book/deref-coercions.html for more.
-impl Deref for TotalFloat {
-    type Target = f64;
+impl<P: NanPolicy, F: RawFloat> Deref for TotalFloatWith<P, F> {
+    type Target = F;
     fn deref(&self) -> &Self::Target {
         &self.inner
     }
 }
-impl DerefMut for TotalFloat {
+impl<P: NanPolicy, F: RawFloat> DerefMut for TotalFloatWith<P, F> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner
     }
 }
 
+// Implement AsRef and AsMut for generic code written against them.
+//
+// Borrow<f64> is deliberately not implemented, as f64's equality disagrees with
+// TotalFloat's for NaN, which would break the contract Borrow requires. To
+// look up an f64 in a collection of TotalFloat, use TotalFloat::from_ref.
+impl<P: NanPolicy> AsRef<f64> for TotalFloatWith<P> {
+    fn as_ref(&self) -> &f64 {
+        &self.inner
+    }
+}
+impl<P: NanPolicy> AsMut<f64> for TotalFloatWith<P> {
+    fn as_mut(&mut self) -> &mut f64 {
+        &mut self.inner
+    }
+}
+
 // Custom Equality Implementation
-impl Eq for TotalFloat {}
-impl PartialEq for TotalFloat {
+impl<P: NanPolicy, F: RawFloat> Eq for TotalFloatWith<P, F> {}
+impl<P: NanPolicy, F: RawFloat> PartialEq for TotalFloatWith<P, F> {
     fn eq(&self, other: &Self) -> bool {
-        (self.is_nan() && other.is_nan()) || self.inner.eq(&other.inner)
+        (self.inner.is_nan() && other.inner.is_nan())
+            || self.inner.eq(&other.inner)
     }
 }
 
 // Custom Ordering Implementation
-impl Ord for TotalFloat {
+// NaN values are placed according to the policy, and everything else is
+// ordered as it is for the wrapped float. See `RawFloat::cmp_with_nan`.
+impl<P: NanPolicy, F: RawFloat> Ord for TotalFloatWith<P, F> {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (self.is_nan(), other.is_nan()) {
-            (true, true) => Ordering::Equal,
-            (true, false) => Ordering::Less,
-            (false, true) => Ordering::Greater,
-            (false, false) => {
-                self.inner
-                    .partial_cmp(other)
-                    .expect("Unexpected Partial Comparison Failure")
-            }
-        }
+        self.inner.cmp_with_nan(other.inner, P::NAN_ORDERING)
     }
 }
-impl PartialOrd for TotalFloat {
+impl<P: NanPolicy, F: RawFloat> PartialOrd for TotalFloatWith<P, F> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
+// Cross-type Comparison Implementations
+// Comparisons against a plain f64 treat the f64 as a TotalFloat, so these use
+// the total order (a NaN on either side is placed by the policy) rather than
+// f64's partial order.
+impl<P: NanPolicy> PartialEq<f64> for TotalFloatWith<P> {
+    fn eq(&self, other: &f64) -> bool {
+        self.eq(Self::from_ref(other))
+    }
+}
+impl<P: NanPolicy> PartialEq<TotalFloatWith<P>> for f64 {
+    fn eq(&self, other: &TotalFloatWith<P>) -> bool {
+        <TotalFloatWith<P>>::from_ref(self).eq(other)
+    }
+}
+impl<P: NanPolicy> PartialOrd<f64> for TotalFloatWith<P> {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        Some(self.cmp(Self::from_ref(other)))
+    }
+}
+impl<P: NanPolicy> PartialOrd<TotalFloatWith<P>> for f64 {
+    fn partial_cmp(&self, other: &TotalFloatWith<P>) -> Option<Ordering> {
+        Some(<TotalFloatWith<P>>::from_ref(self).cmp(other))
+    }
+}
+
+// Custom Hash Implementation
+// This must be consistent with the equality implementation, so the canonical
+// bit pattern is hashed, meaning all NaN values hash the same, and -0.0 hashes
+// the same as 0.0.
+impl<P: NanPolicy> Hash for TotalFloatWith<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonicalize().to_bits().hash(state)
+    }
+}
+
 // Custom Debug Implementation
 // This facilitates printing of TotalFloat in a debug context, as if they were
-// f64.
-impl fmt::Debug for TotalFloat {
+// f64. The formatter is passed straight through so that flags such as width
+// and precision are respected.
+impl<P, F> fmt::Debug for TotalFloatWith<P, F>
+    where P: NanPolicy,
+          F: RawFloat + fmt::Debug
+{
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.inner)
+        fmt::Debug::fmt(&self.inner, f)
     }
 }
 
 // Custom Display Implementation
 // This facilitates printing of TotalFloat in a display context, as if they were
-// f64.
-impl fmt::Display for TotalFloat {
+// f64. The formatter is passed straight through so that flags such as width
+// and precision are respected.
+impl<P, F> fmt::Display for TotalFloatWith<P, F>
+    where P: NanPolicy,
+          F: RawFloat + fmt::Display
+{
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.inner)
+        fmt::Display::fmt(&self.inner, f)
     }
 }
 
-// Conversion wrapper from f64 to TotalFloat
-impl From<f64> for TotalFloat {
-    fn from(from: f64) -> TotalFloat {
-        TotalFloat { inner: from }
+// Custom Exponent Formatting Implementations
+// These facilitate printing of TotalFloat in scientific notation, as if they
+// were f64.
+impl<P, F> fmt::LowerExp for TotalFloatWith<P, F>
+    where P: NanPolicy,
+          F: RawFloat + fmt::LowerExp
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.inner, f)
+    }
+}
+impl<P, F> fmt::UpperExp for TotalFloatWith<P, F>
+    where P: NanPolicy,
+          F: RawFloat + fmt::UpperExp
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.inner, f)
+    }
+}
+
+// Default Implementation
+// The default TotalFloat is positive zero, matching the default f64.
+impl<P: NanPolicy> Default for TotalFloatWith<P> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+// Conversion wrapper from the wrapped float to TotalFloat
+impl<P: NanPolicy, F: RawFloat> From<F> for TotalFloatWith<P, F> {
+    fn from(from: F) -> Self {
+        Self::new(from)
+    }
+}
+
+// Lossless conversion wrapper from f32 to TotalFloat
+impl<P: NanPolicy> From<f32> for TotalFloatWith<P> {
+    fn from(from: f32) -> Self {
+        Self::new(f64::from(from))
     }
 }
 
 // Conversion wrapper from TotalFloat to f64
-impl From<TotalFloat> for f64 {
-    fn from(from: TotalFloat) -> f64 {
+impl<P: NanPolicy> From<TotalFloatWith<P>> for f64 {
+    fn from(from: TotalFloatWith<P>) -> f64 {
         from.inner
     }
 }
 
-/// Macro for converting f64 to TotalFloat.
+// Conversion wrappers between the NaN policies
+impl<F: RawFloat> From<TotalFloatWith<NanLeast, F>>
+    for TotalFloatWith<NanGreatest, F> {
+    fn from(from: TotalFloatWith<NanLeast, F>) -> Self {
+        from.with_policy()
+    }
+}
+impl<F: RawFloat> From<TotalFloatWith<NanGreatest, F>>
+    for TotalFloatWith<NanLeast, F> {
+    fn from(from: TotalFloatWith<NanGreatest, F>) -> Self {
+        from.with_policy()
+    }
+}
+
+/// The error returned when a string cannot be parsed as a TotalFloat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTotalFloatError {
+    input: String,
+}
+
+impl ParseTotalFloatError {
+    /// The input that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for ParseTotalFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid float literal: {:?}", self.input)
+    }
+}
+
+impl Error for ParseTotalFloatError {}
+
+// Parsing Implementations
+// The textual spellings of the special values are checked for first (ignoring
+// case), after which parsing is delegated to the inner float type.
+macro_rules! impl_from_str {
+    ($($float:ident),*) => { $(
+        impl<P: NanPolicy> FromStr for TotalFloatWith<P, $float> {
+            type Err = ParseTotalFloatError;
+            fn from_str(s: &str) -> Result<Self, ParseTotalFloatError> {
+                let special = match s.to_ascii_lowercase().as_str() {
+                    "nan" => Some($float::NAN),
+                    "inf" | "+inf" | "infinity" | "+infinity" => {
+                        Some($float::INFINITY)
+                    }
+                    "-inf" | "-infinity" => Some($float::NEG_INFINITY),
+                    _ => None,
+                };
+                match special {
+                    Some(float) => Ok(Self::new(float)),
+                    None => {
+                        s.parse::<$float>().map(Self::new).map_err(|_| {
+                            ParseTotalFloatError { input: s.to_owned() }
+                        })
+                    }
+                }
+            }
+        }
+    )* }
+}
+
+impl_from_str!(f64, f32);
+
+// Arithmetic Implementations
+// These delegate to the inner float arithmetic, so results follow IEEE
+// semantics (including producing NaN where IEEE says so), but are returned as
+// TotalFloat so that they can continue to be compared and sorted.
+macro_rules! impl_binary_op {
+    ($float:ident: $($trait:ident, $method:ident);*) => { $(
+        impl<P: NanPolicy> ops::$trait for TotalFloatWith<P, $float> {
+            type Output = Self;
+            fn $method(self, other: Self) -> Self {
+                Self::new(ops::$trait::$method(self.inner, other.inner))
+            }
+        }
+        impl<P: NanPolicy> ops::$trait<$float> for TotalFloatWith<P, $float> {
+            type Output = Self;
+            fn $method(self, other: $float) -> Self {
+                Self::new(ops::$trait::$method(self.inner, other))
+            }
+        }
+    )* }
+}
+
+impl_binary_op! {
+    f64:
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+    Rem, rem
+}
+
+impl_binary_op! {
+    f32:
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+    Rem, rem
+}
+
+// Compound Assignment Implementations
+// As with the arithmetic implementations these delegate to the inner float, so
+// `total += tf!(1.0)` and `total += 1.0` both update the TotalFloat in place.
+macro_rules! impl_assign_op {
+    ($float:ident: $($trait:ident, $method:ident);*) => { $(
+        impl<P: NanPolicy> ops::$trait for TotalFloatWith<P, $float> {
+            fn $method(&mut self, other: Self) {
+                ops::$trait::$method(&mut self.inner, other.inner)
+            }
+        }
+        impl<P: NanPolicy> ops::$trait<$float> for TotalFloatWith<P, $float> {
+            fn $method(&mut self, other: $float) {
+                ops::$trait::$method(&mut self.inner, other)
+            }
+        }
+    )* }
+}
+
+impl_assign_op! {
+    f64:
+    AddAssign, add_assign;
+    SubAssign, sub_assign;
+    MulAssign, mul_assign;
+    DivAssign, div_assign;
+    RemAssign, rem_assign
+}
+
+impl_assign_op! {
+    f32:
+    AddAssign, add_assign;
+    SubAssign, sub_assign;
+    MulAssign, mul_assign;
+    DivAssign, div_assign;
+    RemAssign, rem_assign
+}
+
+// Negation, Sum and Product Implementations
+// Negation flips the sign bit, so -(0.0) is -0.0, -(-0.0) is 0.0, and the
+// negation of a NaN is still a NaN. The empty sum is 0.0 and the empty product
+// is 1.0, and NaN propagates through both.
+macro_rules! impl_neg_sum_product {
+    ($($float:ident),*) => { $(
+        impl<P: NanPolicy> ops::Neg for TotalFloatWith<P, $float> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self::new(-self.inner)
+            }
+        }
+        impl<P: NanPolicy> Sum for TotalFloatWith<P, $float> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |acc, x| acc + x)
+            }
+        }
+        impl<'a, P: NanPolicy + 'a> Sum<&'a TotalFloatWith<P, $float>>
+            for TotalFloatWith<P, $float> {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.cloned().sum()
+            }
+        }
+        impl<P: NanPolicy> Product for TotalFloatWith<P, $float> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::new(1.0), |acc, x| acc * x)
+            }
+        }
+        impl<'a, P: NanPolicy + 'a> Product<&'a TotalFloatWith<P, $float>>
+            for TotalFloatWith<P, $float> {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.cloned().product()
+            }
+        }
+    )* }
+}
+
+impl_neg_sum_product!(f64, f32);
+
+/// Macro for converting f64 (or any other type with a lossless conversion,
+/// such as f32 and the smaller integers) to TotalFloat.
 #[macro_export]
 macro_rules! tf {
-    ($float:expr) => { $crate::TotalFloat::from($float) }
+    ($float:expr) => {{
+        let float: $crate::TotalFloat = ($float).into();
+        float
+    }}
 }
 
 /// Macro for creating lists of TotalFloats.
 #[macro_export]
 macro_rules! tfvec {
-    [$($float:expr),*] => {
-        vec![
+    [$($float:expr),*] => {{
+        // The annotation keeps the type of an empty list unambiguous.
+        let list: $crate::__Vec<$crate::TotalFloat> = $crate::__Vec::from([
             $(
                 tf!($float)
             ),*
-        ]
+        ]);
+        list
+    }}
+}
+
+/// The order in which to sort values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Least first.
+    Ascending,
+    /// Greatest first.
+    Descending,
+}
+
+/// Options for tuning the sorts of `merge_sort_with` and `sort_auto_with`.
+///
+/// `SortConfig::new()` gives the configuration that `merge_sort` and
+/// `sort_auto` use, and each setting has a builder method, as in
+/// `SortConfig::new().insertion_cutoff(48).order(SortOrder::Descending)`.
+/// Options that cannot be combined, such as dropping NaNs and returning an
+/// error for them, are variants of the same enum, so cannot be set at once.
+///
+/// `sort_auto_with` always sorts in ascending order with NaNs first, and uses
+/// only the algorithm settings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SortConfig {
+    insertion_cutoff: usize,
+    min_gallop: usize,
+    order: SortOrder,
+    nan_placement: NanPlacement,
+    presorted: bool,
+    radix_threshold: usize,
+    parallel_threshold: usize,
+    force: Option<Algorithm>,
+}
+
+impl SortConfig {
+    /// The default configuration.
+    pub const fn new() -> SortConfig {
+        SortConfig {
+            insertion_cutoff: 32,
+            min_gallop: 7,
+            order: SortOrder::Ascending,
+            nan_placement: NanPlacement::First,
+            presorted: true,
+            radix_threshold: 1 << 12,
+            parallel_threshold: 1 << 20,
+            force: None,
+        }
+    }
+
+    /// Sets the length of run, 32 by default, below which binary insertion
+    /// sort is used rather than further merging.
+    ///
+    /// This only affects performance, not the result. A cutoff of 0 or 1
+    /// merges all the way down to single elements.
+    pub fn insertion_cutoff(mut self, cutoff: usize) -> SortConfig {
+        self.insertion_cutoff = cutoff;
+        self
+    }
+
+    /// Sets the number of times in a row, 7 by default, that one run must win
+    /// a merge before the rest of its winning stretch is found by galloping
+    /// (exponential and then binary search) rather than one value at a time.
+    ///
+    /// This only affects performance, not the result. Galloping pays off when
+    /// runs interleave in long stretches, and `usize::MAX` disables it.
+    pub fn min_gallop(mut self, min_gallop: usize) -> SortConfig {
+        self.min_gallop = min_gallop;
+        self
+    }
+
+    /// Sets the order to sort in, ascending by default.
+    ///
+    /// Equal values keep their relative order in either order, as the
+    /// comparison is reversed rather than the result.
+    pub fn order(mut self, order: SortOrder) -> SortConfig {
+        self.order = order;
+        self
+    }
+
+    /// Sets what is done with NaN values, which by default are placed first.
+    ///
+    /// The placement does not depend on the order, so NaNs placed first come
+    /// first whether the rest are ascending or descending.
+    pub fn nan_placement(mut self, placement: NanPlacement) -> SortConfig {
+        self.nan_placement = placement;
+        self
+    }
+
+    /// Sets whether to first check for input that is already ascending or
+    /// strictly descending, which is then sorted in a single linear scan. This
+    /// is on by default.
+    ///
+    /// This only affects performance, not the result. Turning it off saves a
+    /// scan of input that is known to be unsorted.
+    pub fn presorted(mut self, presorted: bool) -> SortConfig {
+        self.presorted = presorted;
+        self
+    }
+
+    /// Sets the length, 4,096 by default, from which `sort_auto_with` radix
+    /// sorts input that is not mostly sorted.
+    pub fn radix_threshold(mut self, threshold: usize) -> SortConfig {
+        self.radix_threshold = threshold;
+        self
+    }
+
+    /// Sets the length, 1,048,576 by default, from which `sort_auto_with`
+    /// sorts input that is not mostly sorted in parallel. This only has an
+    /// effect with the `rayon` feature.
+    pub fn parallel_threshold(mut self, threshold: usize) -> SortConfig {
+        self.parallel_threshold = threshold;
+        self
+    }
+
+    /// Makes `sort_auto_with` use the given algorithm, whatever the input.
+    pub fn force(mut self, algorithm: Algorithm) -> SortConfig {
+        self.force = Some(algorithm);
+        self
     }
 }
 
-/// Sorts a list of TotalFloat values.
-pub fn merge_sort(mut input: Vec<TotalFloat>) -> Vec<TotalFloat> {
-    let n = input.len();
-    // If there is one element or less of input, we cannot split up the list so
-    // it should simply be returned. Otherwise, recursively call merge_sort on
-    // the left and right half of the list.
-    if n <= 1 {
-        input
-    } else {
-        merge(// Takes half of the input (removing it) and merge_sorts it
-              merge_sort(input.split_off(n / 2)),
-              // Takes the remaning half of the input and merge_sorts it
-              merge_sort(input))
+impl Default for SortConfig {
+    fn default() -> SortConfig {
+        SortConfig::new()
+    }
+}
+
+/// The lesser of two f64 values under the total order of TotalFloat.
+///
+/// As NaN is less than all other values, a NaN will always be returned if
+/// either value is NaN.
+pub fn total_min(a: f64, b: f64) -> f64 {
+    tf!(a).min(tf!(b)).inner
+}
+
+/// The greater of two f64 values under the total order of TotalFloat.
+///
+/// As NaN is less than all other values, a NaN will only be returned if both
+/// values are NaN.
+pub fn total_max(a: f64, b: f64) -> f64 {
+    tf!(a).max(tf!(b)).inner
+}
+
+/// Restricts an f64 value to the range `[lo, hi]` under the total order of
+/// TotalFloat.
+///
+/// See `TotalFloat::clamp`.
+pub fn total_clamp(x: f64, lo: f64, hi: f64) -> f64 {
+    tf!(x).clamp(tf!(lo), tf!(hi)).inner
+}
+
+/// Compares two f64 values under the total order of TotalFloat.
+///
+/// This has a signature compatible with `slice::sort_by`, so a `Vec<f64>` can
+/// be sorted with `v.sort_by(fc_sort::total_cmp)` without converting it.
+#[inline]
+pub fn total_cmp(a: &f64, b: &f64) -> Ordering {
+    TotalFloat::from_ref(a).cmp(TotalFloat::from_ref(b))
+}
+
+/// Builds a comparator, for use with `slice::sort_by`, that compares values by
+/// an f64 key under the total order of TotalFloat.
+pub fn total_cmp_by_key<T, F>(mut key: F) -> impl FnMut(&T, &T) -> Ordering
+    where F: FnMut(&T) -> f64
+{
+    move |a, b| total_cmp(&key(a), &key(b))
+}
+
+// Vec::from_raw_parts requires the new element type to have the same size and
+// alignment as the one the allocation was made for. This is guaranteed by
+// repr(transparent), and checked here so that the guarantee cannot be lost
+// silently.
+fn assert_same_layout() {
+    assert_eq!(mem::size_of::<TotalFloat>(), mem::size_of::<f64>());
+    assert_eq!(mem::align_of::<TotalFloat>(), mem::align_of::<f64>());
+}
+
+/// Converts a Vec of f64 into a Vec of TotalFloat in O(1), reusing the
+/// allocation without copying.
+pub fn wrap_vec(input: Vec<f64>) -> Vec<TotalFloat> {
+    assert_same_layout();
+    let mut input = ManuallyDrop::new(input);
+    // This is sound as the allocation is handed over whole, with its length
+    // and capacity, and the layouts of the element types are identical.
+    unsafe {
+        Vec::from_raw_parts(input.as_mut_ptr() as *mut TotalFloat,
+                            input.len(),
+                            input.capacity())
     }
 }
 
-/// Merges two lists of TotalFloat values into an ordered list of TotalFloat
-/// values.
-pub fn merge(mut a: Vec<TotalFloat>,
-             mut b: Vec<TotalFloat>)
-             -> Vec<TotalFloat> {
+/// Converts a Vec of TotalFloat back into a Vec of f64 in O(1), reusing the
+/// allocation without copying.
+pub fn unwrap_vec(input: Vec<TotalFloat>) -> Vec<f64> {
+    assert_same_layout();
+    let mut input = ManuallyDrop::new(input);
+    // This is sound for the same reasons as in wrap_vec.
+    unsafe {
+        Vec::from_raw_parts(input.as_mut_ptr() as *mut f64,
+                            input.len(),
+                            input.capacity())
+    }
+}
+
+/// Reinterprets a slice of f64 as a slice of TotalFloat, without copying.
+///
+/// See `TotalFloat::slice_from`.
+pub fn wrap_slice(input: &[f64]) -> &[TotalFloat] {
+    TotalFloat::slice_from(input)
+}
+
+/// Reinterprets a mutable slice of f64 as a mutable slice of TotalFloat,
+/// without copying.
+///
+/// See `TotalFloat::slice_from_mut`.
+pub fn wrap_slice_mut(input: &mut [f64]) -> &mut [TotalFloat] {
+    TotalFloat::slice_from_mut(input)
+}
+
+/// Checks whether a slice of TotalFloat values is in ascending order.
+pub fn is_sorted<P, F>(input: &[TotalFloatWith<P, F>]) -> bool
+    where P: NanPolicy,
+          F: RawFloat
+{
+    is_sorted_by(input, TotalFloatWith::cmp)
+}
+
+/// Checks whether a slice of values is in ascending order under the given
+/// comparator, that is, whether no value compares greater than the one after
+/// it.
+pub fn is_sorted_by<T, F>(input: &[T], mut cmp: F) -> bool
+    where F: FnMut(&T, &T) -> Ordering
+{
+    input.windows(2).all(|w| cmp(&w[0], &w[1]) != Ordering::Greater)
+}
+
+/// Sorts a list of values, such as TotalFloat values, by their total order.
+///
+/// The sort is stable, so equal values (such as NaNs with different payloads)
+/// keep their relative order. Input that is already in ascending order is
+/// returned untouched, and input in strictly descending order is reversed,
+/// each after a single linear scan. See `merge_sort_in_place`.
+pub fn merge_sort<T: Ord>(mut input: Vec<T>) -> Vec<T> {
+    merge_sort_in_place(&mut input);
+    input
+}
+
+/// Sorts a slice of values, such as TotalFloat values, in place.
+///
+/// This makes a single scratch allocation of the length of the slice, and
+/// leaves the slice where it is, so it can sort data inside a larger
+/// structure or buffer. As with `merge_sort`, presorted input takes a single
+/// linear scan.
+pub fn merge_sort_in_place<T: Ord>(input: &mut [T]) {
+    sort_slice_by(input, &mut T::cmp);
+}
+
+/// Sorts a list of TotalFloat values, as tuned by the given configuration.
+///
+/// With the default configuration this is `merge_sort`, apart from wrapping
+/// the result in `Ok`. An error is only returned under `NanPlacement::Error`,
+/// and under `NanPlacement::Drop` the number of NaNs dropped is the difference
+/// in length between the input and the result. See `SortConfig`.
+pub fn merge_sort_with<P>(mut input: Vec<TotalFloatWith<P>>,
+                          config: &SortConfig)
+                          -> Result<Vec<TotalFloatWith<P>>, NanFound>
+    where P: NanPolicy
+{
+    // Whether NaNs would end up first if sorted along with everything else.
+    let nans_first = (P::NAN_ORDERING == Ordering::Less) ==
+                     (config.order == SortOrder::Ascending);
+    // Where the order puts NaNs where they are wanted, they are sorted along
+    // with everything else. Otherwise they are moved out of the way, keeping
+    // their relative order, and put back once the rest are sorted.
+    let nans = match config.nan_placement {
+        NanPlacement::First if nans_first => Vec::new(),
+        NanPlacement::Last if !nans_first => Vec::new(),
+        NanPlacement::Error => {
+            if let Some(index) = input.iter().position(|x| x.is_nan()) {
+                return Err(NanFound { index });
+            }
+            Vec::new()
+        }
+        _ => {
+            let nans = input.iter().filter(|x| x.is_nan()).cloned().collect();
+            input.retain(|x| !x.is_nan());
+            nans
+        }
+    };
+    match config.order {
+        SortOrder::Ascending => {
+            sort_slice_with(&mut input, config, &mut TotalFloatWith::cmp)
+        }
+        SortOrder::Descending => {
+            sort_slice_with(&mut input, config, &mut |a, b| b.cmp(a))
+        }
+    }
+    match config.nan_placement {
+        NanPlacement::First if !nans.is_empty() => {
+            let mut nans = nans;
+            nans.append(&mut input);
+            Ok(nans)
+        }
+        NanPlacement::Last => {
+            let mut nans = nans;
+            input.append(&mut nans);
+            Ok(input)
+        }
+        _ => Ok(input),
+    }
+}
+
+/// Sorts a slice of values, such as TotalFloat values, in place, using the
+/// given scratch buffer.
+///
+/// This is the sort of `merge_sort`, but whatever the scratch buffer holds is
+/// discarded, and it is grown only if its capacity is less than the length of
+/// the slice. Reusing one scratch buffer across many sorts of similar lengths
+/// saves allocating for each of them.
+pub fn merge_sort_with_buffer<T: Ord>(input: &mut [T], scratch: &mut Vec<T>) {
+    if !presorted_by(input, &mut T::cmp, &mut ()) {
+        scratch.clear();
+        scratch.reserve(input.len());
+        merge_sort_slice_by(input,
+                            scratch,
+                            &SortConfig::new(),
+                            &mut T::cmp,
+                            &mut ());
+    }
+}
+
+/// Sorts a list of values, such as TotalFloat values, in descending order.
+///
+/// The comparison is reversed, rather than the sorted list, so the sort is
+/// still stable: equal values keep their original relative order. As NaN is
+/// least, NaNs come last.
+pub fn merge_sort_desc<T: Ord>(input: Vec<T>) -> Vec<T> {
+    merge_sort_by(input, |a, b| b.cmp(a))
+}
+
+/// Sorts a slice of f64 in place, under the total order of TotalFloat.
+///
+/// The slice is sorted as TotalFloat values without copying, see
+/// `wrap_slice_mut`, so NaNs come first, and the sort is stable, so -0.0 and
+/// 0.0 keep their relative order, as do NaNs with different payloads.
+pub fn sort_f64(input: &mut [f64]) {
+    merge_sort_in_place(wrap_slice_mut(input));
+}
+
+/// Sorts a slice of f64 in place in descending order, under the total order
+/// of TotalFloat.
+///
+/// As with `merge_sort_desc` the comparison is reversed, so the sort is still
+/// stable, and NaNs come last.
+pub fn sort_f64_desc(input: &mut [f64]) {
+    sort_slice_by(wrap_slice_mut(input), &mut |a: &TotalFloat, b| b.cmp(a));
+}
+
+/// Sorts a list of values, such as TotalFloat values, in the given order.
+///
+/// See `merge_sort` and `merge_sort_desc`.
+pub fn merge_sort_ordered<T: Ord>(input: Vec<T>, order: SortOrder) -> Vec<T> {
+    match order {
+        SortOrder::Ascending => merge_sort(input),
+        SortOrder::Descending => merge_sort_desc(input),
+    }
+}
+
+/// Sorts a list of TotalFloat values under the IEEE 754 totalOrder predicate.
+///
+/// See `TotalFloat::ieee_total_cmp`.
+pub fn merge_sort_ieee<P: NanPolicy>(input: Vec<TotalFloatWith<P>>)
+                                     -> Vec<TotalFloatWith<P>> {
+    merge_sort_by(input, TotalFloatWith::ieee_total_cmp)
+}
+
+/// Canonicalizes every value in a slice of TotalFloat values in place.
+///
+/// See `TotalFloat::canonicalize`.
+pub fn canonicalize_slice<P: NanPolicy>(input: &mut [TotalFloatWith<P>]) {
+    for x in input.iter_mut() {
+        *x = x.canonicalize();
+    }
+}
+
+/// Sorts a list of TotalFloat values, canonicalizing them as it sorts.
+///
+/// Each value is canonicalized, as by `canonicalize_slice`, as the sort first
+/// scans the list for presorted order, so this takes no more passes than
+/// `merge_sort`. As equal values are identical once canonicalized, the result
+/// does not depend on stability.
+pub fn merge_sort_canonical<P: NanPolicy>(mut input: Vec<TotalFloatWith<P>>)
+                                          -> Vec<TotalFloatWith<P>> {
+    let presorted = presorted_visiting_by(&mut input,
+                                          &mut |x| *x = x.canonicalize(),
+                                          &mut TotalFloatWith::cmp,
+                                          &mut ());
+    if !presorted {
+        let mut scratch = Vec::with_capacity(input.len());
+        merge_sort_slice_by(&mut input,
+                            &mut scratch,
+                            &SortConfig::new(),
+                            &mut TotalFloatWith::cmp,
+                            &mut ());
+    }
+    input
+}
+
+/// Merges two ordered lists of values, such as TotalFloat values, into a
+/// single ordered list.
+///
+/// Where values are equal, those from `a` come first. The inputs are only
+/// checked to be in order in debug builds, see `checked_merge`.
+pub fn merge<T: Ord>(a: Vec<T>, b: Vec<T>) -> Vec<T> {
+    debug_assert!(is_sorted_by(&a, T::cmp), "merge input a is not sorted");
+    debug_assert!(is_sorted_by(&b, T::cmp), "merge input b is not sorted");
+    merge_by(a, b, T::cmp)
+}
+
+/// Sorts a list of values by the given comparator.
+///
+/// As with `merge_sort` the sort is stable, so values the comparator finds
+/// equal keep their relative order. If the comparator panics, every value is
+/// still dropped exactly once as the list unwinds.
+pub fn merge_sort_by<T, F>(mut input: Vec<T>, mut cmp: F) -> Vec<T>
+    where F: FnMut(&T, &T) -> Ordering
+{
+    sort_slice_by(&mut input, &mut cmp);
+    input
+}
+
+/// Sorts a list of values by an f64 key, under the total order of TotalFloat.
+///
+/// This saves wrapping the keys to avoid the partial ordering of f64, so NaN
+/// keys are least. The key is computed for every comparison, see
+/// `total_cmp_by_key`.
+pub fn merge_sort_by_total_key<T, F>(input: Vec<T>, key: F) -> Vec<T>
+    where F: FnMut(&T) -> f64
+{
+    merge_sort_by(input, total_cmp_by_key(key))
+}
+
+/// Sorts a list of values by a key.
+///
+/// The sort is stable. The key is computed for every comparison, so about
+/// `2 n log n` times, see `merge_sort_by_cached_key` for expensive keys.
+pub fn merge_sort_by_key<T, K, F>(input: Vec<T>, mut key: F) -> Vec<T>
+    where K: Ord,
+          F: FnMut(&T) -> K
+{
+    merge_sort_by(input, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Sorts a list of values by a key, computing the key exactly once for each
+/// value.
+///
+/// The keys are sorted alongside the indices of their values, and the values
+/// then put into that order. The sort is stable.
+pub fn merge_sort_by_cached_key<T, K, F>(mut input: Vec<T>, key: F) -> Vec<T>
+    where K: Ord,
+          F: FnMut(&T) -> K
+{
+    let keys = input.iter().map(key).zip(0..).collect();
+    let mut keys = merge_sort_by(keys, |a: &(K, usize), b| a.0.cmp(&b.0));
+    // Apply the permutation by swapping each value into place. Values swapped
+    // out of the way are followed along the indices of earlier swaps.
+    for i in 0..keys.len() {
+        let mut index = keys[i].1;
+        while index < i {
+            index = keys[index].1;
+        }
+        keys[i].1 = index;
+        input.swap(i, index);
+    }
+    input
+}
+
+/// Sorts a list of values by an f64 key, under the total order of TotalFloat,
+/// computing the key exactly once for each value.
+///
+/// See `merge_sort_by_cached_key` and `merge_sort_by_total_key`.
+pub fn merge_sort_by_cached_total_key<T, F>(input: Vec<T>, mut key: F) -> Vec<T>
+    where F: FnMut(&T) -> f64
+{
+    merge_sort_by_cached_key(input, |x| TotalFloat::new(key(x)))
+}
+
+/// Merges two lists of values, each ordered by the given comparator, into a
+/// single list ordered by the comparator.
+///
+/// Where values are equal, those from `a` come first.
+pub fn merge_by<T, F>(a: Vec<T>, b: Vec<T>, mut cmp: F) -> Vec<T>
+    where F: FnMut(&T, &T) -> Ordering
+{
     // Declare a new buffer to be our returning data.
     // Size it such that it will not reallocate.
     let mut buffer = Vec::with_capacity(a.len() + b.len());
 
-    // Create drains of `a` and `b`, these are iterators that remove elements as
-    // they are returned.
-    let mut a = a.drain(..);
-    let mut b = b.drain(..);
-
-    // Access the first elements of a and b
-    let mut next_a = a.next();
-    let mut next_b = b.next();
-
-    // Repeat until internal break condition met, which will be when a and b are
-    // both empty.
-    loop {
-        match (next_a, next_b) {
-            // If a and b are both not empty
-            (Some(at), Some(bt)) => {
-                // Push the lesser element to the buffer, and advance it's
-                // iterator.
-                if at > bt {
-                    buffer.push(bt);
-                    next_b = b.next();
-                } else {
-                    buffer.push(at);
-                    next_a = a.next();
-                }
-            }
-            // If a is not empty, and b is
-            (Some(at), None) => {
-                // Push the yielded element of a to the buffer, and advance a's
-                // iterator.
-                buffer.push(at);
-                next_a = a.next();
-            }
-            // If b is not empty, and a is
-            (None, Some(bt)) => {
-                // Push the yielded element of b to the buffer, and advance b's
-                // iterator.
-                buffer.push(bt);
-                next_b = b.next();
+    // Merge into the buffer, moving the elements out of `a` and `b`.
+    merge_iter_by(a.into_iter(), b.into_iter(), &mut buffer, &mut cmp);
+
+    // Return the buffer
+    buffer
+}
+
+/// Merges two ordered slices of TotalFloat values, appending the result to
+/// `out`.
+///
+/// This is `merge` for values that are only borrowed, so where values are
+/// equal, those from `a` come first. The contents of `out` are kept, and
+/// exactly enough space is reserved for the result.
+pub fn merge_into(a: &[TotalFloat],
+                  b: &[TotalFloat],
+                  out: &mut Vec<TotalFloat>) {
+    out.reserve_exact(a.len() + b.len());
+    let (a, b) = (a.iter().cloned(), b.iter().cloned());
+    merge_iter_by(a, b, out, &mut TotalFloat::cmp);
+}
+
+/// Merges two ordered slices of TotalFloat values into a new list.
+///
+/// See `merge_into`.
+pub fn merge_slices(a: &[TotalFloat], b: &[TotalFloat]) -> Vec<TotalFloat> {
+    let mut out = Vec::new();
+    merge_into(a, b, &mut out);
+    out
+}
+
+/// Merges an ordered slice of TotalFloat values into an ordered Vec of them,
+/// in place.
+///
+/// The Vec is grown by the length of the batch and merged from the back, so
+/// this takes `O(n + m)` time and allocates nothing beyond the growth. Where
+/// values are equal, those already in the Vec come first. The batch is only
+/// checked to be in order in debug builds, so sort it first if it may not be.
+pub fn merge_in_place(base: &mut Vec<TotalFloat>, batch: &[TotalFloat]) {
+    debug_assert!(is_sorted(base), "merge_in_place base is not sorted");
+    debug_assert!(is_sorted(batch), "merge_in_place batch is not sorted");
+    let (mut i, mut j) = (base.len(), batch.len());
+    base.extend_from_slice(batch);
+    // Fill from the back with the greater of the values left, preferring the
+    // batch's if they are equal, as its values come after the base's. Once the
+    // batch is used up, the rest of the base is already in place.
+    while j > 0 {
+        let take_base = i > 0 && base[i - 1] > batch[j - 1];
+        if take_base {
+            base[i + j - 1] = base[i - 1];
+            i -= 1;
+        } else {
+            base[i + j - 1] = batch[j - 1];
+            j -= 1;
+        }
+    }
+}
+
+/// Inserts a value into an ordered Vec of TotalFloat values, after any values
+/// equal to it, and returns the index it was inserted at.
+///
+/// The position is found by binary search, see `upper_bound`.
+pub fn insert_sorted(base: &mut Vec<TotalFloat>, value: TotalFloat) -> usize {
+    let index = base.partition_point(|x| *x <= value);
+    base.insert(index, value);
+    index
+}
+
+// Merges two iterators over values ordered by the comparator, pushing the
+// result to the buffer, with values from `a` winning ties.
+fn merge_iter_by<T, I, F>(a: I, b: I, buffer: &mut Vec<T>, cmp: &mut F)
+    where I: Iterator<Item = T>,
+          F: FnMut(&T, &T) -> Ordering
+{
+    // Make the iterators peekable, so the heads can be compared before either
+    // is taken.
+    let mut a = a.peekable();
+    let mut b = b.peekable();
+
+    // Repeat while a and b are both not empty.
+    while let (Some(at), Some(bt)) = (a.peek(), b.peek()) {
+        // Take the lesser element, preferring a's if they are equal, pushing
+        // it to the buffer and advancing its iterator.
+        let take_a = cmp(at, bt) != Ordering::Greater;
+        buffer.extend(if take_a { a.next() } else { b.next() });
+    }
+
+    // At most one of a and b has elements left, which are already in order,
+    // so they are appended all at once rather than compared one by one.
+    buffer.extend(a);
+    buffer.extend(b);
+}
+
+// Puts a list of values in order if it is already ascending or strictly
+// descending, returning whether it did so. Only strictly descending input is
+// reversed, as reversing equal values would change their relative order.
+// Each neighbouring pair is compared once, so this takes n - 1 comparisons.
+fn presorted_by<T, F, C>(input: &mut [T], cmp: &mut F, counter: &mut C) -> bool
+    where F: FnMut(&T, &T) -> Ordering,
+          C: Counter
+{
+    presorted_visiting_by(input, &mut |_| {}, cmp, counter)
+}
+
+// As presorted_by, first visiting each value as it is scanned. Every value is
+// visited exactly once, even once the input is known not to be presorted, so
+// that the rest of the sort sees only visited values.
+fn presorted_visiting_by<T, V, F, C>(input: &mut [T],
+                                     visit: &mut V,
+                                     cmp: &mut F,
+                                     counter: &mut C)
+                                     -> bool
+    where V: FnMut(&mut T),
+          F: FnMut(&T, &T) -> Ordering,
+          C: Counter
+{
+    let n = input.len();
+    if n < 2 {
+        input.iter_mut().for_each(visit);
+        return true;
+    }
+    visit(&mut input[0]);
+    visit(&mut input[1]);
+    let descending = cmp(&input[0], &input[1]) == Ordering::Greater;
+    let mut end = 2;
+    while end < n {
+        visit(&mut input[end]);
+        let greater = cmp(&input[end - 1], &input[end]) == Ordering::Greater;
+        end += 1;
+        if greater != descending {
+            input[end..].iter_mut().for_each(visit);
+            return false;
+        }
+    }
+    if descending {
+        input.reverse();
+        counter.moved(n / 2 * 2);
+        counter.inverted(pairs(n));
+    }
+    true
+}
+
+// Sorts a slice of values in place, ordering them by the given comparator,
+// with the presorted fast path.
+fn sort_slice_by<T, F>(input: &mut [T], cmp: &mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    sort_slice_with(input, &SortConfig::new(), cmp);
+}
+
+// As sort_slice_by, as tuned by the given configuration.
+fn sort_slice_with<T, F>(input: &mut [T], config: &SortConfig, cmp: &mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    sort_slice_counting(input, config, cmp, &mut ());
+}
+
+// As sort_slice_with, tallying the work done with the given counter.
+fn sort_slice_counting<T, F, C>(input: &mut [T],
+                                config: &SortConfig,
+                                cmp: &mut F,
+                                counter: &mut C)
+    where F: FnMut(&T, &T) -> Ordering,
+          C: Counter
+{
+    if !(config.presorted && presorted_by(input, cmp, counter)) {
+        let mut scratch = Vec::with_capacity(input.len());
+        if !input.is_empty() {
+            counter.allocated();
+        }
+        merge_sort_slice_by(input, &mut scratch, config, cmp, counter);
+    }
+}
+
+// Tallies the work done by a sort, for `merge_sort_with_stats`. Each method
+// does nothing by default, and the unit type tallies nothing, so that in
+// every other sort the counting compiles away entirely.
+trait Counter {
+    // Some values were moved to new positions.
+    fn moved(&mut self, _count: usize) {}
+
+    // Two runs were merged.
+    fn merged(&mut self) {}
+
+    // A run was pushed onto the stack, which is now this many runs deep.
+    fn pushed_run(&mut self, _depth: usize) {}
+
+    // A scratch buffer was allocated.
+    fn allocated(&mut self) {}
+
+    // This many pairs of values, each out of order, were put in order.
+    fn inverted(&mut self, _count: u64) {}
+}
+
+impl Counter for () {}
+
+// The number of pairs of n values, n * (n - 1) / 2, without overflowing
+// before the division.
+fn pairs(n: usize) -> u64 {
+    let n = n as u64;
+    if n.is_multiple_of(2) {
+        n / 2 * n.saturating_sub(1)
+    } else {
+        (n - 1) / 2 * n
+    }
+}
+
+// Sorts a slice of values in place, ordering them by the given comparator.
+//
+// This is a natural merge sort, in the style of timsort. The slice is scanned
+// for maximal runs that are already ascending, or strictly descending (which
+// are reversed, as reversing equal values would break stability). Runs shorter
+// than the cutoff are extended to it by insertion sort. Each run is pushed onto
+// a stack, and neighbouring runs on the stack are merged whenever their lengths
+// break the invariants below, so that merges stay balanced. Sorted input is a
+// single run, and input of k runs takes O(n log k) time.
+//
+// The scratch buffer must be empty, with capacity for the whole slice. Only
+// its spare capacity is used, so it never owns the values copied into it and
+// never reallocates. The stack of runs is a fixed array, so the sort makes no
+// allocations of its own.
+fn merge_sort_slice_by<T, F, C>(input: &mut [T],
+                                scratch: &mut Vec<T>,
+                                config: &SortConfig,
+                                cmp: &mut F,
+                                counter: &mut C)
+    where F: FnMut(&T, &T) -> Ordering,
+          C: Counter
+{
+    let n = input.len();
+    let cutoff = config.insertion_cutoff;
+    // The start and length of each run on the stack, in order, of which the
+    // first `k` are in use.
+    let mut runs = [(0, 0); MAX_RUNS];
+    let mut k = 0;
+    let mut start = 0;
+    while start < n {
+        let mut len = find_run_by(&mut input[start..], cmp, counter);
+        if len < cutoff {
+            let end = n.min(start + cutoff);
+            insertion_sort_counting(&mut input[start..end], len, cmp, counter);
+            len = end - start;
+        }
+        runs[k] = (start, len);
+        k += 1;
+        counter.pushed_run(k);
+        start += len;
+
+        // Restore the invariants, that each run is longer than the one above
+        // it, and than the two above it together.
+        loop {
+            // The length of the jth run from the top of the stack.
+            let len = |j: usize| runs[k - j].1;
+            let i = if k >= 3 && len(3) <= len(2) + len(1) ||
+                       k >= 4 && len(4) <= len(3) + len(2) {
+                // Merge the middle run with the smaller of its neighbours.
+                if len(3) < len(1) { k - 3 } else { k - 2 }
+            } else if k >= 2 && len(2) <= len(1) {
+                k - 2
+            } else {
+                break;
+            };
+            merge_runs_by(input, &mut runs[..k], i, scratch, config, cmp,
+                          counter);
+            k -= 1;
+        }
+    }
+    // Merge whatever remains on the stack, from the top.
+    while k > 1 {
+        merge_runs_by(input, &mut runs[..k], k - 2, scratch, config, cmp,
+                      counter);
+        k -= 1;
+    }
+}
+
+// The most runs that can be on the stack at once. After the invariants are
+// restored, the run lengths from the top of the stack grow at least as fast as
+// the Fibonacci numbers, so fewer than this many runs could not fit into even
+// the largest slice. One more may be pushed before they are restored.
+const MAX_RUNS: usize = 100;
+
+// Finds the length of the run at the start of a slice, which is either
+// ascending, or strictly descending and then reversed.
+fn find_run_by<T, F, C>(input: &mut [T], cmp: &mut F, counter: &mut C) -> usize
+    where F: FnMut(&T, &T) -> Ordering,
+          C: Counter
+{
+    let n = input.len();
+    if n < 2 {
+        return n;
+    }
+    let mut end = 2;
+    if cmp(&input[1], &input[0]) == Ordering::Less {
+        while end < n && cmp(&input[end], &input[end - 1]) == Ordering::Less {
+            end += 1;
+        }
+        input[..end].reverse();
+        counter.moved(end / 2 * 2);
+        counter.inverted(pairs(end));
+    } else {
+        while end < n && cmp(&input[end], &input[end - 1]) != Ordering::Less {
+            end += 1;
+        }
+    }
+    end
+}
+
+// Merges the run at index i of the stack with the run after it, shifting any
+// runs above them down, so that the stack is one run shorter.
+fn merge_runs_by<T, F, C>(input: &mut [T],
+                          runs: &mut [(usize, usize)],
+                          i: usize,
+                          scratch: &mut Vec<T>,
+                          config: &SortConfig,
+                          cmp: &mut F,
+                          counter: &mut C)
+    where F: FnMut(&T, &T) -> Ordering,
+          C: Counter
+{
+    let (start, len) = runs[i];
+    let (_, next_len) = runs[i + 1];
+    merge_halves_by(&mut input[start..start + len + next_len],
+                    len,
+                    scratch,
+                    config.min_gallop,
+                    cmp,
+                    counter);
+    runs[i].1 = len + next_len;
+    runs.copy_within(i + 2.., i + 1);
+}
+
+// Sorts a slice of values in place by binary insertion sort, which is fastest
+// for short slices. The first `sorted` values must already be in order.
+fn insertion_sort_by<T, F>(input: &mut [T], sorted: usize, cmp: &mut F)
+    where F: FnMut(&T, &T) -> Ordering
+{
+    insertion_sort_counting(input, sorted, cmp, &mut ());
+}
+
+// As insertion_sort_by, tallying the values moved with the given counter.
+fn insertion_sort_counting<T, F, C>(input: &mut [T],
+                                    sorted: usize,
+                                    cmp: &mut F,
+                                    counter: &mut C)
+    where F: FnMut(&T, &T) -> Ordering,
+          C: Counter
+{
+    for i in sorted.max(1)..input.len() {
+        // Insert each value after any equal values before it, keeping the
+        // sort stable.
+        let (sorted, rest) = input.split_at(i);
+        let position = sorted.partition_point(|x| {
+            cmp(x, &rest[0]) != Ordering::Greater
+        });
+        if position < i {
+            // The value passes every value after the position, each greater.
+            input[position..i + 1].rotate_right(1);
+            counter.moved(i + 1 - position);
+            counter.inverted((i - position) as u64);
+        }
+    }
+}
+
+// Merges the two sorted halves of a slice, split at mid, in place, tallying
+// the merge and the values moved with the given counter.
+//
+// Once either half has won `min_gallop` comparisons in a row, the rest of its
+// winning stretch is found by galloping and moved in one go.
+fn merge_halves_by<T, F, C>(input: &mut [T],
+                            mid: usize,
+                            scratch: &mut Vec<T>,
+                            min_gallop: usize,
+                            cmp: &mut F,
+                            counter: &mut C)
+    where F: FnMut(&T, &T) -> Ordering,
+          C: Counter
+{
+    counter.merged();
+    debug_assert!(scratch.is_empty() && scratch.capacity() >= mid);
+    let n = input.len();
+    let input = input.as_mut_ptr();
+    let min_gallop = min_gallop.max(1);
+    // This is sound as the first half is moved (by a bitwise copy) into the
+    // scratch buffer, leaving a gap at the front of the slice. Each step moves
+    // values into the front of the gap, from either the scratch buffer or the
+    // second half, so the gap is always exactly as long as what remains in the
+    // scratch buffer, and never overtakes the unread second half. The Hole
+    // then fills the gap with the remainder, even if cmp panics, so every
+    // value ends up in the slice exactly once.
+    unsafe {
+        ptr::copy_nonoverlapping(input, scratch.as_mut_ptr(), mid);
+        let mut hole = Hole {
+            src: scratch.as_mut_ptr(),
+            dest: input,
+            remaining: mid,
+        };
+        let mut j = mid;
+        // How many times in a row each half has won.
+        let (mut left_wins, mut right_wins) = (0, 0);
+        while hole.remaining > 0 && j < n {
+            let right = input.add(j);
+            if left_wins >= min_gallop {
+                // Every value of the first half up to and including any equal
+                // to the next value of the second half wins.
+                let left = slice::from_raw_parts(hole.src, hole.remaining);
+                let count = gallop(left, |x| cmp(&*right, x) != Ordering::Less);
+                ptr::copy_nonoverlapping(hole.src, hole.dest, count);
+                hole.src = hole.src.add(count);
+                hole.remaining -= count;
+                hole.dest = hole.dest.add(count);
+                left_wins = 0;
+            } else if right_wins >= min_gallop {
+                // Every value of the second half less than the next value of
+                // the first half wins. The gap may be shorter than the
+                // stretch, which is moved within the slice, so may overlap.
+                let rest = slice::from_raw_parts(right, n - j);
+                let head = &*hole.src;
+                let count = gallop(rest, |x| cmp(x, head) == Ordering::Less);
+                ptr::copy(right, hole.dest, count);
+                counter.inverted(count as u64 * hole.remaining as u64);
+                j += count;
+                hole.dest = hole.dest.add(count);
+                right_wins = 0;
+            } else if cmp(&*right, &*hole.src) == Ordering::Less {
+                // Values from the first half win ties, keeping the sort stable.
+                // A value of the second half that wins is less than every
+                // value that remains of the first half.
+                ptr::copy_nonoverlapping(right, hole.dest, 1);
+                counter.inverted(hole.remaining as u64);
+                j += 1;
+                hole.dest = hole.dest.add(1);
+                right_wins += 1;
+                left_wins = 0;
+            } else {
+                ptr::copy_nonoverlapping(hole.src, hole.dest, 1);
+                hole.src = hole.src.add(1);
+                hole.remaining -= 1;
+                hole.dest = hole.dest.add(1);
+                left_wins += 1;
+                right_wins = 0;
             }
-            // If both a and b are empty, exit the loop
-            (None, None) => break,
         }
+        // If the second half remains it is already in place, and dropping
+        // the Hole moves whatever remains of the first half after it. The
+        // first half is moved twice, and the second half up to `j` once.
+        counter.moved(mid + j);
     }
+}
 
-    // Return the buffer
-    buffer
+// The length of the prefix of a slice whose values all satisfy pred, which
+// must hold for a prefix of the slice and for nothing after it. The prefix is
+// bounded by checking positions 1, 2, 4, 8 and so on, and then found by binary
+// search within the bound, so a prefix of length k takes O(log k) checks.
+fn gallop<T, P>(input: &[T], mut pred: P) -> usize
+    where P: FnMut(&T) -> bool
+{
+    let mut bound = 1;
+    while bound <= input.len() && pred(&input[bound - 1]) {
+        bound *= 2;
+    }
+    // The prefix holds up to bound / 2, and fails at bound - 1 if it exists.
+    let low = bound / 2;
+    let high = input.len().min(bound - 1);
+    low + input[low..high].partition_point(pred)
+}
+
+// The unmerged remainder of the first half of a slice, held in the scratch
+// buffer, and the gap in the slice that it fills when dropped.
+struct Hole<T> {
+    src: *mut T,
+    dest: *mut T,
+    remaining: usize,
+}
+
+impl<T> Drop for Hole<T> {
+    fn drop(&mut self) {
+        // This is sound as the gap is exactly `remaining` long, and the
+        // scratch buffer and slice never overlap.
+        unsafe {
+            ptr::copy_nonoverlapping(self.src, self.dest, self.remaining);
+        }
+    }
 }
@@ -0,0 +1,248 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::cmp::Reverse;
+use core::iter::Fuse;
+use core::iter::FusedIterator;
+
+use TotalFloat;
+
+/// An iterator merging two ordered iterators of TotalFloat values, created by
+/// `merge_iter`.
+#[derive(Clone, Debug)]
+pub struct MergeIter<A, B> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+    // Values taken from either end of `a` and `b` but not yet yielded. The
+    // values left of each input are its front, then the rest of the iterator,
+    // then its back.
+    a_front: Option<TotalFloat>,
+    a_back: Option<TotalFloat>,
+    b_front: Option<TotalFloat>,
+    b_back: Option<TotalFloat>,
+}
+
+/// Merges two ordered iterators of TotalFloat values, yielding the merged
+/// values lazily.
+///
+/// This is `merge` without collecting the result, so where values are equal,
+/// those from `a` come first. The merge can be iterated from either end when
+/// both inputs can, and its length is exact when theirs are.
+pub fn merge_iter<A, B>(a: A, b: B) -> MergeIter<A::IntoIter, B::IntoIter>
+    where A: IntoIterator<Item = TotalFloat>,
+          B: IntoIterator<Item = TotalFloat>
+{
+    MergeIter {
+        a: a.into_iter().fuse(),
+        b: b.into_iter().fuse(),
+        a_front: None,
+        a_back: None,
+        b_front: None,
+        b_back: None,
+    }
+}
+
+// Fills the front of an input, from the back once its iterator is empty.
+fn fill_front<I>(iter: &mut I,
+                 front: &mut Option<TotalFloat>,
+                 back: &mut Option<TotalFloat>)
+                 -> Option<TotalFloat>
+    where I: Iterator<Item = TotalFloat>
+{
+    if front.is_none() {
+        *front = iter.next().or_else(|| back.take());
+    }
+    *front
+}
+
+// Fills the back of an input, from the front once its iterator is empty.
+fn fill_back<I>(iter: &mut I,
+                front: &mut Option<TotalFloat>,
+                back: &mut Option<TotalFloat>)
+                -> Option<TotalFloat>
+    where I: DoubleEndedIterator<Item = TotalFloat>
+{
+    if back.is_none() {
+        *back = iter.next_back().or_else(|| front.take());
+    }
+    *back
+}
+
+impl<A, B> Iterator for MergeIter<A, B>
+    where A: Iterator<Item = TotalFloat>,
+          B: Iterator<Item = TotalFloat>
+{
+    type Item = TotalFloat;
+
+    fn next(&mut self) -> Option<TotalFloat> {
+        let a = fill_front(&mut self.a, &mut self.a_front, &mut self.a_back);
+        let b = fill_front(&mut self.b, &mut self.b_front, &mut self.b_back);
+        // Take the lesser value, preferring a's if they are equal.
+        let take_a = match (a, b) {
+            (Some(a), Some(b)) => a.cmp(&b) != Ordering::Greater,
+            (a, _) => a.is_some(),
+        };
+        if take_a { self.a_front.take() } else { self.b_front.take() }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let held = [self.a_front, self.a_back, self.b_front, self.b_back]
+            .iter()
+            .filter(|x| x.is_some())
+            .count();
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => a.checked_add(b)
+                .and_then(|n| n.checked_add(held)),
+            _ => None,
+        };
+        (a_lo.saturating_add(b_lo).saturating_add(held), hi)
+    }
+}
+
+impl<A, B> DoubleEndedIterator for MergeIter<A, B>
+    where A: DoubleEndedIterator<Item = TotalFloat>,
+          B: DoubleEndedIterator<Item = TotalFloat>
+{
+    fn next_back(&mut self) -> Option<TotalFloat> {
+        let a = fill_back(&mut self.a, &mut self.a_front, &mut self.a_back);
+        let b = fill_back(&mut self.b, &mut self.b_front, &mut self.b_back);
+        // Take the greater value, preferring b's if they are equal, as its
+        // values come after a's.
+        let take_a = match (a, b) {
+            (Some(a), Some(b)) => a.cmp(&b) == Ordering::Greater,
+            (a, _) => a.is_some(),
+        };
+        if take_a { self.a_back.take() } else { self.b_back.take() }
+    }
+}
+
+impl<A, B> ExactSizeIterator for MergeIter<A, B>
+    where A: ExactSizeIterator<Item = TotalFloat>,
+          B: ExactSizeIterator<Item = TotalFloat>
+{
+}
+
+impl<A, B> FusedIterator for MergeIter<A, B>
+    where A: Iterator<Item = TotalFloat>,
+          B: Iterator<Item = TotalFloat>
+{
+}
+
+/// An iterator merging any number of ordered iterators of TotalFloat values,
+/// created by `kmerge`.
+#[derive(Clone, Debug)]
+pub struct KMergeIter<I> {
+    iters: Vec<I>,
+    // The next value of each input that has one, with the index of the input,
+    // so that the least value, and of equal values the earliest input's, is
+    // on top.
+    heads: BinaryHeap<Reverse<(TotalFloat, usize)>>,
+}
+
+/// Merges any number of ordered iterators of TotalFloat values, yielding the
+/// merged values lazily.
+///
+/// Only the next value of each input is held, in a heap, so each value takes
+/// `O(log k)` time to yield for `k` inputs. Where values are equal, those from
+/// earlier inputs come first. See `merge_iter` for just two inputs.
+pub fn kmerge<I>(iters: I) -> KMergeIter<<I::Item as IntoIterator>::IntoIter>
+    where I: IntoIterator,
+          I::Item: IntoIterator<Item = TotalFloat>
+{
+    let mut iters: Vec<_> = iters.into_iter().map(|x| x.into_iter()).collect();
+    let heads = iters.iter_mut()
+        .enumerate()
+        .filter_map(|(i, iter)| iter.next().map(|x| Reverse((x, i))))
+        .collect();
+    KMergeIter { iters, heads }
+}
+
+impl<I> KMergeIter<I>
+    where I: Iterator<Item = TotalFloat>
+{
+    // The next value, with the index of the input it came from.
+    fn next_tagged(&mut self) -> Option<(TotalFloat, usize)> {
+        let Reverse((value, i)) = self.heads.pop()?;
+        if let Some(next) = self.iters[i].next() {
+            self.heads.push(Reverse((next, i)));
+        }
+        Some((value, i))
+    }
+}
+
+impl<I> Iterator for KMergeIter<I>
+    where I: Iterator<Item = TotalFloat>
+{
+    type Item = TotalFloat;
+
+    fn next(&mut self) -> Option<TotalFloat> {
+        self.next_tagged().map(|(value, _)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let held = self.heads.len();
+        self.iters.iter().fold((held, Some(held)), |(lo, hi), iter| {
+            let (iter_lo, iter_hi) = iter.size_hint();
+            let hi = match (hi, iter_hi) {
+                (Some(hi), Some(iter_hi)) => hi.checked_add(iter_hi),
+                _ => None,
+            };
+            (lo.saturating_add(iter_lo), hi)
+        })
+    }
+}
+
+/// Merges three ordered lists of TotalFloat values into a single ordered list.
+///
+/// Where values are equal, those from `a` come first, then those from `b`.
+/// This compares the heads of the three lists directly, which is faster than
+/// merging them pairwise or with `kmerge`.
+pub fn merge3(a: Vec<TotalFloat>,
+              b: Vec<TotalFloat>,
+              c: Vec<TotalFloat>)
+              -> Vec<TotalFloat> {
+    let mut out = Vec::with_capacity(a.len() + b.len() + c.len());
+    let (mut i, mut j, mut k) = (0, 0, 0);
+    while i < a.len() && j < b.len() && k < c.len() {
+        // Take the least head, preferring the earliest list's of equal heads.
+        if a[i] <= b[j] && a[i] <= c[k] {
+            out.push(a[i]);
+            i += 1;
+        } else if b[j] <= c[k] {
+            out.push(b[j]);
+            j += 1;
+        } else {
+            out.push(c[k]);
+            k += 1;
+        }
+    }
+    // Once one list is used up, the other two are merged as a pair.
+    let (x, y) = if i == a.len() {
+        (&b[j..], &c[k..])
+    } else if j == b.len() {
+        (&a[i..], &c[k..])
+    } else {
+        (&a[i..], &b[j..])
+    };
+    out.extend(merge_iter(x.iter().cloned(), y.iter().cloned()));
+    out
+}
+
+/// Merges any number of ordered lists of TotalFloat values into a single
+/// ordered list, with each value tagged with the index of the list it came
+/// from.
+///
+/// Where values are equal, those from earlier lists come first, as with
+/// `kmerge`.
+pub fn merge_k_tagged(inputs: Vec<Vec<TotalFloat>>)
+                      -> Vec<(TotalFloat, usize)> {
+    let len = inputs.iter().map(|x| x.len()).sum();
+    let mut merged = kmerge(inputs);
+    let mut out = Vec::with_capacity(len);
+    while let Some(tagged) = merged.next_tagged() {
+        out.push(tagged);
+    }
+    out
+}
@@ -0,0 +1,139 @@
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use merge;
+use merge_sort;
+use merge_sort_with;
+use SortConfig;
+use TotalFloat;
+
+/// What to do with NaN values when sorting, see `SortConfig::nan_placement`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NanPlacement {
+    /// Put NaNs first, in their original relative order.
+    First,
+    /// Put NaNs last, in their original relative order, as with SQL's
+    /// `NULLS LAST`.
+    Last,
+    /// Remove NaNs.
+    Drop,
+    /// Return a `NanFound` error for the first NaN.
+    Error,
+}
+
+/// The error returned when a NaN is found in input that must not have any.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NanFound {
+    /// The index of the first NaN in the input.
+    pub index: usize,
+}
+
+impl fmt::Display for NanFound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NaN found at index {}", self.index)
+    }
+}
+
+impl Error for NanFound {}
+
+/// The error returned by `try_merge_sort` and `try_merge` when their input has
+/// a NaN, giving the input back untouched.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NanRejected<T> {
+    /// The index of the first NaN in the input.
+    pub index: usize,
+    /// The bit pattern of the first NaN in the input.
+    pub bits: u64,
+    /// The input, as it was given.
+    pub input: T,
+}
+
+impl<T> fmt::Display for NanRejected<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NaN ({:#018x}) found at index {}", self.bits, self.index)
+    }
+}
+
+impl<T: fmt::Debug> Error for NanRejected<T> {}
+
+/// The number of NaN values in a slice of TotalFloat values.
+pub fn nan_count(values: &[TotalFloat]) -> usize {
+    values.iter().filter(|x| x.is_nan()).count()
+}
+
+/// Removes the NaN values from a list of TotalFloat values, returning the rest,
+/// in their original relative order, along with the number removed.
+pub fn partition_nans(mut values: Vec<TotalFloat>) -> (Vec<TotalFloat>, usize) {
+    let removed = strip_nans_in_place(&mut values);
+    (values, removed)
+}
+
+/// Removes the NaN values from a Vec of TotalFloat values in place, keeping
+/// the rest in their original relative order, and returns the number removed.
+pub fn strip_nans_in_place(values: &mut Vec<TotalFloat>) -> usize {
+    let n = values.len();
+    values.retain(|x| !x.is_nan());
+    n - values.len()
+}
+
+/// Sorts the values of a list of TotalFloat values that are not NaN, returning
+/// them along with the number of NaN values dropped.
+pub fn merge_sort_dropping_nans(values: Vec<TotalFloat>)
+                                -> (Vec<TotalFloat>, usize) {
+    let (values, removed) = partition_nans(values);
+    (merge_sort(values), removed)
+}
+
+/// Sorts a list of TotalFloat values, with the NaN values placed, dropped, or
+/// rejected as given, returning the sorted list along with the number of NaN
+/// values it had.
+///
+/// NaNs placed first or last keep their original relative order, and under
+/// `NanPlacement::Drop` the count is the number dropped. Under
+/// `NanPlacement::Error` the index of the first NaN is returned, if there are
+/// any. See `merge_sort_with` to set the order and other options as well.
+pub fn merge_sort_nan(values: Vec<TotalFloat>,
+                      placement: NanPlacement)
+                      -> Result<(Vec<TotalFloat>, usize), NanFound> {
+    let count = nan_count(&values);
+    let config = SortConfig::new().nan_placement(placement);
+    merge_sort_with(values, &config).map(|sorted| (sorted, count))
+}
+
+/// Sorts a list of TotalFloat values, or returns an error if any is NaN.
+///
+/// The input is scanned for NaNs before anything is sorted, so on error it is
+/// returned as it was given, along with the index and bit pattern of the first
+/// NaN.
+pub fn try_merge_sort(values: Vec<TotalFloat>)
+                      -> Result<Vec<TotalFloat>, NanRejected<Vec<TotalFloat>>> {
+    match values.iter().position(|x| x.is_nan()) {
+        Some(index) => Err(NanRejected { index,
+                                         bits: values[index].to_bits(),
+                                         input: values }),
+        None => Ok(merge_sort(values)),
+    }
+}
+
+// The two inputs to `try_merge`, returned on error.
+type Inputs = (Vec<TotalFloat>, Vec<TotalFloat>);
+
+/// Merges two ordered lists of TotalFloat values, or returns an error if any
+/// is NaN.
+///
+/// As with `try_merge_sort` both inputs are scanned first, and returned as
+/// they were given on error. The index of the first NaN counts the values of
+/// `b` after those of `a`, so it is `a.len()` or more if `a` has no NaN.
+pub fn try_merge(a: Vec<TotalFloat>,
+                 b: Vec<TotalFloat>)
+                 -> Result<Vec<TotalFloat>, NanRejected<Inputs>> {
+    match a.iter().chain(b.iter()).position(|x| x.is_nan()) {
+        Some(index) => {
+            let bits = a.get(index).unwrap_or_else(|| &b[index - a.len()])
+                .to_bits();
+            Err(NanRejected { index, bits, input: (a, b) })
+        }
+        None => Ok(merge(a, b)),
+    }
+}
@@ -0,0 +1,176 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::ops;
+use core::ops::Deref;
+
+use merge_sort;
+use NanPolicy;
+use TotalFloatWith;
+
+/// A 64 Bit IEEE Float which is guaranteed not to be NaN.
+///
+/// Excluding NaN makes the ordering and equality of f64 total without any
+/// special treatment, so NotNan is Ord, Eq and Hash. As with TotalFloat, -0.0
+/// and 0.0 are equal.
+///
+/// Arithmetic on NotNan values returns a `Result`, which is an error whenever
+/// IEEE arithmetic would have produced a NaN (such as `inf - inf`).
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct NotNan {
+    inner: f64,
+}
+
+/// The error returned when a NaN is found where one is not permitted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NanError;
+
+impl fmt::Display for NanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "value is NaN")
+    }
+}
+
+impl Error for NanError {}
+
+impl NotNan {
+    /// Creates a new NotNan, or returns an error if the value is NaN.
+    pub fn new(inner: f64) -> Result<NotNan, NanError> {
+        if inner.is_nan() {
+            Err(NanError)
+        } else {
+            Ok(NotNan { inner })
+        }
+    }
+
+    /// The wrapped f64.
+    pub fn get(self) -> f64 {
+        self.inner
+    }
+}
+
+// Implement Deref to allow us to use f64 methods on NotNan.
+//
+// DerefMut is deliberately not implemented, as it would allow a NaN to be
+// written into the NotNan.
+impl Deref for NotNan {
+    type Target = f64;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+// Equality Implementation
+// This is total, as NaN is the only value for which f64 equality is not.
+impl Eq for NotNan {}
+impl PartialEq for NotNan {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+// Ordering Implementation
+// This is total, as NaN is the only value for which f64 ordering is not.
+impl Ord for NotNan {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner
+            .partial_cmp(&other.inner)
+            .expect("Unexpected NaN in NotNan")
+    }
+}
+impl PartialOrd for NotNan {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Hash Implementation
+// -0.0 is hashed as 0.0, as the two are equal.
+impl Hash for NotNan {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let bits = if self.inner == 0.0 { 0 } else { self.inner.to_bits() };
+        bits.hash(state)
+    }
+}
+
+// Debug and Display Implementations
+// These facilitate printing of NotNan as if they were f64.
+impl fmt::Debug for NotNan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+impl fmt::Display for NotNan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+// Fallible conversion from f64 to NotNan
+impl TryFrom<f64> for NotNan {
+    type Error = NanError;
+    fn try_from(from: f64) -> Result<NotNan, NanError> {
+        NotNan::new(from)
+    }
+}
+
+// Conversion wrapper from NotNan to f64
+impl From<NotNan> for f64 {
+    fn from(from: NotNan) -> f64 {
+        from.inner
+    }
+}
+
+// Conversion wrapper from NotNan to TotalFloat
+impl<P: NanPolicy> From<NotNan> for TotalFloatWith<P> {
+    fn from(from: NotNan) -> TotalFloatWith<P> {
+        TotalFloatWith::new(from.inner)
+    }
+}
+
+// Arithmetic Implementations
+// These delegate to the inner f64 arithmetic, and return an error if the
+// result would be NaN, so that a NaN can never be smuggled into a NotNan.
+macro_rules! impl_checked_op {
+    ($($trait:ident, $method:ident);*) => { $(
+        impl ops::$trait for NotNan {
+            type Output = Result<NotNan, NanError>;
+            fn $method(self, other: NotNan) -> Result<NotNan, NanError> {
+                NotNan::new(ops::$trait::$method(self.inner, other.inner))
+            }
+        }
+        impl ops::$trait<f64> for NotNan {
+            type Output = Result<NotNan, NanError>;
+            fn $method(self, other: f64) -> Result<NotNan, NanError> {
+                NotNan::new(ops::$trait::$method(self.inner, other))
+            }
+        }
+    )* }
+}
+
+impl_checked_op! {
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+    Rem, rem
+}
+
+// Negation Implementation
+// Negation can never produce a NaN, so this is infallible.
+impl ops::Neg for NotNan {
+    type Output = NotNan;
+    fn neg(self) -> NotNan {
+        NotNan { inner: -self.inner }
+    }
+}
+
+/// Sorts a list of NotNan values.
+pub fn merge_sort_not_nan(input: Vec<NotNan>) -> Vec<NotNan> {
+    merge_sort(input)
+}
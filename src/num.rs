@@ -0,0 +1,184 @@
+use core::num::FpCategory;
+use num_traits::Float;
+use num_traits::FromPrimitive;
+use num_traits::Num;
+use num_traits::NumCast;
+use num_traits::One;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+
+use NanPolicy;
+use TotalFloatWith;
+
+// num-traits Implementations
+// These all delegate to the implementations for the inner f64. In particular
+// Float::min and Float::max keep f64's behaviour of ignoring NaN, which is not
+// the same as Ord::min and Ord::max, so generic code bounded on both should
+// say which one it means.
+impl<P: NanPolicy> Zero for TotalFloatWith<P> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+    fn is_zero(&self) -> bool {
+        self.inner.is_zero()
+    }
+}
+
+impl<P: NanPolicy> One for TotalFloatWith<P> {
+    fn one() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl<P: NanPolicy> Num for TotalFloatWith<P> {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32)
+                      -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Self::new)
+    }
+}
+
+impl<P: NanPolicy> Signed for TotalFloatWith<P> {
+    fn abs(&self) -> Self {
+        Self::new(Signed::abs(&self.inner))
+    }
+    fn abs_sub(&self, other: &Self) -> Self {
+        Self::new(Signed::abs_sub(&self.inner, &other.inner))
+    }
+    fn signum(&self) -> Self {
+        Self::new(Signed::signum(&self.inner))
+    }
+    fn is_positive(&self) -> bool {
+        Signed::is_positive(&self.inner)
+    }
+    fn is_negative(&self) -> bool {
+        Signed::is_negative(&self.inner)
+    }
+}
+
+impl<P: NanPolicy> ToPrimitive for TotalFloatWith<P> {
+    fn to_i64(&self) -> Option<i64> {
+        self.inner.to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.inner.to_u64()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.inner)
+    }
+}
+
+impl<P: NanPolicy> FromPrimitive for TotalFloatWith<P> {
+    fn from_i64(n: i64) -> Option<Self> {
+        f64::from_i64(n).map(Self::new)
+    }
+    fn from_u64(n: u64) -> Option<Self> {
+        f64::from_u64(n).map(Self::new)
+    }
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::new(n))
+    }
+}
+
+impl<P: NanPolicy> NumCast for TotalFloatWith<P> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f64().map(Self::new)
+    }
+}
+
+// Forwards Float methods to the implementation for f64, grouped by signature.
+macro_rules! forward_float {
+    (fn() -> Self: $($method:ident),*) => { $(
+        fn $method() -> Self {
+            Self::new(<f64 as Float>::$method())
+        }
+    )* };
+    (fn(self) -> Self: $($method:ident),*) => { $(
+        fn $method(self) -> Self {
+            Self::new(Float::$method(self.inner))
+        }
+    )* };
+    (fn(self, Self) -> Self: $($method:ident),*) => { $(
+        fn $method(self, other: Self) -> Self {
+            Self::new(Float::$method(self.inner, other.inner))
+        }
+    )* };
+    (fn(self) -> bool: $($method:ident),*) => { $(
+        fn $method(self) -> bool {
+            Float::$method(self.inner)
+        }
+    )* };
+}
+
+impl<P: NanPolicy> Float for TotalFloatWith<P> {
+    forward_float!(fn() -> Self: nan,
+                   infinity,
+                   neg_infinity,
+                   neg_zero,
+                   min_value,
+                   min_positive_value,
+                   max_value,
+                   epsilon);
+    forward_float!(fn(self) -> Self: floor,
+                   ceil,
+                   round,
+                   trunc,
+                   fract,
+                   abs,
+                   signum,
+                   recip,
+                   sqrt,
+                   exp,
+                   exp2,
+                   ln,
+                   log2,
+                   log10,
+                   cbrt,
+                   sin,
+                   cos,
+                   tan,
+                   asin,
+                   acos,
+                   atan,
+                   exp_m1,
+                   ln_1p,
+                   sinh,
+                   cosh,
+                   tanh,
+                   asinh,
+                   acosh,
+                   atanh,
+                   to_degrees,
+                   to_radians);
+    forward_float!(fn(self, Self) -> Self: powf,
+                   log,
+                   max,
+                   min,
+                   abs_sub,
+                   hypot,
+                   atan2);
+    forward_float!(fn(self) -> bool: is_nan,
+                   is_infinite,
+                   is_finite,
+                   is_normal,
+                   is_sign_positive,
+                   is_sign_negative);
+
+    fn classify(self) -> FpCategory {
+        Float::classify(self.inner)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::new(Float::mul_add(self.inner, a.inner, b.inner))
+    }
+    fn powi(self, n: i32) -> Self {
+        Self::new(Float::powi(self.inner, n))
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = Float::sin_cos(self.inner);
+        (Self::new(sin), Self::new(cos))
+    }
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.inner)
+    }
+}
@@ -0,0 +1,44 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use merge_sort_by;
+use total_cmp;
+
+/// Where `sort_options` puts None values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NonePlacement {
+    /// Put Nones first, before any NaNs.
+    First,
+    /// Put Nones last.
+    Last,
+    /// Remove Nones.
+    Drop,
+}
+
+/// Sorts a list of optional f64 values, with the None values placed or
+/// dropped as given.
+///
+/// The Some values are ordered by the total order of TotalFloat, as by
+/// `total_cmp`, so NaNs are still least among them, and kept apart from the
+/// Nones. The sort is stable, so equal values, such as NaNs with different
+/// payloads, keep their relative order, as do the Nones.
+pub fn sort_options(mut input: Vec<Option<f64>>,
+                    none_placement: NonePlacement)
+                    -> Vec<Option<f64>> {
+    if none_placement == NonePlacement::Drop {
+        input.retain(Option::is_some);
+    }
+    let none = if none_placement == NonePlacement::First {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    merge_sort_by(input, |a, b| {
+        match (a, b) {
+            (Some(a), Some(b)) => total_cmp(a, b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => none,
+            (Some(_), None) => none.reverse(),
+        }
+    })
+}
@@ -0,0 +1,37 @@
+use core::convert::TryFrom;
+use ordered_float::FloatIsNan;
+use ordered_float::NotNan;
+use ordered_float::OrderedFloat;
+
+use NanPolicy;
+use TotalFloatWith;
+
+// ordered-float Conversion Implementations
+// These preserve the value (including the bit pattern of any NaN) exactly.
+// Note that OrderedFloat places NaN greatest, whereas TotalFloat places it
+// least (unless NanGreatest is used), so converting a list does not
+// necessarily preserve where its NaNs sort.
+impl<P: NanPolicy> From<OrderedFloat<f64>> for TotalFloatWith<P> {
+    fn from(from: OrderedFloat<f64>) -> Self {
+        Self::new(from.0)
+    }
+}
+
+impl<P: NanPolicy> From<TotalFloatWith<P>> for OrderedFloat<f64> {
+    fn from(from: TotalFloatWith<P>) -> OrderedFloat<f64> {
+        OrderedFloat(from.inner)
+    }
+}
+
+impl<P: NanPolicy> From<NotNan<f64>> for TotalFloatWith<P> {
+    fn from(from: NotNan<f64>) -> Self {
+        Self::new(from.into_inner())
+    }
+}
+
+impl<P: NanPolicy> TryFrom<TotalFloatWith<P>> for NotNan<f64> {
+    type Error = FloatIsNan;
+    fn try_from(from: TotalFloatWith<P>) -> Result<NotNan<f64>, FloatIsNan> {
+        NotNan::new(from.inner)
+    }
+}
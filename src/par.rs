@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use rayon;
+
+use sort_slice_by;
+use TotalFloat;
+
+/// The length of input, 16,384 by default, at or below which
+/// `merge_sort_par` sorts sequentially.
+pub const PAR_THRESHOLD: usize = 1 << 14;
+
+/// Sorts a list of TotalFloat values on the rayon thread pool.
+///
+/// The result is exactly that of `merge_sort`, down to the order of NaNs with
+/// different payloads. Input of at most `PAR_THRESHOLD` values is sorted
+/// sequentially, see `merge_sort_par_with_threshold`.
+pub fn merge_sort_par(mut input: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    merge_sort_par_in_place(&mut input);
+    input
+}
+
+/// Sorts a slice of TotalFloat values in place on the rayon thread pool.
+///
+/// See `merge_sort_par`.
+pub fn merge_sort_par_in_place(input: &mut [TotalFloat]) {
+    sort_par(input, PAR_THRESHOLD);
+}
+
+/// Sorts a list of TotalFloat values on the rayon thread pool, sorting
+/// sequentially at or below the given length.
+///
+/// The input is split in half recursively, with the halves sorted in parallel
+/// by `rayon::join`, until the pieces are no longer than the threshold, which
+/// are then sorted with the sequential algorithm. The sorted pieces are merged
+/// pairwise, and each merge is itself split in parallel. A threshold of 0 is
+/// treated as 1.
+pub fn merge_sort_par_with_threshold(mut input: Vec<TotalFloat>,
+                                     threshold: usize)
+                                     -> Vec<TotalFloat> {
+    sort_par(&mut input, threshold);
+    input
+}
+
+// Sorts a slice in parallel, or sequentially if it is no longer than the
+// threshold, to not pay for the scratch buffer and thread overhead.
+fn sort_par(input: &mut [TotalFloat], threshold: usize) {
+    let threshold = threshold.max(1);
+    if input.len() <= threshold {
+        sort_slice_by(input, &mut TotalFloat::cmp);
+    } else {
+        let mut scratch = input.to_vec();
+        sort_into(input, &mut scratch, threshold, false);
+    }
+}
+
+// Sorts the values of `input`, leaving the result in `scratch` if `into` is
+// true, and in `input` otherwise. The two slices are the same length, and the
+// other is left in an unspecified order.
+//
+// The halves are sorted into the opposite buffer to the result, so that they
+// can be merged straight into the result without copying them back.
+fn sort_into(input: &mut [TotalFloat],
+             scratch: &mut [TotalFloat],
+             threshold: usize,
+             into: bool) {
+    let n = input.len();
+    if n <= threshold {
+        sort_slice_by(input, &mut TotalFloat::cmp);
+        if into {
+            scratch.copy_from_slice(input);
+        }
+        return;
+    }
+    let mid = n / 2;
+    {
+        let (input_a, input_b) = input.split_at_mut(mid);
+        let (scratch_a, scratch_b) = scratch.split_at_mut(mid);
+        rayon::join(|| sort_into(input_a, scratch_a, threshold, !into),
+                    || sort_into(input_b, scratch_b, threshold, !into));
+    }
+    let (src, dest) = if into { (input, scratch) } else { (scratch, input) };
+    let (a, b) = src.split_at(mid);
+    merge_par(a, b, dest, threshold);
+}
+
+// Merges two sorted slices into a destination of their combined length, with
+// values from `a` winning ties.
+//
+// The longer slice is split at its middle value, and the shorter at the
+// position of that value, chosen so that ties still go to `a`. Everything
+// before both split points is less than everything after, so the two halves
+// of the merge are independent and can run in parallel.
+fn merge_par(a: &[TotalFloat],
+             b: &[TotalFloat],
+             dest: &mut [TotalFloat],
+             threshold: usize) {
+    // Two single values cannot be split further, whatever the threshold.
+    if a.len() + b.len() <= threshold.max(2) || a.is_empty() || b.is_empty() {
+        merge_seq(a, b, dest);
+        return;
+    }
+    let (a_mid, b_mid) = if a.len() >= b.len() {
+        // Values of `b` equal to the split value go after it.
+        let mid = a.len() / 2;
+        (mid, b.partition_point(|x| *x < a[mid]))
+    } else {
+        // Values of `a` equal to the split value go before it.
+        let mid = b.len() / 2;
+        (a.partition_point(|x| *x <= b[mid]), mid)
+    };
+    let (a_lo, a_hi) = a.split_at(a_mid);
+    let (b_lo, b_hi) = b.split_at(b_mid);
+    let (dest_lo, dest_hi) = dest.split_at_mut(a_mid + b_mid);
+    rayon::join(|| merge_par(a_lo, b_lo, dest_lo, threshold),
+                || merge_par(a_hi, b_hi, dest_hi, threshold));
+}
+
+// Merges two sorted slices into a destination of their combined length,
+// sequentially, with values from `a` winning ties.
+fn merge_seq(a: &[TotalFloat], b: &[TotalFloat], dest: &mut [TotalFloat]) {
+    let (mut i, mut j) = (0, 0);
+    for slot in dest.iter_mut() {
+        let take_a = j == b.len() ||
+                     i < a.len() && a[i].cmp(&b[j]) != Ordering::Greater;
+        if take_a {
+            *slot = a[i];
+            i += 1;
+        } else {
+            *slot = b[j];
+            j += 1;
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use core::mem;
+
+use heap_sort;
+use insertion_sort_by;
+use is_sorted;
+use TotalFloat;
+
+/// Sorts a slice of TotalFloat values in place, without allocating.
+///
+/// This is a pattern-defeating quicksort. Pivots are the median of three
+/// values (or of three medians of three, for longer slices), short slices are
+/// insertion sorted, runs of values equal to an earlier pivot are split off in
+/// a single pass, and a bad pivot shuffles a few values to break up whatever
+/// pattern caused it. Should too many pivots be bad, the slice is heapsorted
+/// instead, so the worst case is O(n log n).
+///
+/// Unlike `merge_sort` the sort is not stable, so equal values (such as NaNs
+/// with different payloads, or zeros of different signs) may be reordered.
+pub fn pdq_sort(input: &mut [TotalFloat]) {
+    if is_sorted(input) {
+        return;
+    }
+    // As the sort is unstable, descending input can be reversed even if it
+    // has equal values.
+    if input.windows(2).all(|w| w[0] >= w[1]) {
+        input.reverse();
+        return;
+    }
+    // Allow about 2 log2(n) bad pivots before falling back to heapsort.
+    let limit = 2 * (usize::BITS - input.len().leading_zeros());
+    quicksort(input, None, limit);
+}
+
+// Slices at most this long are insertion sorted.
+const INSERTION_LEN: usize = 20;
+
+// Slices at least this long use the median of three medians of three as their
+// pivot.
+const NINTHER_LEN: usize = 50;
+
+// Sorts a slice by quicksort, given the pivot of the partition it came from if
+// it came from the right of one, which is no greater than anything in it.
+//
+// Only the shorter side of each partition is recursed into, with the longer
+// side sorted in the loop, so the recursion is at most log2(n) deep.
+fn quicksort(mut input: &mut [TotalFloat],
+             mut pred: Option<TotalFloat>,
+             mut limit: u32) {
+    loop {
+        let n = input.len();
+        if n <= INSERTION_LEN {
+            insertion_sort_by(input, 1, &mut TotalFloat::cmp);
+            return;
+        }
+        if limit == 0 {
+            heap_sort(input);
+            return;
+        }
+        let pivot = choose_pivot(input);
+        input.swap(0, pivot);
+        let pivot = input[0];
+
+        // If the pivot equals the predecessor, so does every value no greater
+        // than it, and they are all already in place.
+        if pred.is_some_and(|pred| pred >= pivot) {
+            let mid = partition_equal(input, pivot);
+            input = &mut mem::take(&mut input)[mid..];
+            continue;
+        }
+
+        let mid = partition(input, pivot);
+        let (left, right) = mem::take(&mut input).split_at_mut(mid);
+        let right = &mut right[1..];
+        if left.len().min(right.len()) < n / 8 {
+            limit -= 1;
+            break_patterns(left);
+            break_patterns(right);
+        }
+        if left.len() < right.len() {
+            quicksort(left, pred, limit);
+            input = right;
+            pred = Some(pivot);
+        } else {
+            quicksort(right, Some(pivot), limit);
+            input = left;
+        }
+    }
+}
+
+// The index of a pivot for a slice longer than INSERTION_LEN.
+fn choose_pivot(input: &[TotalFloat]) -> usize {
+    let n = input.len();
+    let median = |a: usize, b: usize, c: usize| {
+        let (a, b) = if input[b] < input[a] { (b, a) } else { (a, b) };
+        if input[c] < input[a] {
+            a
+        } else if input[c] < input[b] {
+            c
+        } else {
+            b
+        }
+    };
+    let (a, b, c) = (n / 4, n / 2, n / 4 * 3);
+    if n >= NINTHER_LEN {
+        median(median(a - 1, a, a + 1),
+               median(b - 1, b, b + 1),
+               median(c - 1, c, c + 1))
+    } else {
+        median(a, b, c)
+    }
+}
+
+// Partitions a slice whose first value is the pivot, so that the values less
+// than the pivot come before it, and the rest after it, returning the index
+// the pivot ends up at.
+fn partition(input: &mut [TotalFloat], pivot: TotalFloat) -> usize {
+    let (mut l, mut r) = (1, input.len());
+    loop {
+        while l < r && input[l] < pivot {
+            l += 1;
+        }
+        while l < r && input[r - 1] >= pivot {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        r -= 1;
+        input.swap(l, r);
+        l += 1;
+    }
+    input.swap(0, l - 1);
+    l - 1
+}
+
+// Partitions a slice whose first value is the pivot, and no value of which is
+// less than it, so that the values equal to the pivot come first, returning
+// how many there are.
+fn partition_equal(input: &mut [TotalFloat], pivot: TotalFloat) -> usize {
+    let (mut l, mut r) = (1, input.len());
+    loop {
+        while l < r && input[l] <= pivot {
+            l += 1;
+        }
+        while l < r && input[r - 1] > pivot {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        r -= 1;
+        input.swap(l, r);
+        l += 1;
+    }
+    l
+}
+
+// Swaps a few values in the middle of a slice with others at pseudo-random
+// positions, so that whatever pattern gave a bad pivot is unlikely to again.
+fn break_patterns(input: &mut [TotalFloat]) {
+    let n = input.len();
+    if n < 8 {
+        return;
+    }
+    // An xorshift generator, seeded by the length so the sort is
+    // deterministic.
+    let mut seed = n as u64;
+    let mut random = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed as usize
+    };
+    let mask = n.next_power_of_two() - 1;
+    let pos = n / 4 * 2;
+    for i in 0..3 {
+        let mut other = random() & mask;
+        if other >= n {
+            other -= n;
+        }
+        input.swap(pos - 1 + i, other);
+    }
+}
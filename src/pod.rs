@@ -0,0 +1,21 @@
+use bytemuck::Pod;
+use bytemuck::Zeroable;
+
+use NanPolicy;
+use RawFloat;
+use TotalFloatWith;
+
+// Zeroable and Pod Implementations
+// TotalFloatWith is repr(transparent) over the wrapped float, and the policy
+// marker is zero sized, so it has no padding and every bit pattern is valid
+// exactly when that is true of the wrapped float.
+unsafe impl<P, F> Zeroable for TotalFloatWith<P, F>
+    where P: NanPolicy,
+          F: RawFloat + Zeroable
+{
+}
+unsafe impl<P, F> Pod for TotalFloatWith<P, F>
+    where P: NanPolicy + 'static,
+          F: RawFloat + Pod
+{
+}
@@ -0,0 +1,36 @@
+//! proptest strategies for TotalFloat.
+//!
+//! These shrink towards zero, and lists towards being shorter.
+
+use alloc::vec::Vec;
+use proptest::collection::SizeRange;
+use proptest::collection::vec;
+use proptest::num::f64;
+use proptest::prelude::any;
+use proptest::strategy::Strategy;
+
+use TotalFloat;
+
+/// A strategy for a TotalFloat of any bit pattern, including infinities and
+/// NaNs with any payload and sign.
+///
+/// This shrinks through the bit pattern, towards 0.0.
+pub fn total_float() -> impl Strategy<Value = TotalFloat> {
+    any::<u64>().prop_map(TotalFloat::from_bits)
+}
+
+/// A strategy for a finite TotalFloat of either sign, which may be normal,
+/// subnormal or zero.
+pub fn finite_total_float() -> impl Strategy<Value = TotalFloat> {
+    (f64::POSITIVE | f64::NEGATIVE | f64::NORMAL | f64::SUBNORMAL | f64::ZERO)
+        .prop_map(TotalFloat::new)
+}
+
+/// A strategy for a list of TotalFloat of any bit pattern, with a length in
+/// the given range.
+pub fn total_float_vec<L>(len_range: L)
+                          -> impl Strategy<Value = Vec<TotalFloat>>
+    where L: Into<SizeRange>
+{
+    vec(total_float(), len_range)
+}
@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use NanPolicy;
+use TotalFloatWith;
+
+/// Sorts a list of TotalFloat values by a least significant digit radix sort.
+///
+/// The values are bucketed by `TotalFloat::total_bits_key`, a byte at a time
+/// from the lowest, with a counting pass for each byte, so the sort takes
+/// O(n) time with a single scratch list. Bytes shared by every key are
+/// skipped. Each pass keeps the order of values with the same byte, so values
+/// with the same key (which are exactly those that are equal) keep their
+/// relative order, and the result is identical to that of `merge_sort`, down
+/// to the order of NaNs with different payloads and of -0.0 and +0.0.
+pub fn radix_sort<P: NanPolicy>(input: Vec<TotalFloatWith<P>>)
+                                -> Vec<TotalFloatWith<P>> {
+    if input.len() < 2 {
+        return input;
+    }
+    // Count each byte of every key up front, for all the passes at once.
+    let mut counts = [[0usize; 256]; 8];
+    for x in input.iter() {
+        let key = x.total_bits_key();
+        for (byte, counts) in counts.iter_mut().enumerate() {
+            counts[(key >> (8 * byte)) as usize & 0xff] += 1;
+        }
+    }
+
+    let n = input.len();
+    let mut src = input;
+    let mut dest = src.clone();
+    for (byte, counts) in counts.iter().enumerate() {
+        // If every key has the same byte, the pass would change nothing.
+        if counts.contains(&n) {
+            continue;
+        }
+        // The start of each bucket, after all the buckets before it.
+        let mut starts = [0; 256];
+        let mut start = 0;
+        for (bucket, &count) in starts.iter_mut().zip(counts.iter()) {
+            *bucket = start;
+            start += count;
+        }
+        for x in src.iter() {
+            let bucket = (x.total_bits_key() >> (8 * byte)) as usize & 0xff;
+            dest[starts[bucket]] = *x;
+            starts[bucket] += 1;
+        }
+        mem::swap(&mut src, &mut dest);
+    }
+    src
+}
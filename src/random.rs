@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use rand::distr::Distribution;
+use rand::distr::StandardUniform;
+use rand::distr::uniform::Error;
+use rand::distr::uniform::SampleBorrow;
+use rand::distr::uniform::SampleUniform;
+use rand::distr::uniform::UniformSampler;
+use rand::Rng;
+use rand::RngExt;
+
+use NanPolicy;
+use RawFloat;
+use TotalFloat;
+use TotalFloatWith;
+
+// Standard Distribution Implementation
+// This samples the wrapped float from its own standard distribution, which
+// for f32 and f64 is uniform over [0, 1) and so never NaN.
+impl<P, F> Distribution<TotalFloatWith<P, F>> for StandardUniform
+    where P: NanPolicy,
+          F: RawFloat,
+          StandardUniform: Distribution<F>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TotalFloatWith<P, F> {
+        TotalFloatWith::new(rng.random())
+    }
+}
+
+/// A uniform distribution over a range of TotalFloat values.
+///
+/// This delegates to the uniform distribution of the wrapped float, so it
+/// rejects NaN and non-finite bounds, and never samples NaN.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformTotalFloat<P: NanPolicy, F: RawFloat + SampleUniform> {
+    inner: F::Sampler,
+    policy: PhantomData<P>,
+}
+
+impl<P, F> UniformSampler for UniformTotalFloat<P, F>
+    where P: NanPolicy,
+          F: RawFloat + SampleUniform
+{
+    type X = TotalFloatWith<P, F>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+        where B1: SampleBorrow<Self::X> + Sized,
+              B2: SampleBorrow<Self::X> + Sized
+    {
+        UniformSampler::new(low.borrow().inner, high.borrow().inner)
+            .map(|inner| UniformTotalFloat { inner, policy: PhantomData })
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+        where B1: SampleBorrow<Self::X> + Sized,
+              B2: SampleBorrow<Self::X> + Sized
+    {
+        UniformSampler::new_inclusive(low.borrow().inner, high.borrow().inner)
+            .map(|inner| UniformTotalFloat { inner, policy: PhantomData })
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        TotalFloatWith::new(self.inner.sample(rng))
+    }
+}
+
+impl<P, F> SampleUniform for TotalFloatWith<P, F>
+    where P: NanPolicy,
+          F: RawFloat + SampleUniform
+{
+    type Sampler = UniformTotalFloat<P, F>;
+}
+
+/// Creates a list of `n` random TotalFloat values, sampled uniformly from
+/// [0, 1).
+pub fn random_tfvec<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Vec<TotalFloat> {
+    (0..n).map(|_| rng.random()).collect()
+}
@@ -0,0 +1,61 @@
+use alloc::vec::Vec;
+
+use merge_sort;
+use TotalFloat;
+
+/// How tied values are ranked by `ranks`.
+///
+/// Ranks start at 1, for the least value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RankMethod {
+    /// Tied values all take the least rank of the group, as in competition
+    /// ranking ("1224").
+    Min,
+    /// Tied values all take the greatest rank of the group ("1334").
+    Max,
+    /// Tied values all take the same rank, and the next group takes the next
+    /// rank, leaving no gaps ("1223").
+    Dense,
+    /// Every value takes a distinct rank, with tied values ranked in the order
+    /// they appear ("1234").
+    Ordinal,
+    /// Tied values all take the mean of the ranks of the group, giving
+    /// fractional ranks ("1 2.5 2.5 4").
+    Average,
+}
+
+/// Ranks a slice of TotalFloat values, returning the rank of each value in the
+/// same position.
+///
+/// Ties are detected with TotalFloat equality, so all NaNs share a rank, as
+/// do -0.0 and +0.0. As NaN is least, NaNs take the lowest ranks.
+pub fn ranks(data: &[TotalFloat], method: RankMethod) -> Vec<f64> {
+    // Sort the indices of the values by value. Pairing each value with its
+    // index keeps tied values in the order they appear.
+    let order: Vec<_> = data.iter().cloned().zip(0..).collect();
+    let order = merge_sort(order);
+
+    let mut ranks = alloc::vec![0.0; data.len()];
+    let mut dense = 0;
+    let mut start = 0;
+    // Walk the groups of tied values, each spanning the sorted positions
+    // `start..end`, and assign the ranks of each group.
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && order[end].0 == order[start].0 {
+            end += 1;
+        }
+        dense += 1;
+        for (i, &(_, index)) in order[start..end].iter().enumerate() {
+            ranks[index] = match method {
+                RankMethod::Min => (start + 1) as f64,
+                RankMethod::Max => end as f64,
+                RankMethod::Dense => dense as f64,
+                RankMethod::Ordinal => (start + i + 1) as f64,
+                RankMethod::Average => (start + 1 + end) as f64 / 2.0,
+            };
+        }
+        start = end;
+    }
+    ranks
+}
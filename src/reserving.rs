@@ -0,0 +1,68 @@
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use merge_sort_slice_by;
+use presorted_by;
+use SortConfig;
+use TotalFloat;
+
+/// The error returned by `try_merge_sort_reserving` when the scratch buffer
+/// cannot be allocated, giving the input back untouched.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReserveFailed {
+    /// The error from reserving the scratch buffer.
+    pub error: TryReserveError,
+    /// The input, as it was given.
+    pub input: Vec<TotalFloat>,
+}
+
+impl fmt::Display for ReserveFailed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not reserve the scratch buffer: {}", self.error)
+    }
+}
+
+impl Error for ReserveFailed {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Sorts a list of TotalFloat values as `merge_sort` does, returning an error
+/// rather than aborting if the scratch buffer cannot be allocated.
+///
+/// The scratch buffer, of the length of the list, is the only allocation the
+/// sort makes, and it is made through `Vec::try_reserve_exact` before any
+/// value is moved, so on error the input is returned as it was given. Input
+/// that is already in order needs no scratch buffer, so cannot fail.
+pub fn try_merge_sort_reserving(mut input: Vec<TotalFloat>)
+                                -> Result<Vec<TotalFloat>, ReserveFailed> {
+    match try_merge_sort_in_place_reserving(&mut input) {
+        Ok(()) => Ok(input),
+        Err(error) => Err(ReserveFailed { error, input }),
+    }
+}
+
+/// Sorts a slice of TotalFloat values in place as `merge_sort_in_place`
+/// does, returning an error rather than aborting if the scratch buffer
+/// cannot be allocated.
+///
+/// The scratch buffer is allocated before any value is moved, so on error
+/// the slice is left exactly as it was given.
+pub fn try_merge_sort_in_place_reserving(input: &mut [TotalFloat])
+                                         -> Result<(), TryReserveError> {
+    // Only input that is already ascending or strictly descending is put in
+    // order here, so anything else is still untouched.
+    if !presorted_by(input, &mut TotalFloat::cmp, &mut ()) {
+        let mut scratch = Vec::new();
+        scratch.try_reserve_exact(input.len())?;
+        merge_sort_slice_by(input,
+                            &mut scratch,
+                            &SortConfig::new(),
+                            &mut TotalFloat::cmp,
+                            &mut ());
+    }
+    Ok(())
+}
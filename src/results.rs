@@ -0,0 +1,34 @@
+use alloc::vec::Vec;
+
+use co_sort;
+use merge_sort;
+use wrap_vec;
+use TotalFloat;
+
+/// Sorts the values of an iterator of results, or returns the first error.
+///
+/// As with collecting into a `Result`, iteration stops at the first error,
+/// which is returned as it was, and nothing is sorted.
+pub fn try_sort_results<E, I>(iter: I) -> Result<Vec<TotalFloat>, E>
+    where I: IntoIterator<Item = Result<f64, E>>
+{
+    let values = iter.into_iter().collect::<Result<Vec<f64>, E>>()?;
+    Ok(merge_sort(wrap_vec(values)))
+}
+
+/// Sorts a list of values by a fallible f64 key, under the total order of
+/// TotalFloat, or returns the first error of the key.
+///
+/// The key is computed exactly once for each value, in order, stopping at the
+/// first error, before anything is sorted. As with `merge_sort_by_total_key`
+/// the sort is stable.
+pub fn try_sorted_by_key<T, E, F>(mut input: Vec<T>, mut key: F)
+                                  -> Result<Vec<T>, E>
+    where F: FnMut(&T) -> Result<f64, E>
+{
+    let mut keys = input.iter()
+        .map(|x| key(x).map(TotalFloat::new))
+        .collect::<Result<Vec<_>, E>>()?;
+    co_sort(&mut keys, &mut input);
+    Ok(input)
+}
@@ -0,0 +1,145 @@
+use NanHandling;
+use TotalFloat;
+
+/// An accumulator of summary statistics over a stream of TotalFloat values,
+/// which holds none of the values themselves.
+///
+/// The mean and variance are kept with Welford's algorithm, which avoids the
+/// loss of precision of summing squares. NaNs are counted apart from the other
+/// values, and the NanHandling given on construction decides whether they
+/// make the statistics NaN, or are left out of them.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RunningStats {
+    nans: NanHandling,
+    nan_count: usize,
+    // The count, extrema and moments of the values that are not NaN.
+    count: usize,
+    min: Option<TotalFloat>,
+    max: Option<TotalFloat>,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    /// Creates a new, empty RunningStats, treating NaN values as given.
+    pub fn new(nans: NanHandling) -> RunningStats {
+        RunningStats {
+            nans,
+            nan_count: 0,
+            count: 0,
+            min: None,
+            max: None,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Adds a value to the statistics.
+    pub fn push(&mut self, value: TotalFloat) {
+        if value.is_nan() {
+            self.nan_count += 1;
+            return;
+        }
+        self.count += 1;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        let delta = value.inner - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value.inner - self.mean);
+    }
+
+    /// Adds every value of another RunningStats, as if they had been pushed to
+    /// this one.
+    ///
+    /// This allows statistics gathered in parallel to be combined. The NaN
+    /// handling of this RunningStats is kept.
+    pub fn merge(&mut self, other: &RunningStats) {
+        self.nan_count += other.nan_count;
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            self.count = other.count;
+            self.min = other.min;
+            self.max = other.max;
+            self.mean = other.mean;
+            self.m2 = other.m2;
+            return;
+        }
+        // Chan et al's formula for combining the moments of two sets.
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// The number of values pushed, including NaNs.
+    pub fn count(&self) -> usize {
+        self.count + self.nan_count
+    }
+
+    /// The number of NaN values pushed.
+    pub fn nan_count(&self) -> usize {
+        self.nan_count
+    }
+
+    /// The least value, or None if there are no values.
+    ///
+    /// Under `NanHandling::Propagate` this is NaN if any value is NaN, as NaN
+    /// is least, and under `NanHandling::Ignore` it is the least other value.
+    pub fn min(&self) -> Option<TotalFloat> {
+        if self.propagates() {
+            Some(TotalFloat::NAN)
+        } else {
+            self.min
+        }
+    }
+
+    /// The greatest value, or None if there are no values.
+    ///
+    /// As NaN is least this is only NaN if there are no other values, and
+    /// then only under `NanHandling::Propagate`.
+    pub fn max(&self) -> Option<TotalFloat> {
+        match self.max {
+            None if self.propagates() => Some(TotalFloat::NAN),
+            max => max,
+        }
+    }
+
+    /// The mean of the values, or None if there are no values.
+    pub fn mean(&self) -> Option<TotalFloat> {
+        self.moment(self.mean)
+    }
+
+    /// The population variance of the values, or None if there are no values.
+    pub fn variance(&self) -> Option<TotalFloat> {
+        self.moment(self.m2 / self.count as f64)
+    }
+
+    /// The population standard deviation of the values, or None if there are
+    /// no values.
+    #[cfg(feature = "std")]
+    pub fn stddev(&self) -> Option<TotalFloat> {
+        self.variance().map(TotalFloat::sqrt)
+    }
+
+    // Whether a NaN should be propagated into the statistics.
+    fn propagates(&self) -> bool {
+        self.nans == NanHandling::Propagate && self.nan_count > 0
+    }
+
+    // A moment of the values that are not NaN, propagating NaNs as needed.
+    fn moment(&self, value: f64) -> Option<TotalFloat> {
+        if self.propagates() {
+            Some(TotalFloat::NAN)
+        } else if self.count == 0 {
+            None
+        } else {
+            Some(TotalFloat::new(value))
+        }
+    }
+}
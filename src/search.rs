@@ -0,0 +1,134 @@
+use core::ops::Range;
+
+use TotalFloat;
+
+/// Binary searches a sorted slice of TotalFloat values for the given value.
+///
+/// This has the same semantics as `slice::binary_search` under the total
+/// order of TotalFloat: if the value is found then `Ok` of its index is
+/// returned, and otherwise `Err` of the index where it could be inserted while
+/// keeping the slice sorted. If several elements are equal to the value, any
+/// one of their indices may be returned.
+///
+/// As all NaNs are equal and least, searching for a NaN finds the block of
+/// NaNs at the front of the slice, and -0.0 and +0.0 find each other.
+pub fn binary_search(sorted: &[TotalFloat],
+                     target: f64)
+                     -> Result<usize, usize> {
+    sorted.binary_search(&TotalFloat::new(target))
+}
+
+/// Binary searches a slice of f64, sorted under the total order of
+/// TotalFloat, for the given value.
+///
+/// See `binary_search`.
+pub fn binary_search_f64(sorted: &[f64], target: f64) -> Result<usize, usize> {
+    binary_search(TotalFloat::slice_from(sorted), target)
+}
+
+/// The index of the first element of a sorted slice of TotalFloat values that
+/// is not less than the given value, or the length of the slice if there is
+/// none.
+///
+/// Equality is that of TotalFloat, so all NaNs are equal to each other and
+/// -0.0 is equal to +0.0: the lower bound of any NaN is the start of the block
+/// of NaNs, and the lower bounds of -0.0 and +0.0 are the same.
+pub fn lower_bound(sorted: &[TotalFloat], target: f64) -> usize {
+    let target = TotalFloat::new(target);
+    sorted.partition_point(|x| *x < target)
+}
+
+/// The index of the first element of a sorted slice of TotalFloat values that
+/// is greater than the given value, or the length of the slice if there is
+/// none.
+///
+/// Equality is as for `lower_bound`, so that the upper bound of any NaN is the
+/// end of the block of NaNs.
+pub fn upper_bound(sorted: &[TotalFloat], target: f64) -> usize {
+    let target = TotalFloat::new(target);
+    sorted.partition_point(|x| *x <= target)
+}
+
+/// The half-open range of indices of the elements of a sorted slice of
+/// TotalFloat values that are equal to the given value.
+///
+/// This spans from `lower_bound` to `upper_bound`, and is empty, at the
+/// index where the value could be inserted, if there are no equal elements.
+pub fn equal_range(sorted: &[TotalFloat], target: f64) -> Range<usize> {
+    lower_bound(sorted, target)..upper_bound(sorted, target)
+}
+
+/// The elements of a sorted slice of TotalFloat values in the half-open range
+/// `[lo, hi)`, under the total order.
+///
+/// This spans from the `lower_bound` of `lo` to that of `hi`, and is empty if
+/// `hi` is not greater than `lo`. As NaN is least, a `lo` of negative infinity
+/// includes everything but NaNs, and NaNs are only included by a `lo` of NaN.
+pub fn slice_in_range(sorted: &[TotalFloat],
+                      lo: f64,
+                      hi: f64)
+                      -> &[TotalFloat] {
+    let start = lower_bound(sorted, lo);
+    let end = lower_bound(sorted, hi).max(start);
+    &sorted[start..end]
+}
+
+/// The number of elements of a sorted slice of TotalFloat values in the
+/// half-open range `[lo, hi)`, under the total order.
+///
+/// See `slice_in_range`.
+pub fn count_in_range(sorted: &[TotalFloat], lo: f64, hi: f64) -> usize {
+    slice_in_range(sorted, lo, hi).len()
+}
+
+/// The element of a sorted slice of TotalFloat values closest to the given
+/// value, with its index, or None if there is none.
+///
+/// Of two elements equally close, the smaller is returned, and of several
+/// equal elements, the one nearest the value's position in the slice. NaN
+/// elements are never closest to a value that is not NaN, whereas a NaN value
+/// finds the first NaN element, if there is one.
+pub fn nearest(sorted: &[TotalFloat],
+               target: f64)
+               -> Option<(usize, TotalFloat)> {
+    let end_of_nans = upper_bound(sorted, f64::NAN);
+    if target.is_nan() {
+        return if end_of_nans > 0 { Some((0, sorted[0])) } else { None };
+    }
+    let i = lower_bound(sorted, target);
+    let below = if i > end_of_nans { Some(i - 1) } else { None };
+    let above = if i < sorted.len() { Some(i) } else { None };
+    let index = match (below, above) {
+        (Some(below), Some(above)) => {
+            // An equal element is closest, and must be checked first, as the
+            // distance between equal infinities is NaN.
+            let (lo, hi) = (sorted[below].inner, sorted[above].inner);
+            if sorted[above] == TotalFloat::new(target) ||
+               hi - target < target - lo {
+                above
+            } else {
+                below
+            }
+        }
+        (below, above) => below.or(above)?,
+    };
+    Some((index, sorted[index]))
+}
+
+/// As `lower_bound`, for a slice of f64 sorted under the total order of
+/// TotalFloat.
+pub fn lower_bound_f64(sorted: &[f64], target: f64) -> usize {
+    lower_bound(TotalFloat::slice_from(sorted), target)
+}
+
+/// As `upper_bound`, for a slice of f64 sorted under the total order of
+/// TotalFloat.
+pub fn upper_bound_f64(sorted: &[f64], target: f64) -> usize {
+    upper_bound(TotalFloat::slice_from(sorted), target)
+}
+
+/// As `equal_range`, for a slice of f64 sorted under the total order of
+/// TotalFloat.
+pub fn equal_range_f64(sorted: &[f64], target: f64) -> Range<usize> {
+    equal_range(TotalFloat::slice_from(sorted), target)
+}
@@ -0,0 +1,95 @@
+use TotalFloat;
+
+/// How `quantile` chooses a value when the quantile falls between two values.
+///
+/// For a quantile `q` of `n` values, the position sought in the sorted values
+/// is `q * (n - 1)`. If this lies between the values at indices `i` and
+/// `i + 1`, then:
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// The value at index `i` is chosen.
+    Lower,
+    /// The value at index `i + 1` is chosen.
+    Higher,
+    /// The value at the closer index is chosen, or at the even index if the
+    /// position lies halfway.
+    Nearest,
+    /// The mean of the two values is chosen.
+    Midpoint,
+    /// The two values are interpolated linearly by the position between them.
+    Linear,
+}
+
+/// Reorders a slice of TotalFloat values so that the value at index `k` is the
+/// one that would be there if the slice were sorted, returning the values
+/// before it, the value itself, and the values after it.
+///
+/// Every value before index `k` is less than or equal to it, and every value
+/// after is greater than or equal to it, under the total order of TotalFloat.
+/// This mirrors `slice::select_nth_unstable`, which it uses: an introselect
+/// that falls back to median of medians, so it runs in linear time even on
+/// adversarial input.
+///
+/// # Panics
+///
+/// Panics if `k` is not less than the length of the slice.
+pub fn select_nth(data: &mut [TotalFloat],
+                  k: usize)
+                  -> (&mut [TotalFloat], &mut TotalFloat, &mut [TotalFloat]) {
+    data.select_nth_unstable(k)
+}
+
+/// The median of a slice of TotalFloat values, or None if it is empty.
+///
+/// This is the quantile 0.5 with linear interpolation, so the median of an
+/// even number of values is the mean of the middle two. See `quantile`.
+pub fn median(data: &mut [TotalFloat]) -> Option<TotalFloat> {
+    quantile(data, 0.5, Interpolation::Linear)
+}
+
+/// The quantile `q` of a slice of TotalFloat values, or None if it is empty or
+/// `q` is not within `[0, 1]`.
+///
+/// This runs in expected linear time, by selection rather than by sorting, and
+/// reorders the slice as it does so.
+///
+/// The values are ordered by the total order of TotalFloat, so NaNs occupy the
+/// low end: if the low quantiles fall on NaNs they are NaN, and higher
+/// quantiles are unaffected other than by the number of values. Filter out
+/// NaNs beforehand to compute quantiles of the other values alone.
+pub fn quantile(data: &mut [TotalFloat],
+                q: f64,
+                interp: Interpolation)
+                -> Option<TotalFloat> {
+    if data.is_empty() || !(0.0..=1.0).contains(&q) {
+        return None;
+    }
+    let position = q * (data.len() - 1) as f64;
+    // The position is non-negative, so truncating it is flooring it.
+    let i = position as usize;
+    let fraction = position - i as f64;
+
+    let (_, &mut lower, higher) = select_nth(data, i);
+    // Everything after index i is at least the value at i, so the least of it
+    // is the value at i + 1.
+    let upper = match higher.iter().min() {
+        Some(&upper) if fraction > 0.0 => upper,
+        _ => return Some(lower),
+    };
+    Some(match interp {
+        Interpolation::Lower => lower,
+        Interpolation::Higher => upper,
+        Interpolation::Nearest => {
+            if fraction < 0.5 || (fraction == 0.5 && i.is_multiple_of(2)) {
+                lower
+            } else {
+                upper
+            }
+        }
+        _ if lower == upper => lower,
+        // The bounds are weighted rather than differenced, so that these
+        // cannot overflow to infinity when they span more than f64::MAX.
+        Interpolation::Midpoint => lower / 2.0 + upper / 2.0,
+        Interpolation::Linear => lower * (1.0 - fraction) + upper * fraction,
+    })
+}
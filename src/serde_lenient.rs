@@ -0,0 +1,184 @@
+//! Lenient deserialization of TotalFloat, for data from sources which are not
+//! careful about how they write numbers.
+//!
+//! As well as numbers this accepts numeric strings (such as `"3.14"`), the
+//! spellings of the special values accepted by `TotalFloat::from_str` (such as
+//! `"NaN"`, `"Infinity"` and `"-Infinity"`), and null, which is taken to mean
+//! NaN. For use on a field with `#[serde(with = "fc_sort::serde_lenient")]`,
+//! or on a `Vec<TotalFloat>` field with
+//! `#[serde(with = "fc_sort::serde_lenient::vec")]`.
+//!
+//! Values are serialized as by `fc_sort::serde_special`, so anything written
+//! can be read back.
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serializer;
+
+use serde_special;
+use NanPolicy;
+use TotalFloatWith;
+
+/// Serializes a TotalFloat as a number, or as a string if it is not finite.
+pub fn serialize<P, S>(value: &TotalFloatWith<P>,
+                       serializer: S)
+                       -> Result<S::Ok, S::Error>
+    where P: NanPolicy,
+          S: Serializer
+{
+    serde_special::serialize(value, serializer)
+}
+
+/// Deserializes a TotalFloat leniently.
+pub fn deserialize<'de, P, D>(deserializer: D)
+                              -> Result<TotalFloatWith<P>, D::Error>
+    where P: NanPolicy,
+          D: Deserializer<'de>
+{
+    deserializer.deserialize_any(LenientVisitor(PhantomData))
+}
+
+/// Deserializes a sequence of leniently written values into a Vec of
+/// TotalFloat.
+///
+/// If an element cannot be read, the error reports its index.
+pub fn deserialize_vec<'de, P, D>(deserializer: D)
+                                  -> Result<Vec<TotalFloatWith<P>>, D::Error>
+    where P: NanPolicy,
+          D: Deserializer<'de>
+{
+    deserializer.deserialize_seq(LenientSeqVisitor(PhantomData))
+}
+
+/// Lenient serialization of a whole `Vec<TotalFloat>`.
+pub mod vec {
+    use alloc::vec::Vec;
+    use serde::ser::SerializeSeq;
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    use NanPolicy;
+    use TotalFloatWith;
+
+    /// Serializes each TotalFloat as by `fc_sort::serde_special`.
+    pub fn serialize<P, S>(values: &[TotalFloatWith<P>],
+                           serializer: S)
+                           -> Result<S::Ok, S::Error>
+        where P: NanPolicy,
+              S: Serializer
+    {
+        let mut seq = serializer.serialize_seq(Some(values.len()))?;
+        for value in values {
+            seq.serialize_element(&super::Special(*value))?;
+        }
+        seq.end()
+    }
+
+    /// Deserializes a sequence leniently. See `deserialize_vec`.
+    pub fn deserialize<'de, P, D>(deserializer: D)
+                                  -> Result<Vec<TotalFloatWith<P>>, D::Error>
+        where P: NanPolicy,
+              D: Deserializer<'de>
+    {
+        super::deserialize_vec(deserializer)
+    }
+}
+
+// Serializes a single element of a sequence as by serde_special.
+struct Special<P: NanPolicy>(TotalFloatWith<P>);
+
+impl<P: NanPolicy> ::serde::Serialize for Special<P> {
+    fn serialize<S: Serializer>(&self, serializer: S)
+                                -> Result<S::Ok, S::Error> {
+        serde_special::serialize(&self.0, serializer)
+    }
+}
+
+// Deserializes a single element of a sequence leniently.
+struct Lenient<P: NanPolicy>(TotalFloatWith<P>);
+
+impl<'de, P: NanPolicy> Deserialize<'de> for Lenient<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D)
+                                         -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Lenient)
+    }
+}
+
+struct LenientVisitor<P>(PhantomData<P>);
+
+impl<'de, P: NanPolicy> de::Visitor<'de> for LenientVisitor<P> {
+    type Value = TotalFloatWith<P>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a number, a numeric string or null")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(TotalFloatWith::new(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(TotalFloatWith::new(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(TotalFloatWith::new(v as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.trim()
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(TotalFloatWith::new(f64::NAN))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(TotalFloatWith::new(f64::NAN))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserialize(deserializer)
+    }
+}
+
+struct LenientSeqVisitor<P>(PhantomData<P>);
+
+impl<'de, P: NanPolicy> de::Visitor<'de> for LenientSeqVisitor<P> {
+    type Value = Vec<TotalFloatWith<P>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of numbers, numeric strings or nulls")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where A: de::SeqAccess<'de>
+    {
+        // The length is claimed by the input itself, so it is only trusted
+        // up to a bound, as by serde's own cautious size hints.
+        let hint = seq.size_hint().map_or(0, |n| n.min(4096));
+        let mut values = Vec::with_capacity(hint);
+        loop {
+            // Errors are rewrapped to say which element could not be read.
+            let index = values.len();
+            match seq.next_element::<Lenient<P>>() {
+                Ok(Some(Lenient(value))) => values.push(value),
+                Ok(None) => return Ok(values),
+                Err(e) => {
+                    return Err(de::Error::custom(format!("at index {}: {}",
+                                                         index,
+                                                         e)))
+                }
+            }
+        }
+    }
+}
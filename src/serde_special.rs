@@ -0,0 +1,77 @@
+//! Serialization of TotalFloat which tolerates NaN and the infinities in
+//! formats that reject non-finite numbers, such as JSON.
+//!
+//! Finite values are written as numbers, and NaN, positive infinity and
+//! negative infinity as the strings `"NaN"`, `"inf"` and `"-inf"`. Either form
+//! is accepted when deserializing. For use on a field with
+//! `#[serde(with = "fc_sort::serde_special")]`.
+//!
+//! NaN payloads are not preserved, as every NaN is written as `"NaN"`.
+
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de;
+use serde::Deserializer;
+use serde::Serializer;
+
+use NanPolicy;
+use TotalFloatWith;
+
+/// Serializes a TotalFloat as a number, or as a string if it is not finite.
+pub fn serialize<P, S>(value: &TotalFloatWith<P>,
+                       serializer: S)
+                       -> Result<S::Ok, S::Error>
+    where P: NanPolicy,
+          S: Serializer
+{
+    if value.is_nan() {
+        serializer.serialize_str("NaN")
+    } else if value.inner == f64::INFINITY {
+        serializer.serialize_str("inf")
+    } else if value.inner == f64::NEG_INFINITY {
+        serializer.serialize_str("-inf")
+    } else {
+        serializer.serialize_f64(value.inner)
+    }
+}
+
+/// Deserializes a TotalFloat from a number, or from one of the strings
+/// written by `serialize`.
+pub fn deserialize<'de, P, D>(deserializer: D)
+                              -> Result<TotalFloatWith<P>, D::Error>
+    where P: NanPolicy,
+          D: Deserializer<'de>
+{
+    deserializer.deserialize_any(SpecialVisitor(PhantomData))
+}
+
+struct SpecialVisitor<P>(PhantomData<P>);
+
+impl<'de, P: NanPolicy> de::Visitor<'de> for SpecialVisitor<P> {
+    type Value = TotalFloatWith<P>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a number, or one of \"NaN\", \"inf\" and \"-inf\"")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(TotalFloatWith::new(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(TotalFloatWith::new(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(TotalFloatWith::new(v as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match v {
+            "NaN" => Ok(TotalFloatWith::new(f64::NAN)),
+            "inf" => Ok(TotalFloatWith::new(f64::INFINITY)),
+            "-inf" => Ok(TotalFloatWith::new(f64::NEG_INFINITY)),
+            _ => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+}
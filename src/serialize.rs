@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use NanPolicy;
+use RawFloat;
+use TotalFloatWith;
+
+// Serialize and Deserialize Implementations
+// TotalFloat is serialized transparently as the float it wraps. Formats which
+// cannot represent NaN or the infinities as numbers (such as JSON) may need
+// `fc_sort::serde_special` instead.
+impl<P, F> Serialize for TotalFloatWith<P, F>
+    where P: NanPolicy,
+          F: RawFloat + Serialize
+{
+    fn serialize<S: Serializer>(&self, serializer: S)
+                                -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+impl<'de, P, F> Deserialize<'de> for TotalFloatWith<P, F>
+    where P: NanPolicy,
+          F: RawFloat + Deserialize<'de>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D)
+                                         -> Result<Self, D::Error> {
+        F::deserialize(deserializer).map(Self::new)
+    }
+}
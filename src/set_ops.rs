@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use TotalFloat;
+
+// Walks two sorted slices together, pairing each value with the next equal
+// value of the other slice if there is one, and keeps the values of `a`
+// without a pair, those of `b` without a pair, and the paired values of `a`,
+// as asked.
+fn set_op(a: &[TotalFloat],
+          b: &[TotalFloat],
+          keep_a: bool,
+          keep_b: bool,
+          keep_both: bool)
+          -> Vec<TotalFloat> {
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                if keep_a {
+                    out.push(a[i]);
+                }
+                i += 1;
+            }
+            Ordering::Greater => {
+                if keep_b {
+                    out.push(b[j]);
+                }
+                j += 1;
+            }
+            Ordering::Equal => {
+                if keep_both {
+                    out.push(a[i]);
+                }
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    if keep_a {
+        out.extend_from_slice(&a[i..]);
+    }
+    if keep_b {
+        out.extend_from_slice(&b[j..]);
+    }
+    out
+}
+
+/// The union of two sorted slices of TotalFloat values, in order.
+///
+/// Equality is that of TotalFloat, so all NaNs are one value, as are -0.0 and
+/// 0.0. Duplicates are counted, so a value appears as many times as the most
+/// it appears in either slice. Of equal values, those of `a` are kept first.
+pub fn sorted_union(a: &[TotalFloat], b: &[TotalFloat]) -> Vec<TotalFloat> {
+    set_op(a, b, true, true, true)
+}
+
+/// The intersection of two sorted slices of TotalFloat values, in order.
+///
+/// A value appears as many times as the fewest it appears in either slice,
+/// with the values kept from `a`. See `sorted_union`.
+pub fn sorted_intersection(a: &[TotalFloat],
+                           b: &[TotalFloat])
+                           -> Vec<TotalFloat> {
+    set_op(a, b, false, false, true)
+}
+
+/// The values of a sorted slice of TotalFloat values that are not in another,
+/// in order.
+///
+/// A value appears as many times as it appears in `a` more than in `b`. See
+/// `sorted_union`.
+pub fn sorted_difference(a: &[TotalFloat],
+                         b: &[TotalFloat])
+                         -> Vec<TotalFloat> {
+    set_op(a, b, true, false, false)
+}
+
+/// The values of two sorted slices of TotalFloat values that are in one but
+/// not the other, in order.
+///
+/// A value appears as many times as it appears in one slice more than in the
+/// other. See `sorted_union`.
+pub fn sorted_symmetric_difference(a: &[TotalFloat],
+                                   b: &[TotalFloat])
+                                   -> Vec<TotalFloat> {
+    set_op(a, b, true, true, false)
+}
+
+/// Checks whether every value of a sorted slice of TotalFloat values is in
+/// another, counting duplicates.
+///
+/// That is, whether no value appears more times in `a` than in `b`. See
+/// `sorted_union`.
+pub fn sorted_is_subset(a: &[TotalFloat], b: &[TotalFloat]) -> bool {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => return false,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    i == a.len()
+}
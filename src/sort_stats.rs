@@ -0,0 +1,64 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use sort_slice_counting;
+use Counter;
+use SortConfig;
+use TotalFloat;
+
+/// The work done by `merge_sort_with_stats`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SortStats {
+    /// The number of calls to the comparison, including those made checking
+    /// whether the input was already sorted.
+    pub comparisons: u64,
+    /// The number of values moved to new positions, counting each move into
+    /// and out of the scratch buffer.
+    pub moves: u64,
+    /// The number of merges of two sorted runs.
+    pub merges: u32,
+    /// The most sorted runs waiting to be merged at once. The sort works from
+    /// the bottom up, so this stands in for its recursion depth, and is 0 for
+    /// input that is already in order.
+    pub max_depth: u32,
+    /// The number of heap allocations made, which is 1 for the scratch buffer
+    /// unless the input was already in order.
+    pub allocations: u32,
+}
+
+impl Counter for SortStats {
+    fn moved(&mut self, count: usize) {
+        self.moves += count as u64;
+    }
+
+    fn merged(&mut self) {
+        self.merges += 1;
+    }
+
+    fn pushed_run(&mut self, depth: usize) {
+        self.max_depth = self.max_depth.max(depth as u32);
+    }
+
+    fn allocated(&mut self) {
+        self.allocations += 1;
+    }
+}
+
+/// Sorts a list of TotalFloat values as `merge_sort` does, also returning
+/// the work it did.
+///
+/// This is for tuning, and is slower than `merge_sort` for the counting. The
+/// counting is shared with `merge_sort` through a counter that does nothing
+/// there, so the plain sort pays nothing for it.
+pub fn merge_sort_with_stats(mut input: Vec<TotalFloat>)
+                             -> (Vec<TotalFloat>, SortStats) {
+    let mut stats = SortStats::default();
+    let mut comparisons = 0;
+    let mut cmp = |a: &TotalFloat, b: &TotalFloat| -> Ordering {
+        comparisons += 1;
+        a.cmp(b)
+    };
+    sort_slice_counting(&mut input, &SortConfig::new(), &mut cmp, &mut stats);
+    stats.comparisons = comparisons;
+    (input, stats)
+}
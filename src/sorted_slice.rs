@@ -0,0 +1,82 @@
+use core::iter::Rev;
+use core::slice;
+
+use binary_search;
+use is_sorted;
+use TotalFloat;
+
+/// A slice of TotalFloat values which is known to be in ascending order,
+/// created by `as_sorted`.
+///
+/// Taking a SortedSlice rather than a plain slice lets a function require
+/// sorted input in its signature, rather than checking for it or trusting it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SortedSlice<'a> {
+    inner: &'a [TotalFloat],
+}
+
+/// Views a slice of TotalFloat values as a SortedSlice, or returns None if it
+/// is not in ascending order.
+///
+/// This checks the order with a single linear scan, see `is_sorted`.
+pub fn as_sorted<'a>(input: &'a [TotalFloat]) -> Option<SortedSlice<'a>> {
+    if is_sorted(input) {
+        Some(SortedSlice { inner: input })
+    } else {
+        None
+    }
+}
+
+impl<'a> SortedSlice<'a> {
+    /// The sorted values, as a plain slice.
+    pub fn as_slice(self) -> &'a [TotalFloat] {
+        self.inner
+    }
+
+    /// The number of values.
+    pub fn len(self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether there are no values.
+    pub fn is_empty(self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// An iterator over the values in ascending order.
+    pub fn iter(self) -> slice::Iter<'a, TotalFloat> {
+        self.inner.iter()
+    }
+
+    /// An iterator over the values in descending order.
+    ///
+    /// As NaN is least, any NaNs come last.
+    pub fn iter_desc(self) -> Rev<slice::Iter<'a, TotalFloat>> {
+        self.inner.iter().rev()
+    }
+
+    /// The least value, which is a NaN if there are any, or None if empty.
+    pub fn min(self) -> Option<TotalFloat> {
+        self.inner.first().cloned()
+    }
+
+    /// The greatest value, or None if empty.
+    pub fn max(self) -> Option<TotalFloat> {
+        self.inner.last().cloned()
+    }
+
+    /// Binary searches the values for the given value.
+    ///
+    /// See `binary_search`.
+    pub fn binary_search(self, target: f64) -> Result<usize, usize> {
+        binary_search(self.inner, target)
+    }
+}
+
+impl<'a> IntoIterator for SortedSlice<'a> {
+    type Item = &'a TotalFloat;
+    type IntoIter = slice::Iter<'a, TotalFloat>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
@@ -0,0 +1,151 @@
+use TotalFloat;
+
+/// How NaN values are treated by the summary functions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NanHandling {
+    /// Any NaN makes the result NaN, as it would in plain f64 arithmetic.
+    Propagate,
+    /// NaN values are skipped, and the number skipped is reported.
+    Ignore,
+}
+
+/// Sums a slice of TotalFloat values with Neumaier's compensated summation,
+/// which keeps the error independent of the number of values.
+///
+/// NaN values propagate. See `sum_compensated_with`.
+pub fn sum_compensated(values: &[TotalFloat]) -> TotalFloat {
+    sum_compensated_with(values, NanHandling::Propagate).0
+}
+
+/// Sums a slice of TotalFloat values with Neumaier's compensated summation,
+/// treating NaN values as given.
+///
+/// Also returns the number of NaN values skipped, which is always zero under
+/// `NanHandling::Propagate`.
+pub fn sum_compensated_with(values: &[TotalFloat],
+                            nans: NanHandling)
+                            -> (TotalFloat, usize) {
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    let mut skipped = 0;
+    for value in values {
+        let x = value.inner;
+        if x.is_nan() && nans == NanHandling::Ignore {
+            skipped += 1;
+            continue;
+        }
+        // Whichever of the running sum and the new value is smaller in
+        // magnitude has its low order bits lost by the addition, and those are
+        // recovered into the compensation.
+        let t = sum + x;
+        if f64::abs(sum) >= f64::abs(x) {
+            compensation += (sum - t) + x;
+        } else {
+            compensation += (x - t) + sum;
+        }
+        sum = t;
+    }
+    // Once the sum is infinite or NaN the compensation is meaningless (and
+    // would itself be NaN), so the plain sum is returned.
+    if sum.is_finite() {
+        (TotalFloat::new(sum + compensation), skipped)
+    } else {
+        (TotalFloat::new(sum), skipped)
+    }
+}
+
+/// The mean of a slice of TotalFloat values, using compensated summation, or
+/// None if the slice is empty.
+///
+/// NaN values propagate. See `mean_with`.
+pub fn mean(values: &[TotalFloat]) -> Option<TotalFloat> {
+    mean_with(values, NanHandling::Propagate).0
+}
+
+/// The mean of a slice of TotalFloat values, using compensated summation,
+/// treating NaN values as given.
+///
+/// The mean is None if there are no values left to average. Also returns the
+/// number of NaN values skipped, which is always zero under
+/// `NanHandling::Propagate`.
+pub fn mean_with(values: &[TotalFloat],
+                 nans: NanHandling)
+                 -> (Option<TotalFloat>, usize) {
+    let (sum, skipped) = sum_compensated_with(values, nans);
+    let count = values.len() - skipped;
+    if count == 0 {
+        (None, skipped)
+    } else {
+        (Some(sum / count as f64), skipped)
+    }
+}
+
+/// The least value in a slice of TotalFloat values, or None if it is empty.
+///
+/// As NaN is least, this is NaN if any value is NaN. See `min_of_non_nan`.
+pub fn min_of(values: &[TotalFloat]) -> Option<TotalFloat> {
+    values.iter().min().cloned()
+}
+
+/// The greatest value in a slice of TotalFloat values, or None if it is empty.
+///
+/// As NaN is least, this is only NaN if every value is NaN.
+pub fn max_of(values: &[TotalFloat]) -> Option<TotalFloat> {
+    values.iter().max().cloned()
+}
+
+/// The least and greatest values in a slice of TotalFloat values, or None if
+/// it is empty.
+///
+/// This takes a single pass, making about three comparisons for every two
+/// values. Where there are several least or greatest values, the first least
+/// and the last greatest are returned.
+pub fn extrema(values: &[TotalFloat]) -> Option<(TotalFloat, TotalFloat)> {
+    pairwise_extrema(values.iter().cloned())
+}
+
+/// The least value in a slice of TotalFloat values that is not NaN, or None if
+/// there is none.
+pub fn min_of_non_nan(values: &[TotalFloat]) -> Option<TotalFloat> {
+    values.iter().filter(|x| !x.is_nan()).min().cloned()
+}
+
+/// The greatest value in a slice of TotalFloat values that is not NaN, or None
+/// if there is none.
+pub fn max_of_non_nan(values: &[TotalFloat]) -> Option<TotalFloat> {
+    values.iter().filter(|x| !x.is_nan()).max().cloned()
+}
+
+/// The least and greatest values in a slice of TotalFloat values that are not
+/// NaN, or None if there are none.
+///
+/// See `extrema`.
+pub fn extrema_non_nan(values: &[TotalFloat])
+                       -> Option<(TotalFloat, TotalFloat)> {
+    pairwise_extrema(values.iter().cloned().filter(|x| !x.is_nan()))
+}
+
+// Finds the least and greatest values by taking them in pairs, comparing the
+// lesser of each pair only with the least so far, and the greater only with
+// the greatest so far.
+fn pairwise_extrema<I>(mut iter: I) -> Option<(TotalFloat, TotalFloat)>
+    where I: Iterator<Item = TotalFloat>
+{
+    let first = iter.next()?;
+    let (mut min, mut max) = (first, first);
+    while let Some(a) = iter.next() {
+        // If the pair is incomplete, it is compared with both.
+        let (lo, hi) = match iter.next() {
+            Some(b) if b < a => (b, a),
+            Some(b) => (a, b),
+            None => (a, a),
+        };
+        if lo < min {
+            min = lo;
+        }
+        if hi >= max {
+            max = hi;
+        }
+    }
+    Some((min, max))
+}
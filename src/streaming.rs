@@ -0,0 +1,135 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::iter;
+use core::iter::Cloned;
+use core::mem;
+use core::slice;
+
+use insert_sorted;
+use kmerge;
+use merge;
+use merge_sort;
+use KMergeIter;
+use TotalFloat;
+
+// The most values pushed one at a time that are held before being made a run.
+const PENDING_LEN: usize = 32;
+
+/// A sorter for values that arrive over time, which keeps them as sorted runs
+/// so that the sorted result is cheap to take at any point.
+///
+/// Each batch is sorted as it arrives, and merged with the runs before it
+/// whenever they are not much longer, as in a log-structured merge, so that
+/// there are only `O(log n)` runs, each at least twice the length of the next.
+/// Values pushed one at a time are gathered into a short sorted buffer first.
+///
+/// As with `merge_sort`, the result is stable: equal values come out in the
+/// order they were pushed. Two sorters are equal if they hold equal values,
+/// however those values were pushed.
+#[derive(Clone, Debug, Default)]
+pub struct StreamingSorter {
+    // The sorted runs, oldest first.
+    runs: Vec<Vec<TotalFloat>>,
+    // The sorted values pushed one at a time since the last run, which are
+    // newer than all of the runs.
+    pending: Vec<TotalFloat>,
+    len: usize,
+}
+
+impl StreamingSorter {
+    /// Creates a new, empty StreamingSorter.
+    pub fn new() -> StreamingSorter {
+        StreamingSorter::default()
+    }
+
+    /// Adds a value.
+    pub fn push(&mut self, value: TotalFloat) {
+        insert_sorted(&mut self.pending, value);
+        self.len += 1;
+        if self.pending.len() >= PENDING_LEN {
+            self.flush();
+        }
+    }
+
+    /// Adds a batch of values, sorting them as a run of their own.
+    pub fn push_batch(&mut self, batch: &[TotalFloat]) {
+        if batch.is_empty() {
+            return;
+        }
+        self.flush();
+        self.len += batch.len();
+        self.push_run(merge_sort(batch.to_vec()));
+    }
+
+    /// The number of values added.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no values have been added.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// An iterator over the values added so far, in ascending order.
+    ///
+    /// This merges the runs lazily, without changing them.
+    pub fn iter_sorted<'a>(&'a self)
+                           -> KMergeIter<Cloned<slice::Iter<'a, TotalFloat>>> {
+        let runs = self.runs.iter().chain(iter::once(&self.pending));
+        kmerge(runs.map(|run| run.iter().cloned()))
+    }
+
+    /// The values added, in ascending order.
+    pub fn into_sorted_vec(mut self) -> Vec<TotalFloat> {
+        self.flush();
+        // The newest runs are the shortest, so merge from the back.
+        while self.runs.len() > 1 {
+            let newer = self.runs.pop().unwrap();
+            let older = self.runs.pop().unwrap();
+            self.runs.push(merge(older, newer));
+        }
+        self.runs.pop().unwrap_or_default()
+    }
+
+    // Makes the pending values a run.
+    fn flush(&mut self) {
+        if !self.pending.is_empty() {
+            let pending = mem::take(&mut self.pending);
+            self.push_run(pending);
+        }
+    }
+
+    // Adds a sorted run, newer than the rest, merging the newest runs until
+    // each is more than twice the length of the next.
+    fn push_run(&mut self, run: Vec<TotalFloat>) {
+        self.runs.push(run);
+        while let [.., older, newer] = &self.runs[..] {
+            if older.len() > 2 * newer.len() {
+                break;
+            }
+            let newer = self.runs.pop().unwrap();
+            let older = self.runs.pop().unwrap();
+            self.runs.push(merge(older, newer));
+        }
+    }
+}
+
+impl PartialEq for StreamingSorter {
+    fn eq(&self, other: &StreamingSorter) -> bool {
+        self.len == other.len && self.iter_sorted().eq(other.iter_sorted())
+    }
+}
+
+impl Eq for StreamingSorter {}
+
+impl Hash for StreamingSorter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // As for a sorted Vec of the values, so that equal sorters hash alike.
+        self.len.hash(state);
+        for x in self.iter_sorted() {
+            x.hash(state);
+        }
+    }
+}
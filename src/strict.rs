@@ -0,0 +1,100 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::ops::Deref;
+
+use TotalFloat;
+
+/// A TotalFloat whose equality and ordering distinguish every bit pattern.
+///
+/// Where TotalFloat treats -0.0 and +0.0 as equal, and all NaNs as equal, this
+/// orders -0.0 before +0.0 and orders NaNs by their bit patterns, so that only
+/// identical bit patterns are equal. This makes it suitable for keys (such as
+/// those of a BTreeMap or HashMap) which must round-trip exactly.
+///
+/// The ordering otherwise matches TotalFloat's, so NaNs are still least. See
+/// `TotalFloat::strict_cmp`.
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct StrictTotalFloat {
+    pub inner: TotalFloat,
+}
+
+impl StrictTotalFloat {
+    /// Creates a new StrictTotalFloat wrapping the given f64.
+    pub const fn new(inner: f64) -> StrictTotalFloat {
+        StrictTotalFloat { inner: TotalFloat::new(inner) }
+    }
+}
+
+// Implement Deref to allow us to use TotalFloat methods on StrictTotalFloat.
+impl Deref for StrictTotalFloat {
+    type Target = TotalFloat;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+// Custom Equality Implementation
+// Only identical bit patterns are equal, consistent with strict_cmp.
+impl Eq for StrictTotalFloat {}
+impl PartialEq for StrictTotalFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.to_bits() == other.inner.to_bits()
+    }
+}
+
+// Custom Ordering Implementation
+impl Ord for StrictTotalFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.strict_cmp(&other.inner)
+    }
+}
+impl PartialOrd for StrictTotalFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Custom Hash Implementation
+// The bits are hashed as they are, as equality is on the bits.
+impl Hash for StrictTotalFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.to_bits().hash(state)
+    }
+}
+
+// Custom Debug and Display Implementations
+impl fmt::Debug for StrictTotalFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+impl fmt::Display for StrictTotalFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+// Conversion wrappers between StrictTotalFloat, TotalFloat and f64
+impl From<f64> for StrictTotalFloat {
+    fn from(from: f64) -> StrictTotalFloat {
+        StrictTotalFloat::new(from)
+    }
+}
+impl From<TotalFloat> for StrictTotalFloat {
+    fn from(from: TotalFloat) -> StrictTotalFloat {
+        StrictTotalFloat { inner: from }
+    }
+}
+impl From<StrictTotalFloat> for TotalFloat {
+    fn from(from: StrictTotalFloat) -> TotalFloat {
+        from.inner
+    }
+}
+impl From<StrictTotalFloat> for f64 {
+    fn from(from: StrictTotalFloat) -> f64 {
+        from.inner.inner
+    }
+}
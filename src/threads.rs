@@ -0,0 +1,57 @@
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+use std::thread;
+
+use merge_halves_by;
+use merge_sort;
+use merge_sort_in_place;
+use SortConfig;
+use TotalFloat;
+
+/// Sorts a list of TotalFloat values on the given number of threads.
+///
+/// The list is split into `threads` contiguous chunks of about equal length
+/// (or fewer, if there are fewer values than threads), each of which is sorted
+/// on its own scoped thread. The sorted chunks are then merged pairwise on the
+/// calling thread. The result is exactly that of `merge_sort`, down to the
+/// order of NaNs with different payloads, and a single thread is exactly
+/// `merge_sort`.
+///
+/// # Panics
+///
+/// Panics if a thread cannot be spawned.
+pub fn merge_sort_threads(mut input: Vec<TotalFloat>,
+                          threads: NonZeroUsize)
+                          -> Vec<TotalFloat> {
+    let n = input.len();
+    let threads = threads.get();
+    if threads == 1 || n < 2 {
+        return merge_sort(input);
+    }
+    let chunk = n.div_ceil(threads);
+    thread::scope(|scope| {
+        for part in input.chunks_mut(chunk) {
+            scope.spawn(move || merge_sort_in_place(part));
+        }
+    });
+
+    // Merge neighbouring chunks in rounds, doubling their length each time,
+    // so that each value is moved about log2(threads) times.
+    let mut scratch = Vec::with_capacity(n);
+    let mut width = chunk;
+    while width < n {
+        for start in (0..n).step_by(2 * width) {
+            let end = n.min(start + 2 * width);
+            if start + width < end {
+                merge_halves_by(&mut input[start..end],
+                                width,
+                                &mut scratch,
+                                SortConfig::new().min_gallop,
+                                &mut TotalFloat::cmp,
+                                &mut ());
+            }
+        }
+        width *= 2;
+    }
+    input
+}
@@ -0,0 +1,58 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use TotalFloat;
+
+/// The `k` greatest values, in descending order.
+///
+/// This keeps a heap of at most `k` values, so takes O(k) memory and
+/// O(n log k) time for n values. As NaN is least, NaNs are only included if
+/// there are fewer than `k` other values. If there are fewer than `k` values in
+/// all, all of them are returned.
+pub fn top_k_largest<I>(iter: I, k: usize) -> Vec<TotalFloat>
+    where I: IntoIterator<Item = TotalFloat>
+{
+    if k == 0 {
+        return Vec::new();
+    }
+    // A min-heap of the greatest values seen so far, whose top is the least of
+    // them and so the first to be displaced. Its capacity is bounded by the
+    // input as well as by k, which may be far larger.
+    let iter = iter.into_iter();
+    let mut heap = BinaryHeap::with_capacity(k.min(iter.size_hint().0));
+    for x in iter {
+        if heap.len() < k {
+            heap.push(Reverse(x));
+        } else if heap.peek().is_some_and(|top| x > top.0) {
+            heap.pop();
+            heap.push(Reverse(x));
+        }
+    }
+    // Sorting ascending by Reverse gives descending values.
+    heap.into_sorted_vec().into_iter().map(|x| x.0).collect()
+}
+
+/// The `k` least values, in ascending order.
+///
+/// As with `top_k_largest` this takes O(k) memory. As NaN is least, NaNs are
+/// included before any other value.
+pub fn top_k_smallest<I>(iter: I, k: usize) -> Vec<TotalFloat>
+    where I: IntoIterator<Item = TotalFloat>
+{
+    if k == 0 {
+        return Vec::new();
+    }
+    // A max-heap of the least values seen so far.
+    let iter = iter.into_iter();
+    let mut heap = BinaryHeap::with_capacity(k.min(iter.size_hint().0));
+    for x in iter {
+        if heap.len() < k {
+            heap.push(x);
+        } else if heap.peek().is_some_and(|top| x < *top) {
+            heap.pop();
+            heap.push(x);
+        }
+    }
+    heap.into_sorted_vec()
+}
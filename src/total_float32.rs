@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::slice;
+
+use merge_sort;
+use merge_sort_in_place;
+use sort_slice_by;
+use NanLeast;
+use NanPolicy;
+use TotalFloatWith;
+
+/// An abstraction over 32 Bit IEEE Floats providing Totality in Ordering and
+/// Reflexivity in Equality.
+///
+/// This has the same semantics as TotalFloat: NaN values are treated as being
+/// strictly less than all other values, including negative infinity, and all
+/// NaN values are treated as being equal to each other.
+///
+/// It has the constants, bit conversions, reinterpreting casts, arithmetic
+/// operators and parsing of TotalFloat. It does not have the methods of
+/// TotalFloat beyond those, such as its math methods and `ieee_total_cmp`,
+/// nor comparisons against a plain f32.
+pub type TotalFloat32 = TotalFloatWith<NanLeast, f32>;
+
+impl<P: NanPolicy> TotalFloatWith<P, f32> {
+    /// Not a Number.
+    pub const NAN: Self = Self::new(f32::NAN);
+    /// Positive infinity, which is greater than all other values except NaN
+    /// under NanGreatest.
+    pub const INFINITY: Self = Self::new(f32::INFINITY);
+    /// Negative infinity, which is less than all other values except NaN
+    /// under NanLeast.
+    pub const NEG_INFINITY: Self = Self::new(f32::NEG_INFINITY);
+    /// The smallest finite value.
+    pub const MIN: Self = Self::new(f32::MIN);
+    /// The largest finite value.
+    pub const MAX: Self = Self::new(f32::MAX);
+    /// The smallest positive normal value.
+    pub const MIN_POSITIVE: Self = Self::new(f32::MIN_POSITIVE);
+    /// The difference between 1.0 and the next largest representable value.
+    pub const EPSILON: Self = Self::new(f32::EPSILON);
+    /// Positive zero.
+    pub const ZERO: Self = Self::new(0.0);
+    /// Negative zero, which is equal to positive zero.
+    pub const NEG_ZERO: Self = Self::new(-0.0);
+
+    /// Creates a TotalFloat32 from its raw IEEE 754 bit pattern, preserving
+    /// it exactly (including any NaN payload).
+    pub const fn from_bits(bits: u32) -> Self {
+        Self::new(f32::from_bits(bits))
+    }
+
+    /// The raw IEEE 754 bit pattern of the inner f32.
+    pub const fn to_bits(self) -> u32 {
+        self.inner.to_bits()
+    }
+
+    /// Normalizes the representation of this value, mapping every NaN to the
+    /// canonical quiet NaN (0x7fc00000) and -0.0 to +0.0.
+    pub fn canonicalize(self) -> Self {
+        if self.is_nan() {
+            Self::from_bits(0x7fc00000)
+        } else if self.inner == 0.0 {
+            Self::ZERO
+        } else {
+            self
+        }
+    }
+
+    /// Reinterprets a reference to an f32 as a reference to a TotalFloat32.
+    pub fn from_ref(float: &f32) -> &Self {
+        // This is sound as TotalFloatWith is repr(transparent) over f32.
+        unsafe { &*(float as *const f32 as *const Self) }
+    }
+
+    /// Reinterprets a mutable reference to an f32 as a mutable reference to a
+    /// TotalFloat32.
+    pub fn from_mut(float: &mut f32) -> &mut Self {
+        // This is sound as TotalFloatWith is repr(transparent) over f32.
+        unsafe { &mut *(float as *mut f32 as *mut Self) }
+    }
+
+    /// Reinterprets a slice of f32 as a slice of TotalFloat32, without
+    /// copying.
+    pub fn slice_from(slice: &[f32]) -> &[Self] {
+        // This is sound as TotalFloatWith is repr(transparent) over f32, so
+        // the two slices have identical layouts.
+        unsafe {
+            slice::from_raw_parts(slice.as_ptr() as *const Self, slice.len())
+        }
+    }
+
+    /// Reinterprets a mutable slice of f32 as a mutable slice of
+    /// TotalFloat32, without copying.
+    pub fn slice_from_mut(slice: &mut [f32]) -> &mut [Self] {
+        // This is sound as TotalFloatWith is repr(transparent) over f32, so
+        // the two slices have identical layouts.
+        unsafe {
+            slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut Self,
+                                      slice.len())
+        }
+    }
+}
+
+// Custom Hash Implementation
+// As for TotalFloat, the canonical bit pattern is hashed so that this is
+// consistent with the equality implementation.
+impl<P: NanPolicy> Hash for TotalFloatWith<P, f32> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonicalize().to_bits().hash(state)
+    }
+}
+
+// Default Implementation
+impl<P: NanPolicy> Default for TotalFloatWith<P, f32> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+// Conversion wrapper from TotalFloat32 to f32
+impl<P: NanPolicy> From<TotalFloatWith<P, f32>> for f32 {
+    fn from(from: TotalFloatWith<P, f32>) -> f32 {
+        from.inner
+    }
+}
+
+// Lossless conversion wrapper from TotalFloat32 to TotalFloat
+impl<P: NanPolicy> From<TotalFloatWith<P, f32>> for TotalFloatWith<P> {
+    fn from(from: TotalFloatWith<P, f32>) -> TotalFloatWith<P> {
+        TotalFloatWith::new(f64::from(from.inner))
+    }
+}
+
+/// Macro for converting f32 to TotalFloat32.
+#[macro_export]
+macro_rules! tf32 {
+    ($float:expr) => { $crate::TotalFloat32::from($float) }
+}
+
+/// Macro for creating lists of TotalFloat32s.
+#[macro_export]
+macro_rules! tf32vec {
+    [$($float:expr),*] => {{
+        // The annotation keeps the type of an empty list unambiguous.
+        let list: $crate::__Vec<$crate::TotalFloat32> = $crate::__Vec::from([
+            $(
+                tf32!($float)
+            ),*
+        ]);
+        list
+    }}
+}
+
+/// Sorts a list of TotalFloat32 values.
+///
+/// This is the same as `merge_sort`, fixed to TotalFloat32.
+pub fn merge_sort32(input: Vec<TotalFloat32>) -> Vec<TotalFloat32> {
+    merge_sort(input)
+}
+
+/// Sorts a slice of f32 in place, under the total order of TotalFloat32.
+///
+/// See `sort_f64`.
+pub fn sort_f32(input: &mut [f32]) {
+    merge_sort_in_place(TotalFloat32::slice_from_mut(input));
+}
+
+/// Sorts a slice of f32 in place in descending order, under the total order
+/// of TotalFloat32.
+///
+/// See `sort_f64_desc`.
+pub fn sort_f32_desc(input: &mut [f32]) {
+    let input = TotalFloat32::slice_from_mut(input);
+    sort_slice_by(input, &mut |a: &TotalFloat32, b: &TotalFloat32| b.cmp(a));
+}
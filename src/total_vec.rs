@@ -0,0 +1,116 @@
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::mem;
+use core::ops::Deref;
+
+use binary_search;
+use is_sorted;
+use merge_sort;
+use wrap_vec;
+use TotalFloat;
+
+/// An owned list of TotalFloat values, with sorting and query methods.
+///
+/// This wraps a `Vec<TotalFloat>`, so that the list can be sorted in place
+/// rather than passed through `merge_sort` by value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TotalVec(Vec<TotalFloat>);
+
+impl TotalVec {
+    /// Creates a new, empty TotalVec.
+    pub fn new() -> TotalVec {
+        TotalVec(Vec::new())
+    }
+
+    /// Appends a value to the end of the list.
+    pub fn push<T: Into<TotalFloat>>(&mut self, value: T) {
+        self.0.push(value.into());
+    }
+
+    /// Sorts the list in place, using merge sort.
+    pub fn sort(&mut self) {
+        self.0 = merge_sort(mem::take(&mut self.0));
+    }
+
+    /// Checks whether the list is in ascending order.
+    pub fn is_sorted(&self) -> bool {
+        is_sorted(&self.0)
+    }
+
+    /// The least value in the list, or None if it is empty.
+    pub fn min(&self) -> Option<TotalFloat> {
+        self.0.iter().min().cloned()
+    }
+
+    /// The greatest value in the list, or None if it is empty.
+    pub fn max(&self) -> Option<TotalFloat> {
+        self.0.iter().max().cloned()
+    }
+
+    /// Binary searches the sorted list for the given value.
+    ///
+    /// See `fc_sort::binary_search`. The result is unspecified if the list is
+    /// not sorted.
+    pub fn binary_search(&self, value: f64) -> Result<usize, usize> {
+        binary_search(&self.0, value)
+    }
+
+    /// Unwraps the underlying Vec of TotalFloat values.
+    pub fn into_inner(self) -> Vec<TotalFloat> {
+        self.0
+    }
+}
+
+// Implement Deref to allow us to use slice methods on TotalVec.
+impl Deref for TotalVec {
+    type Target = [TotalFloat];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<f64>> for TotalVec {
+    fn from(input: Vec<f64>) -> TotalVec {
+        TotalVec(wrap_vec(input))
+    }
+}
+
+impl From<Vec<TotalFloat>> for TotalVec {
+    fn from(input: Vec<TotalFloat>) -> TotalVec {
+        TotalVec(input)
+    }
+}
+
+impl From<TotalVec> for Vec<TotalFloat> {
+    fn from(input: TotalVec) -> Vec<TotalFloat> {
+        input.0
+    }
+}
+
+impl FromIterator<f64> for TotalVec {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> TotalVec {
+        TotalVec(iter.into_iter().map(TotalFloat::new).collect())
+    }
+}
+
+impl FromIterator<TotalFloat> for TotalVec {
+    fn from_iter<I: IntoIterator<Item = TotalFloat>>(iter: I) -> TotalVec {
+        TotalVec(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for TotalVec {
+    type Item = TotalFloat;
+    type IntoIter = ::alloc::vec::IntoIter<TotalFloat>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a TotalVec {
+    type Item = &'a TotalFloat;
+    type IntoIter = ::core::slice::Iter<'a, TotalFloat>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
@@ -0,0 +1,115 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_by_cached_key;
+use fc_sort::merge_sort_canonical;
+use fc_sort::merge_sort_in_place;
+use fc_sort::merge_sort_with_buffer;
+use fc_sort::sort_array;
+use fc_sort::TotalFloat;
+use rand::random;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+// An allocator counting the allocations made on each thread, so that tests
+// running in parallel do not count each other's.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+    unsafe fn realloc(&self,
+                      ptr: *mut u8,
+                      layout: Layout,
+                      new_size: usize)
+                      -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+// The number of allocations made by `f` on this thread.
+fn allocations<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn random_list(n: usize) -> Vec<TotalFloat> {
+    (0..n).map(|_| TotalFloat::new(random())).collect()
+}
+
+#[test]
+fn sort_allocates_once() {
+    // Only the scratch buffer, whatever the length
+    for &n in &[2, 100, 10_000, 1_000_000] {
+        let list = random_list(n);
+        assert!(allocations(|| drop(merge_sort(list))) <= 2);
+        let mut list = random_list(n);
+        assert!(allocations(|| merge_sort_in_place(&mut list)) <= 2);
+        let list = random_list(n);
+        assert!(allocations(|| drop(merge_sort_canonical(list))) <= 2);
+    }
+    // Shuffled input needs the scratch buffer, so is counted
+    let list = random_list(1000);
+    assert_eq!(allocations(|| drop(merge_sort(list))), 1);
+}
+
+#[test]
+fn presorted_does_not_allocate() {
+    // The presorted scan needs no scratch buffer
+    let mut list = merge_sort(random_list(10_000));
+    assert_eq!(allocations(|| merge_sort_in_place(&mut list)), 0);
+    list.reverse();
+    assert_eq!(allocations(|| merge_sort_in_place(&mut list)), 0);
+}
+
+#[test]
+fn reused_buffer() {
+    // Once the scratch buffer is large enough, sorts make no allocations
+    let mut scratch = Vec::new();
+    let mut list = random_list(1000);
+    assert!(allocations(|| merge_sort_with_buffer(&mut list, &mut scratch)) <=
+            1);
+    for &n in &[1000, 10, 500, 1000] {
+        let mut list = random_list(n);
+        let expected = merge_sort(list.clone());
+        assert_eq!(allocations(|| {
+                       merge_sort_with_buffer(&mut list, &mut scratch)
+                   }),
+                   0);
+        assert_eq!(list, expected);
+    }
+}
+
+#[test]
+fn cached_key() {
+    // The keys and the scratch buffer for sorting them
+    let list = random_list(10_000);
+    assert!(allocations(|| drop(merge_sort_by_cached_key(list, |x| *x))) <=
+            2);
+}
+
+#[test]
+fn short_array_does_not_allocate() {
+    // Insertion sort on the stack, even for unsorted input
+    let mut array = [TotalFloat::new(0.0); 32];
+    for x in array.iter_mut() {
+        *x = TotalFloat::new(random());
+    }
+    assert_eq!(allocations(|| array = sort_array(array)), 0);
+    assert!(array.windows(2).all(|w| w[0] <= w[1]));
+}
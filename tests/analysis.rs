@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::analyze;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn runs() {
+    // A three run sawtooth, sorted input, and reversed input
+    let sawtooth: Vec<_> = (0..30).map(|x| tf!((x % 10) as f64)).collect();
+    let report = analyze(&sawtooth);
+    assert_eq!((report.runs, report.longest_run), (3, 10));
+
+    let sorted = tfvec![NAN, -INF, 1.0, 1.0, 2.0];
+    assert_eq!((analyze(&sorted).runs, analyze(&sorted).longest_run), (1, 5));
+    assert_eq!(analyze(&sorted).estimated_inversions, 0);
+
+    let reversed = tfvec![3.0, 2.0, 1.0, NAN];
+    let report = analyze(&reversed);
+    assert_eq!((report.runs, report.longest_run), (4, 1));
+    assert_eq!(report.estimated_inversions, 6);
+
+    let empty = analyze(&tfvec![]);
+    assert_eq!((empty.runs, empty.longest_run, empty.len), (0, 0, 0));
+    assert_eq!((empty.min, empty.max), (None, None));
+}
+
+#[test]
+fn small_input_is_exact() {
+    // Up to 256 values, everything is counted rather than sampled
+    let list = tfvec![2.0, NAN, 2.0, -1.0, NAN, 5.0, -0.0, 0.0];
+    let report = analyze(&list);
+    assert_eq!(report.len, 8);
+    assert_eq!(report.nans, 2);
+    assert_eq!(report.sample_len, 8);
+    assert_eq!(report.sample_duplicates, 3);
+    assert_eq!(report.estimated_inversions, 12);
+    assert_eq!((report.min, report.max), (Some(tf!(-1.0)), Some(tf!(5.0))));
+}
+
+#[test]
+fn large_input_is_sampled() {
+    // Reversed input estimates every pair as an inversion
+    let reversed: Vec<TotalFloat> = (0..10_000).rev()
+                                               .map(|x| tf!(x as f64))
+                                               .collect();
+    let report = analyze(&reversed);
+    assert_eq!(report.sample_len, 256);
+    assert_eq!(report.runs, 10_000);
+    assert_eq!(report.sample_duplicates, 0);
+    let exact = 10_000.0 * 9_999.0 / 2.0;
+    assert!((report.estimated_inversions as f64 - exact).abs() < exact * 1e-9);
+}
+
+#[test]
+fn display() {
+    // The report prints one statistic a line
+    let report = analyze(&tfvec![3.0, NAN, 1.0, 2.0]);
+    assert_eq!(report.to_string(),
+               "values:      4\n\
+                runs:        2 (longest 3)\n\
+                inversions:  ~3\n\
+                NaNs:        1\n\
+                duplicates:  0 of 4 sampled\n\
+                range:       1 to 3");
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "approx")]
+
+#[macro_use]
+extern crate fc_sort;
+#[macro_use]
+extern crate approx;
+
+use approx::AbsDiffEq;
+use approx::RelativeEq;
+use approx::UlpsEq;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn computed_values() {
+    // Values which differ only by rounding compare approximately equal
+    let sum = tf!(0.1) + tf!(0.2);
+    assert_ne!(sum, tf!(0.3));
+    assert_abs_diff_eq!(sum, tf!(0.3));
+    assert_relative_eq!(sum, tf!(0.3));
+    assert_ulps_eq!(sum, tf!(0.3));
+    assert_relative_eq!(tf!(1.0), tf!(1.001), epsilon = 0.01);
+    assert_relative_ne!(tf!(1.0), tf!(1.1));
+}
+
+#[test]
+fn nan_vs_nan() {
+    // Any two NaNs are approximately equal, whatever their payloads
+    let other = fc_sort::TotalFloat::from_bits(0xfff0_0000_0000_0001);
+    assert!(tf!(NAN).abs_diff_eq(&other, 0.0));
+    assert!(tf!(NAN).relative_eq(&other, 0.0, 0.0));
+    assert!(tf!(NAN).ulps_eq(&other, 0.0, 0));
+    assert_relative_eq!(tf!(NAN), tf!(NAN));
+}
+
+#[test]
+fn nan_vs_other() {
+    // A NaN is not approximately equal to any other value
+    for &x in &[0.0, 1.0, INF, -INF] {
+        assert!(!tf!(NAN).abs_diff_eq(&tf!(x), INF));
+        assert!(!tf!(x).relative_eq(&tf!(NAN), INF, INF));
+        assert!(!tf!(NAN).ulps_eq(&tf!(x), INF, u32::MAX));
+    }
+    assert_relative_ne!(tf!(NAN), tf!(1.0));
+}
@@ -0,0 +1,105 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::choose_algorithm;
+use fc_sort::merge_sort;
+use fc_sort::sort_auto;
+use fc_sort::sort_auto_with;
+use fc_sort::Algorithm;
+use fc_sort::SortConfig;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const NAN: f64 = f64::NAN;
+
+fn bits(list: &[TotalFloat]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+// Random values with NaNs of differing payloads and zeros of both signs, so
+// that any instability shows up in the bits.
+fn random_list(n: usize) -> Vec<TotalFloat> {
+    (0..n)
+        .map(|_| match random::<u32>() % 5 {
+            0 => TotalFloat::from_bits(NAN.to_bits() | (random::<u64>() % 8)),
+            1 => TotalFloat::new(if random() { 0.0 } else { -0.0 }),
+            _ => TotalFloat::new(random()),
+        })
+        .collect()
+}
+
+// Checks the algorithm chosen for the list, and that the result is that of
+// merge_sort bit for bit.
+fn check(list: Vec<TotalFloat>, config: &SortConfig, algorithm: Algorithm) {
+    assert_eq!(choose_algorithm(&list, config), algorithm);
+    let expected = bits(&merge_sort(list.clone()));
+    assert_eq!(bits(&sort_auto_with(list, config)), expected);
+}
+
+#[test]
+fn heuristics() {
+    let config = SortConfig::new();
+    // Tiny input is insertion sorted
+    for n in 0..33 {
+        check(random_list(n), &config, Algorithm::Insertion);
+    }
+    // Short unsorted input, and mostly sorted input of any length, is merged
+    check(random_list(1000), &config, Algorithm::Merge);
+    let mut sorted = merge_sort(random_list(100_000));
+    check(sorted.clone(), &config, Algorithm::Merge);
+    sorted.reverse();
+    check(sorted.clone(), &config, Algorithm::Merge);
+    sorted.reverse();
+    sorted.swap(10, 90_000);
+    check(sorted, &config, Algorithm::Merge);
+    // Long unsorted input is radix sorted
+    check(random_list(10_000), &config, Algorithm::Radix);
+    assert_eq!(bits(&sort_auto(random_list(0))), bits(&[]));
+}
+
+#[test]
+fn thresholds() {
+    // Moving the thresholds moves the choices
+    let list = random_list(2000);
+    let config = SortConfig::new().radix_threshold(1000);
+    check(list.clone(), &config, Algorithm::Radix);
+    let config = SortConfig::new().radix_threshold(usize::MAX);
+    check(list.clone(), &config, Algorithm::Merge);
+    let config = SortConfig::new().insertion_cutoff(5000);
+    check(list.clone(), &config, Algorithm::Insertion);
+    let config = SortConfig::new().insertion_cutoff(0);
+    check(random_list(1), &config, Algorithm::Merge);
+}
+
+#[test]
+fn forced() {
+    // Every algorithm, whatever the input
+    let algorithms = [Algorithm::Insertion,
+                      Algorithm::Merge,
+                      Algorithm::Radix,
+                      Algorithm::Parallel];
+    for &algorithm in algorithms.iter() {
+        let config = SortConfig::new().force(algorithm);
+        for &n in &[0, 1, 10, 1000, 50_000] {
+            check(random_list(n), &config, algorithm);
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_threshold() {
+    // The longest unsorted input is sorted in parallel
+    let config = SortConfig::new().parallel_threshold(20_000);
+    check(random_list(30_000), &config, Algorithm::Parallel);
+    check(random_list(10_000), &config, Algorithm::Radix);
+    check(merge_sort(random_list(30_000)), &config, Algorithm::Merge);
+}
+
+#[cfg(not(feature = "rayon"))]
+#[test]
+fn parallel_needs_rayon() {
+    // Without rayon the parallel threshold is ignored
+    let config = SortConfig::new().parallel_threshold(20_000);
+    check(random_list(30_000), &config, Algorithm::Radix);
+}
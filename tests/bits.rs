@@ -0,0 +1,143 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::TotalFloat;
+use rand::random;
+
+// Bit patterns for values that random u64s are unlikely to produce
+const SPECIAL_BITS: [u64; 10] = [0x0000000000000000, // 0.0
+                                 0x8000000000000000, // -0.0
+                                 0x7ff0000000000000, // inf
+                                 0xfff0000000000000, // -inf
+                                 0x7ff8000000000000, // NaN
+                                 0xfff8000000000000, // -NaN
+                                 0x7ff0000000000001, // signalling NaN
+                                 0xfff80090e200a000, // -NaN with payload
+                                 0x0000000000000001, // smallest subnormal
+                                 0x800fffffffffffff]; // largest -subnormal
+
+// Constructed at compile time, which requires from_bits to be a const fn
+const ONE: TotalFloat = TotalFloat::from_bits(0x3ff0000000000000);
+
+#[test]
+fn bits_round_trip() {
+    // from_bits and to_bits should preserve the bit pattern exactly
+    for &bits in SPECIAL_BITS.iter() {
+        assert_eq!(TotalFloat::from_bits(bits).to_bits(), bits);
+    }
+    for _ in 0..1000 {
+        let bits = random::<u64>();
+        assert_eq!(TotalFloat::from_bits(bits).to_bits(), bits);
+    }
+    assert_eq!(ONE.inner, 1.0);
+}
+
+#[test]
+fn total_bits_key_specials() {
+    // All NaNs share the least key, and the zeros share a key
+    assert_eq!(TotalFloat::NAN.total_bits_key(), 0);
+    assert_eq!(TotalFloat::from_bits(0xfff8000000000000).total_bits_key(), 0);
+    assert_eq!(TotalFloat::ZERO.total_bits_key(),
+               TotalFloat::NEG_ZERO.total_bits_key());
+    assert!(TotalFloat::NEG_INFINITY.total_bits_key() > 0);
+    assert!(TotalFloat::INFINITY.total_bits_key() >
+            TotalFloat::MAX.total_bits_key());
+}
+
+#[test]
+fn total_bits_key_agrees_with_ord() {
+    // Mix random bit patterns with the special ones
+    let values: Vec<_> = (0..500)
+        .map(|_| random::<u64>())
+        .chain(SPECIAL_BITS.iter().cloned())
+        .map(TotalFloat::from_bits)
+        .collect();
+
+    // Compare every pair of values both ways
+    for a in &values {
+        for b in &values {
+            assert_eq!(a.total_bits_key().cmp(&b.total_bits_key()),
+                       a.cmp(b),
+                       "{:#x} vs {:#x}",
+                       a.to_bits(),
+                       b.to_bits());
+        }
+    }
+}
+
+#[test]
+fn canonicalize_specials() {
+    // Every NaN becomes the canonical NaN, and -0.0 becomes +0.0
+    for &bits in &[0x7ff8000000000000u64, 0xfff8000000000000,
+                   0x7ff0000000000001, 0xfff80090e200a000] {
+        let nan = TotalFloat::from_bits(bits).canonicalize();
+        assert_eq!(nan.to_bits(), 0x7ff8000000000000);
+    }
+    assert_eq!(TotalFloat::NEG_ZERO.canonicalize().to_bits(), 0);
+
+    // Everything else is left untouched
+    for &bits in &[0x0000000000000001u64, 0xfff0000000000000,
+                   0x3ff0000000000000] {
+        assert_eq!(TotalFloat::from_bits(bits).canonicalize().to_bits(), bits);
+    }
+}
+
+#[test]
+fn canonicalized_equal_vectors_are_bit_identical() {
+    // Two vectors that compare equal element-wise, but differ in bits
+    let mut a: Vec<_> = [0x7ff800a004001000u64, 0x8000000000000000,
+                         0x3ff0000000000000, 0xfff8000000000000]
+        .iter()
+        .map(|&b| TotalFloat::from_bits(b))
+        .collect();
+    let mut b: Vec<_> = [0x7ff8000000000000u64, 0x0000000000000000,
+                         0x3ff0000000000000, 0x7ff0000000000001]
+        .iter()
+        .map(|&b| TotalFloat::from_bits(b))
+        .collect();
+    let bits = |v: &[TotalFloat]| {
+        v.iter().map(|x| x.to_bits()).collect::<Vec<_>>()
+    };
+    assert_eq!(a, b);
+    assert!(bits(&a) != bits(&b));
+
+    // After canonicalization they are bit-identical
+    fc_sort::canonicalize_slice(&mut a);
+    fc_sort::canonicalize_slice(&mut b);
+    assert_eq!(bits(&a), bits(&b));
+}
+
+#[test]
+fn merge_sort_canonical_matches_canonicalize_then_sort() {
+    // Random bit patterns, with the specials mixed in
+    let values: Vec<_> = (0..500)
+        .map(|_| random::<u64>())
+        .chain(SPECIAL_BITS.iter().cloned())
+        .map(TotalFloat::from_bits)
+        .collect();
+
+    let mut expected = values.clone();
+    fc_sort::canonicalize_slice(&mut expected);
+    let expected = fc_sort::merge_sort(expected);
+    let actual = fc_sort::merge_sort_canonical(values);
+
+    assert_eq!(actual.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+               expected.iter().map(|x| x.to_bits()).collect::<Vec<_>>());
+}
+
+#[test]
+fn merge_sort_canonical_presorted() {
+    // Presorted input is canonicalized as it is scanned, whichever way it runs
+    let ascending: Vec<_> = [0xfff0000000000001, 0x8000000000000000, 1 << 52]
+        .iter()
+        .map(|&bits| TotalFloat::from_bits(bits))
+        .collect();
+    let mut descending = ascending.clone();
+    descending.reverse();
+
+    for values in [ascending, descending] {
+        let sorted = fc_sort::merge_sort_canonical(values);
+        assert_eq!(sorted.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+                   vec![0x7ff8000000000000, 0, 1 << 52]);
+    }
+}
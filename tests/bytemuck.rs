@@ -0,0 +1,49 @@
+#![cfg(feature = "bytemuck")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate bytemuck;
+
+use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
+use fc_sort::TotalFloat32;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn cast_slice_round_trip() {
+    // Casting to TotalFloat and back gives the same bytes
+    let data = [3.0, NAN, -1.0, INF];
+    let view: &[TotalFloat] = bytemuck::cast_slice(&data);
+    assert_eq!(view, &tfvec![3.0, NAN, -1.0, INF][..]);
+    let back: &[f64] = bytemuck::cast_slice(view);
+    assert_eq!(bytemuck::cast_slice::<f64, u8>(back),
+               bytemuck::cast_slice::<f64, u8>(&data));
+}
+
+#[test]
+fn sort_through_cast() {
+    // merge_sort the values seen through a mutable cast, and write them back
+    let mut data = [3.0, NAN, -1.0, N_INF, 0.5];
+    {
+        let view: &mut [TotalFloat] = bytemuck::cast_slice_mut(&mut data);
+        let sorted = merge_sort(view.to_vec());
+        view.copy_from_slice(&sorted);
+    }
+
+    // The bytes are those of the sorted f64s
+    let expected = [NAN, N_INF, -1.0, 0.5, 3.0];
+    assert_eq!(bytemuck::cast_slice::<f64, u8>(&data),
+               bytemuck::cast_slice::<f64, u8>(&expected));
+}
+
+#[test]
+fn zeroed() {
+    // The zeroed value is positive zero, for both widths
+    let zero: TotalFloat = bytemuck::Zeroable::zeroed();
+    assert_eq!(zero.to_bits(), 0);
+    let zero32: TotalFloat32 = bytemuck::Zeroable::zeroed();
+    assert_eq!(zero32.to_bits(), 0);
+}
@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::checked_merge;
+use fc_sort::merge;
+use fc_sort::UnsortedInput;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn sorted_inputs() {
+    // Sorted inputs are merged as with merge
+    let a = tfvec![NAN, -0.0, 1.0, 1.0];
+    let b = tfvec![NAN, 0.0, INF];
+    let merged = checked_merge(a.clone(), b.clone()).unwrap();
+    assert_eq!(merged, merge(a, b));
+    assert!(merged[2].is_sign_negative());
+    assert_eq!(checked_merge(tfvec![], tfvec![1.0]), Ok(tfvec![1.0]));
+}
+
+#[test]
+fn unsorted_inputs() {
+    // The first value out of order is reported, checking a first
+    let sorted = tfvec![1.0, 2.0, 3.0];
+    assert_eq!(checked_merge(sorted.clone(), tfvec![1.0, 2.0, 0.5, 3.0]),
+               Err(UnsortedInput::B { index: 2 }));
+    assert_eq!(checked_merge(tfvec![1.0, NAN], tfvec![0.0, NAN]),
+               Err(UnsortedInput::A { index: 1 }));
+    assert_eq!(checked_merge(sorted, tfvec![INF, -INF]).unwrap_err()
+                   .to_string(),
+               "merge input b is not sorted at index 1");
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "merge input b is not sorted")]
+fn debug_merge_checks() {
+    // Debug builds catch unsorted input to merge
+    merge(tfvec![1.0, 2.0], tfvec![2.0, 1.0]);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn release_merge_unchecked() {
+    // Release builds merge unsorted input as they always have
+    assert_eq!(merge(tfvec![1.0, 2.0], tfvec![3.0, 0.0]),
+               tfvec![1.0, 2.0, 3.0, 0.0]);
+    assert_eq!(merge(tfvec![3.0, 1.0], tfvec![2.0]), tfvec![2.0, 3.0, 1.0]);
+}
@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::is_sorted;
+use fc_sort::kmerge;
+use fc_sort::merge_sort;
+use fc_sort::sorted_chunks;
+use fc_sort::wrap_vec;
+use rand::random;
+use std::num::NonZeroUsize;
+
+const NAN: f64 = f64::NAN;
+
+fn size(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).unwrap()
+}
+
+#[test]
+fn chunks_are_sorted() {
+    // Every chunk is full and sorted, but the last, which is partial
+    let input: Vec<f64> = (0..1003).map(|_| random::<f64>() - 0.5).collect();
+    let chunks: Vec<_> = sorted_chunks(input.iter().cloned(), size(100))
+        .collect();
+    assert_eq!(chunks.len(), 11);
+    assert!(chunks[..10].iter().all(|x| x.len() == 100));
+    assert_eq!(chunks[10].len(), 3);
+    assert!(chunks.iter().all(|x| is_sorted(x)));
+
+    // Their concatenation is a permutation of the input, and they merge to
+    // its sort
+    let all = merge_sort(chunks.concat());
+    assert_eq!(all, merge_sort(wrap_vec(input)));
+    assert_eq!(kmerge(chunks).collect::<Vec<_>>(), all);
+}
+
+#[test]
+fn chunk_boundaries() {
+    // NaNs lead each chunk they are in, and exact multiples leave no partial
+    // chunk
+    let input = vec![2.0, NAN, 1.0, 4.0, 3.0, NAN];
+    let chunks: Vec<_> = sorted_chunks(input.into_iter(), size(3)).collect();
+    assert_eq!(chunks, [tfvec![NAN, 1.0, 2.0], tfvec![NAN, 3.0, 4.0]]);
+
+    let mut chunks = sorted_chunks(vec![1.0, 0.0].into_iter(), size(5));
+    assert_eq!(chunks.size_hint(), (1, Some(1)));
+    assert_eq!(chunks.next(), Some(tfvec![0.0, 1.0]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(sorted_chunks(Vec::new().into_iter(), size(1)).next(), None);
+}
@@ -0,0 +1,118 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::{NanGreatest, NanLeast, NanPolicy, RawFloat, TotalFloatWith};
+use rand::random;
+use std::cmp::Ordering;
+
+// Bit patterns for values that random bits are unlikely to produce
+const SPECIAL_BITS: [u64; 14] = [0x0000000000000000, // 0.0
+                                 0x8000000000000000, // -0.0
+                                 0x7ff0000000000000, // inf
+                                 0xfff0000000000000, // -inf
+                                 0x7ff8000000000000, // NaN
+                                 0xfff8000000000000, // -NaN
+                                 0x7ff0000000000001, // signalling NaN
+                                 0xffffffffffffffff, // -NaN, all bits set
+                                 0x7fffffffffffffff, // NaN, all bits set
+                                 0x0000000000000001, // smallest subnormal
+                                 0x8000000000000001, // -smallest subnormal
+                                 0x800fffffffffffff, // largest -subnormal
+                                 0x7fefffffffffffff, // MAX
+                                 0xffefffffffffffff]; // MIN
+
+// The comparison by NaN checks and partial_cmp, which the bitwise comparison
+// replaced.
+fn reference_cmp<F: RawFloat>(a: F, b: F, nan_ordering: Ordering) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => nan_ordering,
+        (false, true) => nan_ordering.reverse(),
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+// Random bits, a special pattern, or a neighbour of a special pattern.
+fn random_bits() -> u64 {
+    let special = SPECIAL_BITS[random::<u32>() as usize % SPECIAL_BITS.len()];
+    match random::<u32>() % 4 {
+        0 => special,
+        1 => special.wrapping_add(1),
+        2 => special.wrapping_sub(1),
+        _ => random(),
+    }
+}
+
+fn check<P: NanPolicy, F: RawFloat>(a: F, b: F) {
+    let expected = reference_cmp(a, b, P::NAN_ORDERING);
+    let x = TotalFloatWith::<P, F>::new(a);
+    let y = TotalFloatWith::<P, F>::new(b);
+    assert_eq!(x.cmp(&y), expected);
+    assert_eq!(a.cmp_with_nan(b, P::NAN_ORDERING), expected);
+}
+
+#[test]
+fn agrees_on_special_values() {
+    // Every pair of special patterns, and their neighbours
+    for &a in &SPECIAL_BITS {
+        for &b in &SPECIAL_BITS {
+            for &(a, b) in &[(a, b), (a.wrapping_add(1), b), (a, b ^ 1)] {
+                let (a, b) = (f64::from_bits(a), f64::from_bits(b));
+                check::<NanLeast, f64>(a, b);
+                check::<NanGreatest, f64>(a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn agrees_on_random_f64() {
+    // A million random pairs under each policy
+    for _ in 0..1_000_000 {
+        let a = f64::from_bits(random_bits());
+        let b = f64::from_bits(random_bits());
+        check::<NanLeast, f64>(a, b);
+        check::<NanGreatest, f64>(a, b);
+    }
+}
+
+#[test]
+fn agrees_on_random_f32() {
+    // Converted specials keep their class, and random f32 bits fill the rest
+    for _ in 0..1_000_000 {
+        let a = f64::from_bits(random_bits()) as f32;
+        let b = if random() {
+            f32::from_bits(random())
+        } else {
+            f64::from_bits(random_bits()) as f32
+        };
+        check::<NanLeast, f32>(a, b);
+        check::<NanGreatest, f32>(a, b);
+        check::<NanLeast, f32>(b, a);
+    }
+}
+
+#[test]
+fn default_agrees() {
+    // The default comparison, used by other float types, agrees too
+    #[derive(Copy, Clone, PartialEq, PartialOrd)]
+    struct Wrapped(f64);
+    impl RawFloat for Wrapped {
+        type Bits = u64;
+        fn is_nan(self) -> bool {
+            self.0.is_nan()
+        }
+        fn to_bits(self) -> u64 {
+            self.0.to_bits()
+        }
+        fn from_bits(bits: u64) -> Wrapped {
+            Wrapped(f64::from_bits(bits))
+        }
+    }
+    for _ in 0..100_000 {
+        let a = f64::from_bits(random_bits());
+        let b = f64::from_bits(random_bits());
+        assert_eq!(Wrapped(a).cmp_with_nan(Wrapped(b), Ordering::Less),
+                   a.cmp_with_nan(b, Ordering::Less));
+    }
+}
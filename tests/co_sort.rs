@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::co_sort;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn strings(list: &[&str]) -> Vec<String> {
+    list.iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn values_follow_keys() {
+    // Each value ends up beside its key
+    let mut keys = tfvec![3.0, -1.0, INF, 0.5, 2.0];
+    let mut values = strings(&["three", "minus one", "inf", "half", "two"]);
+    co_sort(&mut keys, &mut values);
+    assert_eq!(keys, tfvec![-1.0, 0.5, 2.0, 3.0, INF]);
+    assert_eq!(values, strings(&["minus one", "half", "two", "three", "inf"]));
+}
+
+#[test]
+fn stable() {
+    // Values of equal keys, NaNs and zeros included, keep their order
+    let mut keys = tfvec![1.0, NAN, 0.0, 1.0, -NAN, -0.0, 1.0, NAN];
+    let mut values = strings(&["a", "b", "c", "d", "e", "f", "g", "h"]);
+    co_sort(&mut keys, &mut values);
+    assert_eq!(keys, tfvec![NAN, NAN, NAN, 0.0, 0.0, 1.0, 1.0, 1.0]);
+    assert_eq!(values, strings(&["b", "e", "h", "c", "f", "a", "d", "g"]));
+    assert!(keys[1].is_sign_negative() && keys[4].is_sign_negative());
+}
+
+#[test]
+fn long_cycles() {
+    // A reversal and a rotation, permutations made of long cycles
+    let mut keys: Vec<_> = (0..100).rev().map(|x| tf!(x as f64)).collect();
+    let mut values: Vec<usize> = (0..100).rev().collect();
+    co_sort(&mut keys, &mut values);
+    assert!(values.iter().zip(0..).all(|(x, i)| *x == i));
+
+    let mut keys: Vec<_> = (0..100).map(|x| tf!(((x + 37) % 100) as f64))
+                                   .collect();
+    let mut values: Vec<String> = keys.iter().map(|x| x.to_string()).collect();
+    co_sort(&mut keys, &mut values);
+    assert!(keys.iter().zip(&values).all(|(k, v)| k.to_string() == *v));
+    assert_eq!(keys[0], tf!(0.0));
+
+    co_sort(&mut tfvec![], &mut Vec::<String>::new());
+}
+
+#[test]
+#[should_panic(expected = "co_sort requires as many values as keys")]
+fn length_mismatch() {
+    // Mismatched lengths are rejected before anything is moved
+    co_sort(&mut tfvec![1.0, 2.0], &mut [1]);
+}
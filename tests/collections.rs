@@ -0,0 +1,82 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use std::collections::VecDeque;
+
+use fc_sort::merge_sort;
+use fc_sort::sort_array;
+use fc_sort::sort_deque;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn random_array<const N: usize>() -> [TotalFloat; N] {
+    let mut array = [TotalFloat::new(0.0); N];
+    for x in array.iter_mut() {
+        *x = TotalFloat::new(random::<u32>() as f64 - 2147483648.0);
+    }
+    array
+}
+
+#[test]
+fn wrapped_deque() {
+    // A deque whose values wrap around the end of its buffer
+    let mut deque = VecDeque::with_capacity(8);
+    for x in tfvec![3.0, NAN, -1.0] {
+        deque.push_back(x);
+    }
+    for x in tfvec![INF, 0.0, -INF, 2.0] {
+        deque.push_front(x);
+    }
+    assert!(!deque.as_slices().1.is_empty());
+    sort_deque(&mut deque);
+    assert_eq!(Vec::from(deque),
+               tfvec![NAN, -INF, -1.0, 0.0, 2.0, 3.0, INF]);
+}
+
+#[test]
+fn empty_deque() {
+    // Nothing to sort
+    let mut deque = VecDeque::new();
+    sort_deque(&mut deque);
+    assert!(deque.is_empty());
+}
+
+#[test]
+fn short_arrays() {
+    // Arrays of no values, one value, and two values
+    let empty: [TotalFloat; 0] = [];
+    assert_eq!(sort_array(empty), empty);
+    assert_eq!(sort_array([TotalFloat::new(1.0)]), [TotalFloat::new(1.0)]);
+    assert_eq!(sort_array([TotalFloat::new(1.0), TotalFloat::NAN]),
+               [TotalFloat::NAN, TotalFloat::new(1.0)]);
+}
+
+#[test]
+fn array_of_seven() {
+    // NaNs first, and -0.0 equal to 0.0 in place
+    let array = [TotalFloat::new(2.0), TotalFloat::new(0.0),
+                 TotalFloat::new(-INF), TotalFloat::NAN,
+                 TotalFloat::new(-0.0), TotalFloat::new(INF),
+                 TotalFloat::new(-1.0)];
+    let sorted = sort_array(array);
+    assert_eq!(sorted.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+               tfvec![NAN, -INF, -1.0, 0.0, -0.0, 2.0, INF]
+                   .iter()
+                   .map(|x| x.to_bits())
+                   .collect::<Vec<_>>());
+}
+
+#[test]
+fn random_arrays() {
+    // Arrays either side of the insertion sort threshold match merge_sort
+    for _ in 0..20 {
+        let array = random_array::<7>();
+        assert_eq!(sort_array(array).to_vec(), merge_sort(array.to_vec()));
+        let array = random_array::<64>();
+        assert_eq!(sort_array(array).to_vec(), merge_sort(array.to_vec()));
+    }
+}
@@ -0,0 +1,96 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use std::cmp::Ordering;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn compare_with_literals() {
+    // TotalFloat on the left
+    assert!(tf!(3.5) > 3.0);
+    assert!(tf!(3.5) <= 3.5);
+    assert!(tf!(3.5) == 3.5);
+    assert!(tf!(3.5) != 4.0);
+
+    // TotalFloat on the right
+    assert!(3.0 < tf!(3.5));
+    assert!(3.5 >= tf!(3.5));
+    assert!(3.5 == tf!(3.5));
+    assert!(4.0 != tf!(3.5));
+}
+
+#[test]
+fn zeros_compare_equal() {
+    // -0.0 and 0.0 are equal from either side
+    assert!(tf!(-0.0) == 0.0);
+    assert!(0.0 == tf!(-0.0));
+    assert_eq!(tf!(-0.0).partial_cmp(&0.0), Some(Ordering::Equal));
+}
+
+#[test]
+fn nan_on_the_left() {
+    // A NaN TotalFloat is less than every non-NaN f64, and equal to f64 NaN
+    assert!(tf!(NAN) < 0.0);
+    assert!(tf!(NAN) < N_INF);
+    assert!(tf!(NAN) == NAN);
+    assert_eq!(tf!(NAN).partial_cmp(&0.0), Some(Ordering::Less));
+    assert_eq!(tf!(NAN).partial_cmp(&NAN), Some(Ordering::Equal));
+}
+
+#[test]
+fn nan_on_the_right() {
+    // A NaN f64 compared against a TotalFloat is also least
+    assert!(tf!(0.0) > NAN);
+    assert!(tf!(N_INF) > NAN);
+    assert!(tf!(NAN) == NAN);
+    assert_eq!(tf!(INF).partial_cmp(&NAN), Some(Ordering::Greater));
+
+    // Including when the f64 is on the left of the comparison
+    assert!(NAN < tf!(0.0));
+    assert!(NAN == tf!(NAN));
+    assert_eq!(NAN.partial_cmp(&tf!(N_INF)), Some(Ordering::Less));
+    assert_eq!(NAN.partial_cmp(&tf!(NAN)), Some(Ordering::Equal));
+}
+
+#[test]
+fn total_cmp_agrees_with_ord() {
+    use fc_sort::TotalFloat;
+
+    // Random values, random bit patterns, and NaNs with differing payloads
+    let mut values: Vec<f64> =
+        (0..200).map(|_| rand::random::<f64>()).collect();
+    values.extend((0..200).map(|_| f64::from_bits(rand::random::<u64>())));
+    values.extend((0..50).map(|_| {
+        f64::from_bits(0x7ff8000000000000 | rand::random::<u64>() >> 13)
+    }));
+    values.extend(&[0.0, -0.0, INF, N_INF, NAN, -NAN]);
+
+    for a in &values {
+        for b in &values {
+            assert_eq!(fc_sort::total_cmp(a, b),
+                       TotalFloat::from(*a).cmp(&TotalFloat::from(*b)));
+        }
+    }
+}
+
+#[test]
+fn sort_by_total_cmp() {
+    // A Vec<f64> can be sorted directly
+    let mut list = [3.0, NAN, -1.0, INF, 0.0, N_INF];
+    list.sort_by(fc_sort::total_cmp);
+    assert!(list[0].is_nan());
+    assert_eq!(list[1..], [N_INF, -1.0, 0.0, 3.0, INF]);
+}
+
+#[test]
+fn sort_by_total_key() {
+    // Sort records by an f64 field
+    let mut list = [("c", 3.0), ("nan", NAN), ("a", -1.0), ("b", 0.5)];
+    list.sort_by(fc_sort::total_cmp_by_key(|&(_, x): &(&str, f64)| x));
+    let names: Vec<_> = list.iter().map(|&(name, _)| name).collect();
+    assert_eq!(names, ["nan", "a", "b", "c"]);
+}
@@ -0,0 +1,186 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_desc;
+use fc_sort::merge_sort_with;
+use fc_sort::total_cmp;
+use fc_sort::unwrap_vec;
+use fc_sort::wrap_vec;
+use fc_sort::NanFound;
+use fc_sort::NanGreatest;
+use fc_sort::NanPlacement;
+use fc_sort::SortConfig;
+use fc_sort::SortOrder;
+use fc_sort::TotalFloat;
+use fc_sort::TotalFloatWith;
+use rand::random;
+
+const NAN: f64 = f64::NAN;
+
+// Random values with plenty of duplicates, zeros of both signs and NaNs.
+fn random_list(n: usize) -> Vec<f64> {
+    let specials = [NAN, -NAN, 0.0, -0.0, 1.0, -1.0];
+    (0..n)
+        .map(|_| if random::<bool>() {
+            specials[random::<u32>() as usize % specials.len()]
+        } else {
+            random::<f64>()
+        })
+        .collect()
+}
+
+fn bits(list: &[f64]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn insertion_cutoff_is_invisible() {
+    // Every cutoff agrees bit for bit with the standard stable sort, whose
+    // result is the unique stable ordering
+    let configs = [SortConfig::new(),
+                   SortConfig::new().insertion_cutoff(0),
+                   SortConfig::new().insertion_cutoff(1),
+                   SortConfig::new().insertion_cutoff(7),
+                   SortConfig::new().insertion_cutoff(64)];
+    for n in 0..201 {
+        let list = random_list(n);
+        let mut expected = list.clone();
+        expected.sort_by(total_cmp);
+        for config in configs.iter() {
+            let sorted = merge_sort_with(wrap_vec(list.clone()), config);
+            let sorted = unwrap_vec(sorted.unwrap());
+            assert_eq!(bits(&sorted), bits(&expected));
+        }
+        let sorted = unwrap_vec(merge_sort(wrap_vec(list.clone())));
+        assert_eq!(bits(&sorted), bits(&expected));
+    }
+}
+
+#[test]
+fn default_config() {
+    // The default configuration is that of new
+    assert_eq!(SortConfig::default(), SortConfig::new());
+    assert_eq!(merge_sort_with(tfvec![2.0, NAN, 1.0], &SortConfig::default()),
+               Ok(tfvec![NAN, 1.0, 2.0]));
+}
+
+// NaNs with distinguishable payloads, at the start, middle and end of a list.
+fn nans_everywhere() -> (Vec<TotalFloat>, [u64; 3]) {
+    let nans = [NAN.to_bits() | 1, NAN.to_bits() | 2, (-NAN).to_bits()];
+    let list = vec![TotalFloat::from_bits(nans[0]),
+                    tf!(2.0),
+                    tf!(-0.0),
+                    TotalFloat::from_bits(nans[1]),
+                    tf!(0.0),
+                    tf!(-1.0),
+                    TotalFloat::from_bits(nans[2])];
+    (list, nans)
+}
+
+fn tf_bits(list: &[TotalFloat]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn order() {
+    // Descending matches merge_sort_desc, keeping equal values in order,
+    // except that NaNs are placed first by default
+    let list = wrap_vec(random_list(500));
+    let config = SortConfig::new()
+        .order(SortOrder::Descending)
+        .nan_placement(NanPlacement::Last);
+    let sorted = merge_sort_with(list.clone(), &config).unwrap();
+    assert_eq!(tf_bits(&sorted), tf_bits(&merge_sort_desc(list)));
+
+    let config = SortConfig::new().order(SortOrder::Descending);
+    assert_eq!(merge_sort_with(tfvec![1.0, NAN, 3.0, 2.0], &config),
+               Ok(tfvec![NAN, 3.0, 2.0, 1.0]));
+}
+
+#[test]
+fn nan_placement_first() {
+    let (list, nans) = nans_everywhere();
+    let config = SortConfig::new().nan_placement(NanPlacement::First);
+    for &order in &[SortOrder::Ascending, SortOrder::Descending] {
+        let sorted = merge_sort_with(list.clone(), &config.order(order))
+            .unwrap();
+        assert_eq!(tf_bits(&sorted[..3]), nans);
+        assert_eq!(sorted.len(), 7);
+    }
+    let sorted = merge_sort_with(list, &config).unwrap();
+    assert_eq!(tf_bits(&sorted[3..]),
+               tf_bits(&tfvec![-1.0, -0.0, 0.0, 2.0]));
+}
+
+#[test]
+fn nan_placement_last() {
+    // As SQL's NULLS LAST, in either order
+    let (list, nans) = nans_everywhere();
+    let config = SortConfig::new().nan_placement(NanPlacement::Last);
+    let sorted = merge_sort_with(list.clone(), &config).unwrap();
+    assert_eq!(tf_bits(&sorted),
+               tf_bits(&[tf!(-1.0),
+                         tf!(-0.0),
+                         tf!(0.0),
+                         tf!(2.0),
+                         TotalFloat::from_bits(nans[0]),
+                         TotalFloat::from_bits(nans[1]),
+                         TotalFloat::from_bits(nans[2])]));
+    let config = config.order(SortOrder::Descending);
+    let sorted = merge_sort_with(list, &config).unwrap();
+    assert_eq!(tf_bits(&sorted[..4]), tf_bits(&tfvec![2.0, -0.0, 0.0, -1.0]));
+    assert_eq!(tf_bits(&sorted[4..]), nans);
+
+    // And under a NaN policy where NaNs are already sorted last
+    let list = vec![TotalFloatWith::<NanGreatest>::new(NAN), tf!(1.0).into()];
+    let sorted = merge_sort_with(list, &config.order(SortOrder::Ascending));
+    assert!(sorted.unwrap()[1].is_nan());
+}
+
+#[test]
+fn nan_placement_drop() {
+    // The number dropped is the difference in length
+    let (list, _) = nans_everywhere();
+    let config = SortConfig::new().nan_placement(NanPlacement::Drop);
+    let sorted = merge_sort_with(list.clone(), &config).unwrap();
+    assert_eq!(tf_bits(&sorted), tf_bits(&tfvec![-1.0, -0.0, 0.0, 2.0]));
+    assert_eq!(list.len() - sorted.len(), 3);
+}
+
+#[test]
+fn nan_placement_error() {
+    // The index of the first NaN is reported, wherever it is
+    let (list, _) = nans_everywhere();
+    let config = SortConfig::new().nan_placement(NanPlacement::Error);
+    assert_eq!(merge_sort_with(list.clone(), &config),
+               Err(NanFound { index: 0 }));
+    assert_eq!(merge_sort_with(list[1..].to_vec(), &config),
+               Err(NanFound { index: 2 }));
+    assert_eq!(merge_sort_with(list[4..].to_vec(), &config),
+               Err(NanFound { index: 2 }));
+    assert_eq!(merge_sort_with(tfvec![2.0, 1.0], &config),
+               Ok(tfvec![1.0, 2.0]));
+    assert_eq!(NanFound { index: 4 }.to_string(), "NaN found at index 4");
+}
+
+#[test]
+fn presorted_is_invisible() {
+    // With or without the presorted check, sorted and strictly descending
+    // input sort the same, bit for bit
+    let mut sorted = random_list(300);
+    sorted.sort_by(total_cmp);
+    let mut descending = sorted.clone();
+    descending.dedup_by(|a, b| total_cmp(a, b).is_eq());
+    descending.reverse();
+    for list in [sorted, descending, random_list(300)].iter() {
+        let mut expected = list.clone();
+        expected.sort_by(total_cmp);
+        for &presorted in &[true, false] {
+            let config = SortConfig::new().presorted(presorted);
+            let result = merge_sort_with(wrap_vec(list.clone()), &config);
+            assert_eq!(bits(&unwrap_vec(result.unwrap())), bits(&expected));
+        }
+    }
+}
@@ -0,0 +1,42 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::TotalFloat;
+
+// Constructed at compile time, which requires TotalFloat::new to be a const fn
+static ORIGIN: TotalFloat = TotalFloat::new(0.0);
+const ONE: TotalFloat = TotalFloat::new(1.0);
+
+#[derive(Default)]
+struct Point {
+    x: TotalFloat,
+    y: TotalFloat,
+}
+
+#[test]
+fn new_matches_from() {
+    // new and From<f64> should construct the same value
+    assert_eq!(TotalFloat::new(2.5), tf!(2.5));
+    assert_eq!(TotalFloat::new(-0.0), tf!(-0.0));
+    assert!(TotalFloat::new(f64::NAN).is_nan());
+}
+
+#[test]
+fn const_and_static() {
+    // The compile time constructed values are usable as normal
+    assert_eq!(ORIGIN, tf!(0.0));
+    assert_eq!(ONE, tf!(1.0));
+    assert!(ORIGIN < ONE);
+}
+
+#[test]
+fn default_is_zero() {
+    // The default should be positive zero
+    assert_eq!(TotalFloat::default(), tf!(0.0));
+    assert!(TotalFloat::default().is_sign_positive());
+
+    // And should be usable in a derived Default
+    let point = Point::default();
+    assert_eq!(point.x, tf!(0.0));
+    assert_eq!(point.y, tf!(0.0));
+}
@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::IntConversionError;
+use std::convert::TryFrom;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn exact_integers() {
+    // Integer valued floats convert exactly
+    assert_eq!(i64::try_from(tf!(42.0)), Ok(42));
+    assert_eq!(i32::try_from(tf!(-7.0)), Ok(-7));
+    assert_eq!(u64::try_from(tf!(7.0)), Ok(7));
+    assert_eq!(usize::try_from(tf!(3.0)), Ok(3));
+
+    // -0.0 is just zero
+    assert_eq!(i64::try_from(tf!(-0.0)), Ok(0));
+    assert_eq!(u64::try_from(tf!(-0.0)), Ok(0));
+}
+
+#[test]
+fn large_exactly_representable_integers() {
+    // 2^53 + 2 and 2^62 are exactly representable
+    assert_eq!(i64::try_from(tf!(9007199254740994.0)), Ok(9007199254740994));
+    assert_eq!(i64::try_from(tf!(4611686018427387904.0)),
+               Ok(4611686018427387904));
+
+    // As are the least values of the signed types
+    assert_eq!(i64::try_from(tf!(i64::MIN as f64)), Ok(i64::MIN));
+    assert_eq!(i32::try_from(tf!(i32::MIN as f64)), Ok(i32::MIN));
+    assert_eq!(i32::try_from(tf!(i32::MAX as f64)), Ok(i32::MAX));
+
+    // 2^63 fits in a u64 but not in an i64
+    assert_eq!(u64::try_from(tf!(9223372036854775808.0)),
+               Ok(9223372036854775808));
+    assert_eq!(i64::try_from(tf!(9223372036854775808.0)),
+               Err(IntConversionError::OutOfRange));
+}
+
+#[test]
+fn error_cases() {
+    assert_eq!(i64::try_from(tf!(NAN)), Err(IntConversionError::Nan));
+    assert_eq!(u64::try_from(tf!(INF)), Err(IntConversionError::Infinite));
+    assert_eq!(i32::try_from(tf!(N_INF)), Err(IntConversionError::Infinite));
+    assert_eq!(i64::try_from(tf!(1.5)), Err(IntConversionError::NotAnInteger));
+    assert_eq!(u64::try_from(tf!(-1.0)), Err(IntConversionError::OutOfRange));
+    assert_eq!(i32::try_from(tf!(2147483648.0)),
+               Err(IntConversionError::OutOfRange));
+
+    // MAX + 0.5 rounds to an integer beyond the range of the type
+    assert_eq!(i64::try_from(tf!(i64::MAX as f64 + 0.5)),
+               Err(IntConversionError::OutOfRange));
+    assert_eq!(u64::try_from(tf!(f64::MAX + 0.5)),
+               Err(IntConversionError::OutOfRange));
+
+    // Whereas for i32 the half is kept, so it is a fraction within range
+    assert_eq!(i32::try_from(tf!(i32::MAX as f64 + 0.5)),
+               Err(IntConversionError::NotAnInteger));
+    assert_eq!(i32::try_from(tf!(i32::MAX as f64 + 1.0)),
+               Err(IntConversionError::OutOfRange));
+}
+
+#[test]
+fn lossy_conversion_saturates() {
+    assert_eq!(tf!(2.9).to_int_lossy(), 2);
+    assert_eq!(tf!(-2.9).to_int_lossy(), -2);
+    assert_eq!(tf!(INF).to_int_lossy(), i64::MAX);
+    assert_eq!(tf!(N_INF).to_int_lossy(), i64::MIN);
+    assert_eq!(tf!(1e300).to_int_lossy(), i64::MAX);
+    assert_eq!(tf!(NAN).to_int_lossy(), 0);
+}
@@ -0,0 +1,138 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::dedup_by_tolerance;
+use fc_sort::dedup_by_tolerance_mean;
+use fc_sort::dedup_total;
+use fc_sort::mode;
+use fc_sort::sort_unique;
+use fc_sort::value_counts;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn bits(list: &[TotalFloat]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn dedup_keeps_first_occurrence() {
+    // Mixed NaN payloads and zero signs collapse to their first occurrence
+    let nan_a = f64::from_bits(0xfff8_0000_0000_0001);
+    let nan_b = f64::from_bits(0x7ff0_0000_0000_0002);
+    let mut list = tfvec![nan_a, NAN, nan_b, N_INF, -0.0, 0.0, -0.0, 1.0, 1.0,
+                          INF, INF];
+    dedup_total(&mut list);
+    assert_eq!(bits(&list),
+               [0xfff8_0000_0000_0001,
+                N_INF.to_bits(),
+                0x8000_0000_0000_0000,
+                1.0f64.to_bits(),
+                INF.to_bits()]);
+
+    let mut list = tfvec![0.0, -0.0];
+    dedup_total(&mut list);
+    assert_eq!(bits(&list), [0]);
+}
+
+#[test]
+fn dedup_edge_cases() {
+    // Empty and unique lists are unchanged
+    let mut empty = tfvec![];
+    dedup_total(&mut empty);
+    assert_eq!(empty, tfvec![]);
+    let mut unique = tfvec![NAN, 1.0, 2.0];
+    dedup_total(&mut unique);
+    assert_eq!(unique, tfvec![NAN, 1.0, 2.0]);
+}
+
+#[test]
+fn sort_unique_values() {
+    // Sorting then deduplicating leaves one of each value
+    let list = tfvec![3.0, NAN, -0.0, 3.0, 0.0, -NAN, INF, 1.0, INF, 1.0];
+    assert_eq!(sort_unique(list), tfvec![NAN, 0.0, 1.0, 3.0, INF]);
+
+    // Presorted input keeps the first occurrence of each value
+    let list = tfvec![-NAN, NAN, 0.0, -0.0, 2.0];
+    assert_eq!(bits(&sort_unique(list)),
+               [(-NAN).to_bits(), 0, 2.0f64.to_bits()]);
+}
+
+#[test]
+fn tolerance_keeps_first_of_cluster() {
+    // Sensor noise collapses, while distinct readings are kept
+    let mut list = tfvec![1.0, 1.0000001, 1.0000002, 2.0, 2.5, 2.5000001];
+    dedup_by_tolerance(&mut list, 0.0, 1e-6);
+    assert_eq!(list, tfvec![1.0, 2.0, 2.5]);
+
+    let mut list = tfvec![-0.001, 0.0, 0.0005, 0.1];
+    dedup_by_tolerance(&mut list, 0.001, 0.0);
+    assert_eq!(list, tfvec![-0.001, 0.1]);
+}
+
+#[test]
+fn tolerance_chains() {
+    // a ~ b and b ~ c, but not a ~ c: chaining merges all three
+    let (a, b, c) = (1.0, 1.5, 2.0);
+    assert!(c - a > 0.6);
+    let mut list = tfvec![a, b, c, 3.0];
+    dedup_by_tolerance(&mut list, 0.6, 0.0);
+    assert_eq!(list, tfvec![a, 3.0]);
+
+    let mut list = tfvec![a, b, c, 3.0];
+    dedup_by_tolerance_mean(&mut list, 0.6, 0.0);
+    assert_eq!(list, tfvec![1.5, 3.0]);
+}
+
+#[test]
+fn tolerance_nan_and_infinity() {
+    // NaNs only merge with NaNs, and infinities only with themselves
+    let big = f64::MAX;
+    let mut list = tfvec![NAN, -NAN, N_INF, -big, big, INF, INF];
+    dedup_by_tolerance(&mut list, 1e300, 1.0);
+    assert_eq!(bits(&list),
+               [NAN.to_bits(), N_INF.to_bits(), (-big).to_bits(),
+                big.to_bits(), INF.to_bits()]);
+
+    let mut list = tfvec![NAN, NAN, 1.0, 1.0, INF, INF];
+    dedup_by_tolerance_mean(&mut list, 0.5, 0.5);
+    assert_eq!(bits(&list), [NAN.to_bits(), 1.0f64.to_bits(), INF.to_bits()]);
+}
+
+#[test]
+fn tolerance_mean_of_cluster() {
+    // Each cluster is replaced by its mean
+    let mut list = tfvec![1.0, 1.1, 1.2, 5.0, 5.2];
+    dedup_by_tolerance_mean(&mut list, 0.25, 0.0);
+    assert_eq!(list.len(), 2);
+    assert!((list[0].inner - 1.1).abs() < 1e-12);
+    assert!((list[1].inner - 5.1).abs() < 1e-12);
+}
+
+#[test]
+fn value_counts_of_runs() {
+    // All distinct, all equal, and mixed runs including NaNs and zeros
+    assert_eq!(value_counts(&tfvec![1.0, 2.0, 3.0]),
+               [(tf!(1.0), 1), (tf!(2.0), 1), (tf!(3.0), 1)]);
+    assert_eq!(value_counts(&tfvec![4.0, 4.0, 4.0]), [(tf!(4.0), 3)]);
+    let counts = value_counts(&tfvec![NAN, -NAN, -0.0, 0.0, 0.0, INF]);
+    assert_eq!(counts, [(tf!(NAN), 2), (tf!(0.0), 3), (tf!(INF), 1)]);
+    assert_eq!(counts[1].0.to_bits(), (-0.0f64).to_bits());
+    assert!(value_counts(&[]).is_empty());
+}
+
+#[test]
+fn mode_of_runs() {
+    // Ties between equally frequent values go to the least
+    assert_eq!(mode(&tfvec![1.0, 2.0, 3.0]), Some((tf!(1.0), 1)));
+    assert_eq!(mode(&tfvec![4.0, 4.0, 4.0]), Some((tf!(4.0), 3)));
+    assert_eq!(mode(&tfvec![1.0, 2.0, 2.0, 3.0, 3.0]), Some((tf!(2.0), 2)));
+    assert_eq!(mode(&[]), None);
+
+    // NaN can be the mode, whatever the payloads
+    let (value, count) = mode(&tfvec![NAN, -NAN, NAN, 1.0, 1.0]).unwrap();
+    assert!(value.is_nan());
+    assert_eq!(count, 3);
+}
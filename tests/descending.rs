@@ -0,0 +1,42 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::merge_sort_desc;
+use fc_sort::merge_sort_ordered;
+use fc_sort::SortOrder;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn nans_come_last() {
+    // Greatest first, and NaNs, being least, at the end
+    assert_eq!(merge_sort_desc(tfvec![3.0, NAN, INF, -1.0, N_INF, 0.0]),
+               tfvec![INF, 3.0, 0.0, -1.0, N_INF, NAN]);
+    assert_eq!(merge_sort_desc(tfvec![]), tfvec![]);
+}
+
+#[test]
+fn stable_when_descending() {
+    // Equal values keep their original order, rather than being reversed
+    let nan_a = TotalFloat::from_bits(0x7ff8_0000_0000_0001);
+    let nan_b = TotalFloat::from_bits(0xfff8_0000_0000_0002);
+    let sorted = merge_sort_desc(vec![nan_a, tf!(0.0), tf!(1.0), nan_b,
+                                      tf!(-0.0)]);
+    let bits: Vec<u64> = sorted.iter().map(|x| x.to_bits()).collect();
+    assert_eq!(bits,
+               [1.0f64.to_bits(), 0, 0x8000_0000_0000_0000,
+                0x7ff8_0000_0000_0001, 0xfff8_0000_0000_0002]);
+}
+
+#[test]
+fn sort_order() {
+    // The order parameter selects either direction
+    let list = tfvec![2.0, NAN, -5.0, 7.0];
+    assert_eq!(merge_sort_ordered(list.clone(), SortOrder::Ascending),
+               tfvec![NAN, -5.0, 2.0, 7.0]);
+    assert_eq!(merge_sort_ordered(list, SortOrder::Descending),
+               tfvec![7.0, 2.0, -5.0, NAN]);
+}
@@ -0,0 +1,64 @@
+extern crate fc_sort;
+
+#[cfg(feature = "std")]
+use fc_sort::sort_map_by_value;
+#[cfg(feature = "std")]
+use fc_sort::sort_map_by_value_then_key;
+use fc_sort::sort_entries_by_total_value;
+use fc_sort::SortOrder;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn entries_by_value() {
+    // Ascending puts NaN first, descending last, with ties in given order
+    let entries = vec![("a", 2.0), ("b", NAN), ("c", -1.0), ("d", 2.0),
+                       ("e", INF), ("f", NAN)];
+    let keys = |order| -> Vec<&str> {
+        sort_entries_by_total_value(entries.clone(), order)
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect()
+    };
+    assert_eq!(keys(SortOrder::Ascending), ["b", "f", "c", "a", "d", "e"]);
+    assert_eq!(keys(SortOrder::Descending), ["e", "a", "d", "c", "b", "f"]);
+
+    let sorted = sort_entries_by_total_value(entries, SortOrder::Ascending);
+    assert!(sorted[0].1.is_nan());
+    assert_eq!(sorted[5].1.inner, INF);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn map_by_value() {
+    // Values that convert to f64 are sorted, NaN included
+    let mut map = HashMap::new();
+    map.insert("x", 3.5f32);
+    map.insert("y", f32::NAN);
+    map.insert("z", -2.0);
+    let sorted = sort_map_by_value(&map, SortOrder::Ascending);
+    let keys: Vec<_> = sorted.iter().map(|&(k, _)| *k).collect();
+    assert_eq!(keys, ["y", "z", "x"]);
+    assert_eq!(sorted[2].1.inner, 3.5);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn map_ties_by_key() {
+    // Duplicate values are ordered by key, ascending in both orders
+    let map: HashMap<u32, f64> =
+        vec![(5, 1.0), (1, 1.0), (3, NAN), (4, 0.0), (2, 1.0), (0, NAN)]
+            .into_iter()
+            .collect();
+    let keys = |order| -> Vec<u32> {
+        sort_map_by_value_then_key(&map, order)
+            .into_iter()
+            .map(|(k, _)| *k)
+            .collect()
+    };
+    assert_eq!(keys(SortOrder::Ascending), [0, 3, 4, 1, 2, 5]);
+    assert_eq!(keys(SortOrder::Descending), [1, 2, 5, 4, 0, 3]);
+}
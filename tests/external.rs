@@ -0,0 +1,151 @@
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::external::external_sort;
+use fc_sort::external::ExternalSortOpts;
+use fc_sort::external::RecordFormat;
+use fc_sort::external::Stats;
+use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
+use rand::random;
+use std::env;
+use std::fs;
+use std::io::Cursor;
+use std::io::ErrorKind;
+use std::process;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn to_bytes(values: &[TotalFloat]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_bits().to_le_bytes()).collect()
+}
+
+fn from_bytes(bytes: &[u8]) -> Vec<TotalFloat> {
+    bytes.chunks(8)
+        .map(|x| {
+            let mut bits = [0; 8];
+            bits.copy_from_slice(x);
+            TotalFloat::from_bits(u64::from_le_bytes(bits))
+        })
+        .collect()
+}
+
+// Sorts binary records, returning the output and the stats.
+fn sort_bytes(input: &[u8], opts: ExternalSortOpts) -> (Vec<u8>, Stats) {
+    let mut output = Vec::new();
+    let stats = external_sort(Cursor::new(input), &mut output, opts).unwrap();
+    (output, stats)
+}
+
+#[test]
+fn many_runs() {
+    // A tiny memory limit forces many runs, which merge to the full sort
+    let mut values: Vec<_> = (0..1000).map(|_| tf!(random::<f64>() - 0.5))
+                                      .collect();
+    values.extend(tfvec![NAN, -NAN, INF, 0.0, -0.0]);
+    let opts = ExternalSortOpts::new().memory_limit(8 * 64);
+    let (output, stats) = sort_bytes(&to_bytes(&values), opts);
+    assert_eq!(stats.runs, 16);
+    assert_eq!(stats.records, 1005);
+    assert_eq!(stats.nans, 2);
+    assert_eq!(stats.bytes_spilled, 1005 * 8);
+    assert_eq!(output.len(), 1005 * 8);
+
+    // Stably, so NaN payloads and zero signs are in their original order
+    let bits = |list: &[TotalFloat]| -> Vec<u64> {
+        list.iter().map(|x| x.to_bits()).collect()
+    };
+    assert_eq!(bits(&from_bytes(&output)), bits(&merge_sort(values)));
+}
+
+#[test]
+fn single_run() {
+    // Input that fits in memory is not spilled
+    let values = tfvec![3.0, NAN, 1.0, 2.0];
+    let (output, stats) = sort_bytes(&to_bytes(&values),
+                                     ExternalSortOpts::new());
+    assert_eq!(from_bytes(&output), tfvec![NAN, 1.0, 2.0, 3.0]);
+    assert_eq!(stats, Stats { records: 4, runs: 1, bytes_spilled: 0, nans: 1 });
+
+    // And empty input gives empty output
+    let (output, stats) = sort_bytes(&[], ExternalSortOpts::new());
+    assert!(output.is_empty());
+    assert_eq!(stats, Stats::default());
+}
+
+#[test]
+fn exact_runs() {
+    // Input filling a whole number of runs does not spill the last one alone
+    let values = tfvec![3.0, NAN, 1.0, 2.0];
+    let opts = ExternalSortOpts::new().memory_limit(32);
+    let (output, stats) = sort_bytes(&to_bytes(&values), opts);
+    assert_eq!(from_bytes(&output), tfvec![NAN, 1.0, 2.0, 3.0]);
+    assert_eq!(stats, Stats { records: 4, runs: 1, bytes_spilled: 0, nans: 1 });
+
+    // And two full runs are both spilled and merged
+    let opts = ExternalSortOpts::new().memory_limit(16);
+    let (output, stats) = sort_bytes(&to_bytes(&values), opts);
+    assert_eq!(from_bytes(&output), tfvec![NAN, 1.0, 2.0, 3.0]);
+    assert_eq!(stats,
+               Stats { records: 4, runs: 2, bytes_spilled: 32, nans: 1 });
+}
+
+#[test]
+fn text_records() {
+    // Lines of text, in the same format out as in
+    let input = "3.5\nNaN\n\n-inf\n  2\n1e3\n-0\n";
+    let opts = ExternalSortOpts::new().memory_limit(16)
+                                      .format(RecordFormat::Text);
+    let mut output = Vec::new();
+    let stats = external_sort(input.as_bytes(), &mut output, opts).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(),
+               "NaN\n-inf\n-0\n2\n3.5\n1000\n");
+    assert_eq!((stats.records, stats.runs, stats.nans), (6, 3, 1));
+}
+
+#[test]
+fn bad_records() {
+    // Partial binary records and unparseable lines are rejected
+    let mut bytes = to_bytes(&tfvec![1.0, 2.0]);
+    bytes.push(0);
+    let error = external_sort(Cursor::new(bytes), Vec::new(),
+                              ExternalSortOpts::new().memory_limit(8))
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    let opts = ExternalSortOpts::new().format(RecordFormat::Text);
+    let error = external_sort("1\nfoo\n".as_bytes(), Vec::new(), opts)
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn batched_merge() {
+    // More runs than are merged at once are merged in batches, stably, and
+    // every temporary file is removed
+    let dir = env::temp_dir().join(format!("fc-sort-batched-{}",
+                                           process::id()));
+    fs::create_dir(&dir).unwrap();
+    let values: Vec<_> = (0..300)
+        .map(|i| match i % 5 {
+            0 => TotalFloat::from_bits(0x7ff8000000000000 + i),
+            1 => tf!(-0.0),
+            2 => tf!(0.0),
+            _ => tf!(random::<f64>()),
+        })
+        .collect();
+    let opts = ExternalSortOpts::new().memory_limit(8).temp_dir(&dir);
+    let (output, stats) = sort_bytes(&to_bytes(&values), opts);
+    assert_eq!(stats.runs, 300);
+    assert_eq!(stats.bytes_spilled, 2 * 300 * 8);
+    let bits = |list: &[TotalFloat]| -> Vec<u64> {
+        list.iter().map(|x| x.to_bits()).collect()
+    };
+    assert_eq!(bits(&from_bytes(&output)), bits(&merge_sort(values)));
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+    fs::remove_dir(&dir).unwrap();
+}
@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate fc_sort;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+// The grid of values every format is checked against
+const VALUES: [f64; 9] = [0.0, -0.0, 1.23456, -9876.54321, 1e-7, 6.02e23,
+                          INF, N_INF, NAN];
+
+/// Asserts that formatting a TotalFloat with the given format string matches
+/// formatting the raw f64 with the same format string.
+macro_rules! assert_same_format {
+    ($($fmt:expr),+) => { $(
+        for &x in VALUES.iter() {
+            assert_eq!(format!($fmt, tf!(x)), format!($fmt, x));
+        }
+    )+ }
+}
+
+#[test]
+fn display_flags() {
+    // Display should forward width, precision, fill, sign and alignment
+    assert_same_format!("{}", "{:.3}", "{:10.2}", "{:<12}", "{:>12}",
+                        "{:^12.1}", "{:*^12}", "{:+}", "{:+.2}", "{:08.3}");
+}
+
+#[test]
+fn debug_flags() {
+    // Debug should forward the same state, including the alternate flag
+    assert_same_format!("{:?}", "{:.3?}", "{:10.2?}", "{:#?}", "{:+?}",
+                        "{:>12?}");
+}
+
+#[test]
+fn exponent_formats() {
+    // LowerExp and UpperExp should behave as they do for f64
+    assert_same_format!("{:e}", "{:E}", "{:.2e}", "{:.2E}", "{:12.3e}",
+                        "{:+e}", "{:<14E}");
+}
+
+#[test]
+fn precision_is_respected() {
+    // The motivating example for forwarding formatter state
+    assert_eq!(format!("{:.3}", tf!(1.23456)), "1.235");
+    assert_eq!(format!("{:10.2}", tf!(1.23456)), "      1.23");
+    assert_eq!(format!("{:e}", tf!(1234.5)), "1.2345e3");
+}
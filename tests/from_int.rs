@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::InexactConversionError;
+use fc_sort::TotalFloat;
+use std::convert::TryFrom;
+
+#[test]
+fn lossless_conversions() {
+    // The small integers and f32 convert exactly
+    assert_eq!(TotalFloat::from(-8i8), tf!(-8.0));
+    assert_eq!(TotalFloat::from(i16::MIN), tf!(-32768.0));
+    assert_eq!(TotalFloat::from(i32::MAX), tf!(2147483647.0));
+    assert_eq!(TotalFloat::from(u8::MAX), tf!(255.0));
+    assert_eq!(TotalFloat::from(u16::MAX), tf!(65535.0));
+    assert_eq!(TotalFloat::from(u32::MAX), tf!(4294967295.0));
+    assert_eq!(TotalFloat::from(0.1f32), tf!(f64::from(0.1f32)));
+    assert!(TotalFloat::from(f32::NAN).is_nan());
+}
+
+#[test]
+fn macro_accepts_literals() {
+    // Integer literals and other lossless sources are accepted by tf!
+    assert_eq!(tf!(3), tf!(3.0));
+    assert_eq!(tf!(-3i16), tf!(-3.0));
+    assert_eq!(tf!(1.5f32), tf!(1.5));
+    assert_eq!(tfvec![1, 2.5, 3u8], [tf!(1.0), tf!(2.5), tf!(3.0)]);
+}
+
+#[test]
+fn exact_wide_integers() {
+    // Wide integers which are exactly representable convert
+    assert_eq!(TotalFloat::try_from(1i64 << 53), Ok(tf!(9007199254740992.0)));
+    assert_eq!(TotalFloat::try_from(-(1i64 << 53)),
+               Ok(tf!(-9007199254740992.0)));
+    assert_eq!(TotalFloat::try_from(i64::MIN), Ok(tf!(i64::MIN as f64)));
+    assert_eq!(TotalFloat::try_from(u64::MAX - 2047),
+               Ok(tf!((u64::MAX - 2047) as f64)));
+    assert_eq!(TotalFloat::try_from(i128::MIN), Ok(tf!(i128::MIN as f64)));
+    assert_eq!(TotalFloat::try_from(0u64), Ok(tf!(0.0)));
+}
+
+#[test]
+fn inexact_wide_integers() {
+    // 2^53 + 1 is the smallest positive integer f64 cannot hold
+    let n = (1i64 << 53) + 1;
+    assert_eq!(TotalFloat::try_from(n), Err(InexactConversionError));
+    assert_eq!(TotalFloat::try_from(-n), Err(InexactConversionError));
+    assert_eq!(TotalFloat::try_from(n as u64), Err(InexactConversionError));
+    assert_eq!(TotalFloat::try_from(i128::from(n)),
+               Err(InexactConversionError));
+
+    // The maximum values round up to a power of two, so are also inexact
+    assert_eq!(TotalFloat::try_from(i64::MAX), Err(InexactConversionError));
+    assert_eq!(TotalFloat::try_from(u64::MAX), Err(InexactConversionError));
+    assert_eq!(TotalFloat::try_from(i128::MAX), Err(InexactConversionError));
+}
@@ -0,0 +1,98 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort_by;
+use fc_sort::merge_sort_with;
+use fc_sort::total_cmp;
+use fc_sort::unwrap_vec;
+use fc_sort::wrap_vec;
+use fc_sort::SortConfig;
+use rand::random;
+
+const NAN: f64 = f64::NAN;
+
+// Gallop thresholds from always galloping to never.
+const MIN_GALLOPS: [usize; 5] = [0, 1, 2, 7, usize::MAX];
+
+fn bits(list: &[f64]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+// Sorts the concatenation of two lists, which are each sorted already, so
+// are merged as two runs, and checks that every gallop threshold agrees bit
+// for bit with the standard stable sort.
+fn check_merge(a: &[f64], b: &[f64]) {
+    let mut list = a.to_vec();
+    list.extend_from_slice(b);
+    let mut expected = list.clone();
+    expected.sort_by(total_cmp);
+    for &min_gallop in MIN_GALLOPS.iter() {
+        let config = SortConfig::new()
+            .insertion_cutoff(1)
+            .min_gallop(min_gallop);
+        let sorted = merge_sort_with(wrap_vec(list.clone()), &config);
+        let sorted = unwrap_vec(sorted.unwrap());
+        assert_eq!(bits(&sorted), bits(&expected));
+    }
+}
+
+// A sorted list of n random values, with duplicates and NaNs of differing
+// payloads, so that any instability shows up in the bits.
+fn random_sorted(n: usize) -> Vec<f64> {
+    let mut list: Vec<f64> = (0..n)
+        .map(|_| match random::<u32>() % 4 {
+            0 => f64::from_bits(NAN.to_bits() | (random::<u64>() % 16)),
+            1 => (random::<u32>() % 8) as f64,
+            _ => random::<f64>() * 8.0,
+        })
+        .collect();
+    list.sort_by(total_cmp);
+    list
+}
+
+#[test]
+fn one_sided() {
+    // One run wins all but the last comparison, or all of them
+    let low: Vec<f64> = (1..1000).map(|i| i as f64).collect();
+    check_merge(&low, &[1000.0]);
+    check_merge(&[1000.0], &low);
+    check_merge(&low, &[500.5]);
+    check_merge(&[0.0], &low);
+    check_merge(&low, &low);
+}
+
+#[test]
+fn interleaved() {
+    // Stretches of each run in turn, of lengths either side of the threshold
+    for &stretch in &[1, 6, 7, 8, 50] {
+        let a: Vec<f64> = (0..1000)
+            .map(|i| ((i / stretch) * 2 * stretch + i % stretch) as f64)
+            .collect();
+        let b: Vec<f64> = a.iter().map(|x| x + stretch as f64).collect();
+        check_merge(&a, &b);
+        check_merge(&b, &a);
+    }
+}
+
+#[test]
+fn equal_stretches() {
+    // Long stretches of equal values, which always go to the first run. Two
+    // runs of keys in stretches of 300, each paired with its position
+    let list: Vec<(u32, usize)> = (0..2000)
+        .map(|i| ((i % 1000) as u32 / 300, i))
+        .collect();
+    let sorted = merge_sort_by(list, |x, y| x.0.cmp(&y.0));
+    for w in sorted.windows(2) {
+        assert!(w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1));
+    }
+}
+
+#[test]
+fn random_pairs() {
+    // Differential against the standard stable sort on random sorted pairs
+    for _ in 0..200 {
+        let a = random_sorted(random::<u32>() as usize % 300);
+        let b = random_sorted(random::<u32>() as usize % 300);
+        check_merge(&a, &b);
+    }
+}
@@ -0,0 +1,61 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::merge;
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_in_place;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn pairs() {
+    // Pairs sort by their TotalFloat, then by their index
+    let list = vec![(tf!(2.0), 0), (tf!(NAN), 1), (tf!(-1.0), 2),
+                    (tf!(2.0), 3), (tf!(INF), 4), (tf!(NAN), 5)];
+    assert_eq!(merge_sort(list),
+               [(tf!(NAN), 1), (tf!(NAN), 5), (tf!(-1.0), 2), (tf!(2.0), 0),
+                (tf!(2.0), 3), (tf!(INF), 4)]);
+}
+
+#[test]
+fn strings() {
+    // Any Ord type can be sorted and merged
+    let words: Vec<String> = ["pear", "apple", "fig", "banana", "apple"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    assert_eq!(merge_sort(words),
+               ["apple", "apple", "banana", "fig", "pear"]);
+
+    let a = vec![String::from("b"), String::from("d")];
+    let b = vec![String::from("a"), String::from("c"), String::from("e")];
+    assert_eq!(merge(a, b), ["a", "b", "c", "d", "e"]);
+
+    let mut numbers = [5, 3, 9, 1, 3];
+    merge_sort_in_place(&mut numbers);
+    assert_eq!(numbers, [1, 3, 3, 5, 9]);
+}
+
+// A value that cannot be cloned, so sorting it must move it.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Unique(TotalFloat, Box<u32>);
+
+#[test]
+fn no_clones() {
+    // Sorting and merging values that are not Clone
+    let list: Vec<_> = [3.0, NAN, 1.0, 2.0, 1.0]
+        .iter()
+        .zip(0..)
+        .map(|(&x, i)| Unique(tf!(x), Box::new(i)))
+        .collect();
+    let sorted = merge_sort(list);
+    let order: Vec<u32> = sorted.iter().map(|x| *x.1).collect();
+    assert_eq!(order, [1, 2, 4, 3, 0]);
+
+    let extra = vec![Unique(tf!(0.0), Box::new(9))];
+    let merged = merge(sorted, extra);
+    let order: Vec<u32> = merged.iter().map(|x| *x.1).collect();
+    assert_eq!(order, [1, 9, 2, 4, 3, 0]);
+}
@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::TotalFloat;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn nan_payloads_hash_identically() {
+    // Construct several NaNs with different payloads and sign bits
+    let nans = [0x7ff8000000000000u64,
+                0x7ff800a004001000,
+                0x7ff80090e200a000,
+                0xfff8000000000001,
+                0x7ff0000000000001];
+    let set: HashSet<_> =
+        nans.iter().map(|&b| tf!(f64::from_bits(b))).collect();
+
+    // As they are all equal, they should all collapse to a single entry
+    assert_eq!(set.len(), 1);
+    assert!(set.contains(&TotalFloat::NAN));
+}
+
+#[test]
+fn zeros_hash_identically() {
+    // -0.0 == 0.0, so they should collapse to a single entry
+    let set: HashSet<_> = tfvec![0.0, -0.0].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert!(set.contains(&TotalFloat::ZERO));
+    assert!(set.contains(&TotalFloat::NEG_ZERO));
+}
+
+#[test]
+fn mixed_set() {
+    // NaNs, zeros and ordinary values together, with some repeats
+    let list = tfvec![NAN, 1.0, -0.0, INF, 0.0, 1.0, -NAN, 2.5, INF];
+    let set: HashSet<_> = list.into_iter().collect();
+
+    // {NaN, 0.0, 1.0, 2.5, INF}
+    assert_eq!(set.len(), 5);
+}
+
+#[test]
+fn map_key() {
+    // Count occurrences using TotalFloat as a HashMap key
+    let mut counts = HashMap::new();
+    for x in tfvec![NAN, 1.0, -0.0, -NAN, 0.0, 1.0, NAN] {
+        *counts.entry(x).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&tf!(NAN)], 3);
+    assert_eq!(counts[&tf!(0.0)], 2);
+    assert_eq!(counts[&tf!(1.0)], 2);
+}
@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::heap_sort;
+use fc_sort::into_heap;
+use fc_sort::is_sorted;
+use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+// The values where the total order differs from the partial order of f64.
+const SPECIALS: [f64; 8] = [NAN, -NAN, -INF, INF, 0.0, -0.0, 1.0, -1.0];
+
+#[test]
+fn special_values() {
+    // Every pair and triple of the special values, in every order
+    for &a in SPECIALS.iter() {
+        for &b in SPECIALS.iter() {
+            for &c in SPECIALS.iter() {
+                let list = tfvec![a, b, c, a];
+                let mut sorted = list.clone();
+                heap_sort(&mut sorted);
+                assert_eq!(sorted, merge_sort(list));
+            }
+        }
+    }
+    let mut list = tfvec![1.0, INF, -0.0, NAN, -INF, 0.0, -NAN, -1.0];
+    heap_sort(&mut list);
+    assert_eq!(list, tfvec![NAN, NAN, -INF, -1.0, 0.0, 0.0, 1.0, INF]);
+}
+
+#[test]
+fn random_inputs() {
+    // Random values, with specials mixed in
+    for n in 0..300 {
+        let list: Vec<TotalFloat> = (0..n)
+            .map(|_| if random() {
+                tf!(SPECIALS[random::<u32>() as usize % SPECIALS.len()])
+            } else {
+                tf!(random::<f64>())
+            })
+            .collect();
+        let mut sorted = list.clone();
+        heap_sort(&mut sorted);
+        assert!(is_sorted(&sorted));
+        assert_eq!(sorted, merge_sort(list));
+    }
+}
+
+#[test]
+fn heap_pops_greatest_first() {
+    // Popping the heap empty gives the reverse of the sorted list
+    let list: Vec<TotalFloat> = (0..1000)
+        .map(|i| match i % 5 {
+            0 => tf!(SPECIALS[i % SPECIALS.len()]),
+            _ => tf!(random::<f64>()),
+        })
+        .collect();
+    let mut heap = into_heap(list.clone());
+    let mut popped = Vec::new();
+    while let Some(x) = heap.pop() {
+        popped.push(x);
+    }
+    let mut expected = merge_sort(list);
+    expected.reverse();
+    assert_eq!(popped, expected);
+
+    // So NaNs come last, and the greatest value first
+    let heap = into_heap(tfvec![NAN, 1.0, INF, -INF]);
+    assert_eq!(heap.peek(), Some(&tf!(INF)));
+    assert_eq!(heap.into_sorted_vec(), tfvec![NAN, -INF, 1.0, INF]);
+}
@@ -0,0 +1,132 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::Bins;
+use fc_sort::Histogram;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn equal_width() {
+    // Four bins spanning [0, 8], each two wide
+    let data = tfvec![0.0, 1.0, 2.0, 3.5, 5.0, 7.9, 8.0];
+    let histogram = Histogram::from_sorted(&data, Bins::EqualWidth(4));
+    assert_eq!(histogram.edges(), [0.0, 2.0, 4.0, 6.0, 8.0]);
+    assert_eq!(histogram.counts(), [2, 2, 1, 2]);
+    assert_eq!(histogram.underflow(), 0);
+    assert_eq!(histogram.overflow(), 0);
+}
+
+#[test]
+fn values_on_edges() {
+    // Bins are half-open, except the last which includes its upper edge
+    let data = tfvec![-1.0, 0.0, 1.0, 2.0, 3.0, 4.0];
+    let edges = vec![0.0, 1.0, 2.0, 3.0];
+    let histogram = Histogram::from_sorted(&data, Bins::Explicit(edges));
+    assert_eq!(histogram.counts(), [1, 1, 2]);
+    assert_eq!(histogram.underflow(), 1);
+    assert_eq!(histogram.overflow(), 1);
+    assert_eq!(histogram.bin_of(0.0), Some(0));
+    assert_eq!(histogram.bin_of(0.999), Some(0));
+    assert_eq!(histogram.bin_of(1.0), Some(1));
+    assert_eq!(histogram.bin_of(3.0), Some(2));
+    assert_eq!(histogram.bin_of(3.001), None);
+    assert_eq!(histogram.bin_of(-0.0), Some(0));
+    assert_eq!(histogram.bin_of(NAN), None);
+}
+
+#[test]
+fn infinities_and_nans() {
+    // Infinities fall outside bins chosen from the data, and NaNs are apart
+    let data = tfvec![NAN, NAN, N_INF, 1.0, 2.0, 3.0, INF, INF];
+    let histogram = Histogram::from_sorted(&data, Bins::EqualWidth(2));
+    assert_eq!(histogram.edges(), [1.0, 2.0, 3.0]);
+    assert_eq!(histogram.counts(), [1, 2]);
+    assert_eq!(histogram.underflow(), 1);
+    assert_eq!(histogram.overflow(), 2);
+    assert_eq!(histogram.nan_count(), 2);
+
+    // Explicit infinite edges can take them in
+    let edges = vec![N_INF, 0.0, INF];
+    let histogram = Histogram::from_sorted(&data, Bins::Explicit(edges));
+    assert_eq!(histogram.counts(), [1, 5]);
+    assert_eq!(histogram.overflow(), 0);
+}
+
+#[test]
+fn empty_and_constant_input() {
+    // With no spread the bins span half either side, or [0, 1] with no data
+    let histogram = Histogram::from_sorted(&[], Bins::EqualWidth(2));
+    assert_eq!(histogram.edges(), [0.0, 0.5, 1.0]);
+    assert_eq!(histogram.counts(), [0, 0]);
+    assert_eq!(histogram.nan_count(), 0);
+
+    let histogram = Histogram::from_sorted(&tfvec![5.0, 5.0],
+                                           Bins::EqualWidth(1));
+    assert_eq!(histogram.edges(), [4.5, 5.5]);
+    assert_eq!(histogram.counts(), [2]);
+}
+
+#[test]
+fn huge_span() {
+    // Finite values spanning more than f64::MAX still give finite edges
+    let data = tfvec![-1e308, 0.0, 1e308];
+    let histogram = Histogram::from_sorted(&data, Bins::EqualWidth(2));
+    assert_eq!(histogram.edges(), [-1e308, 0.0, 1e308]);
+    assert_eq!(histogram.counts(), [1, 2]);
+    assert_eq!(histogram.overflow(), 0);
+
+    // And with more bins, every edge is finite and in order
+    let data = tfvec![-f64::MAX, 0.0, f64::MAX];
+    for n in 3..10 {
+        let histogram = Histogram::from_sorted(&data, Bins::EqualWidth(n));
+        let edges = histogram.edges();
+        assert!(edges.iter().all(|x| x.is_finite()));
+        assert!(edges.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(histogram.counts().iter().sum::<usize>(), 3);
+        assert_eq!(histogram.overflow(), 0);
+    }
+    let histogram = Histogram::from_sorted(&data, Bins::EqualWidth(4));
+    assert_eq!(histogram.edges()[2], 0.0);
+    assert_eq!(histogram.counts(), [1, 0, 1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn unordered_edges() {
+    // Explicit edges must be strictly increasing
+    Histogram::from_sorted(&[], Bins::Explicit(vec![0.0, 2.0, 1.0]));
+}
+
+#[test]
+fn freedman_diaconis() {
+    // 1000 evenly spread values have an IQR of about 500, so bins of width
+    // about 100 and ten bins
+    let data: Vec<_> = (0..1000).map(|i| tf!(i as f64)).collect();
+    let histogram = Histogram::from_sorted(&data, Bins::FreedmanDiaconis);
+    assert_eq!(histogram.counts().len(), 10);
+    assert_eq!(histogram.counts().iter().sum::<usize>(), 1000);
+
+    // With no spread there is a single bin
+    let histogram = Histogram::from_sorted(&tfvec![NAN, 2.0, 2.0],
+                                           Bins::FreedmanDiaconis);
+    assert_eq!(histogram.counts(), [2]);
+}
+
+#[test]
+fn freedman_diaconis_outlier() {
+    // A far outlier cannot make more bins than there are values
+    let data = tfvec![0.0, 1.0, 2.0, 3.0, 1e300];
+    let histogram = Histogram::from_sorted(&data, Bins::FreedmanDiaconis);
+    assert_eq!(histogram.counts().len(), 5);
+    assert_eq!(histogram.counts().iter().sum::<usize>(), 5);
+
+    // Nor can values spanning more than f64::MAX, with quartiles between
+    // values far apart
+    let data = tfvec![-1e308, -1e308, 1e308, 1e308, 1e308, 1e308];
+    let histogram = Histogram::from_sorted(&data, Bins::FreedmanDiaconis);
+    assert_eq!(histogram.counts().len(), 2);
+    assert_eq!(histogram.counts().iter().sum::<usize>(), 6);
+}
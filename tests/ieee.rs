@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::TotalFloat;
+use std::cmp::Ordering;
+
+// The IEEE 754 totalOrder chain, from least to greatest
+fn chain() -> Vec<TotalFloat> {
+    [0xfff8000000000001u64, // -NaN with a larger payload
+     0xfff8000000000000,    // -NaN
+     0xfff0000000000000,    // -inf
+     0xc000000000000000,    // -2.0
+     0x800fffffffffffff,    // largest negative subnormal
+     0x8000000000000000,    // -0.0
+     0x0000000000000000,    // +0.0
+     0x0000000000000001,    // smallest subnormal
+     0x4000000000000000,    // 2.0
+     0x7ff0000000000000,    // +inf
+     0x7ff0000000000001,    // +sNaN
+     0x7ff8000000000000]    // +NaN
+        .iter()
+        .map(|&b| TotalFloat::from_bits(b))
+        .collect()
+}
+
+#[test]
+fn full_ordering_chain() {
+    // Every element of the chain is strictly less than every later element
+    let chain = chain();
+    for (i, a) in chain.iter().enumerate() {
+        for (j, b) in chain.iter().enumerate() {
+            assert_eq!(a.ieee_total_cmp(b), i.cmp(&j));
+        }
+    }
+}
+
+#[test]
+fn zeros_are_distinguished() {
+    // Unlike the default ordering, -0.0 is less than +0.0
+    assert_eq!(tf!(-0.0).ieee_total_cmp(&tf!(0.0)), Ordering::Less);
+    assert_eq!(tf!(-0.0).cmp(&tf!(0.0)), Ordering::Equal);
+}
+
+#[test]
+fn nan_signs() {
+    // Negative NaN is least, and positive NaN is greatest
+    let neg_nan = TotalFloat::from_bits(0xfff8000000000000);
+    let pos_nan = TotalFloat::from_bits(0x7ff8000000000000);
+    assert_eq!(neg_nan.ieee_total_cmp(&TotalFloat::NEG_INFINITY),
+               Ordering::Less);
+    assert_eq!(pos_nan.ieee_total_cmp(&TotalFloat::INFINITY),
+               Ordering::Greater);
+    assert_eq!(neg_nan.ieee_total_cmp(&pos_nan), Ordering::Less);
+}
+
+#[test]
+fn agrees_with_std_total_cmp() {
+    // std implements the same predicate for f64
+    for _ in 0..1000 {
+        let a = TotalFloat::from_bits(rand::random::<u64>());
+        let b = TotalFloat::from_bits(rand::random::<u64>());
+        assert_eq!(a.ieee_total_cmp(&b), a.inner.total_cmp(&b.inner));
+    }
+}
+
+#[test]
+fn merge_sort_ieee_sorts_chain() {
+    // Sorting the reversed chain restores it, bit for bit
+    let chain = chain();
+    let mut reversed = chain.clone();
+    reversed.reverse();
+    let sorted = fc_sort::merge_sort_ieee(reversed);
+    assert_eq!(sorted.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+               chain.iter().map(|x| x.to_bits()).collect::<Vec<_>>());
+}
@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort_in_place;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+// Sorts a list in place, returning it for comparison.
+fn sorted(mut list: Vec<TotalFloat>) -> Vec<TotalFloat> {
+    merge_sort_in_place(&mut list);
+    list
+}
+
+#[test]
+fn sort_scenarios() {
+    // The scenarios of merge_sort, run through the slice API
+    assert_eq!(sorted(tfvec![]), tfvec![]);
+    assert_eq!(sorted(tfvec![1.0]), tfvec![1.0]);
+    assert_eq!(sorted(tfvec![2.0, 1.0]), tfvec![1.0, 2.0]);
+    assert_eq!(sorted(tfvec![3.0, 2.0, 5.3, 6.1, 8.4, 1.0]),
+               tfvec![1.0, 2.0, 3.0, 5.3, 6.1, 8.4]);
+    assert_eq!(sorted(tfvec![3.4, 1.2, 8.4, 3.4, 2.3]),
+               tfvec![1.2, 2.3, 3.4, 3.4, 8.4]);
+}
+
+#[test]
+fn special_values() {
+    // Infinities, zeros and NaNs
+    assert_eq!(sorted(tfvec![INF, 4.4, 2.1, N_INF, 5.2, INF]),
+               tfvec![N_INF, 2.1, 4.4, 5.2, INF, INF]);
+    assert_eq!(sorted(tfvec![2.3, -9.0, -0.0, 4.2, 0.0, 9.1]),
+               tfvec![-9.0, -0.0, 0.0, 2.3, 4.2, 9.1]);
+    assert_eq!(sorted(tfvec![3.4, 1.2, 8.4, NAN, 2.3, N_INF]),
+               tfvec![NAN, N_INF, 1.2, 2.3, 3.4, 8.4]);
+    let nan1 = TotalFloat::from_bits(0x7ff800a004001000);
+    let nan2 = TotalFloat::from_bits(0x7ff80090e200a000);
+    assert_eq!(sorted(vec![tf!(2.0), tf!(-4.2), tf!(INF), nan1, nan2]),
+               tfvec![NAN, NAN, -4.2, 2.0, INF]);
+}
+
+#[test]
+fn pseudo_random() {
+    // A random list is sorted, and agrees with the by-value sort
+    let list: Vec<_> = (0..1000).map(|_| tf!(random::<f64>())).collect();
+    let in_place = sorted(list.clone());
+    assert!(in_place.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(in_place, fc_sort::merge_sort(list));
+}
+
+#[test]
+fn sub_slice() {
+    // Only the given part of a larger array is sorted
+    let mut array = [tf!(9.0), tf!(8.0), tf!(NAN), tf!(3.0), tf!(-1.0),
+                     tf!(7.0), tf!(0.0)];
+    merge_sort_in_place(&mut array[1..6]);
+    assert_eq!(array[..], *tfvec![9.0, NAN, -1.0, 3.0, 7.0, 8.0, 0.0]);
+
+    // As is a boxed slice
+    let mut boxed = tfvec![3.0, 1.0, 2.0].into_boxed_slice();
+    merge_sort_in_place(&mut boxed);
+    assert_eq!(*boxed, *tfvec![1.0, 2.0, 3.0]);
+}
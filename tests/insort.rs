@@ -0,0 +1,82 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::insert_sorted;
+use fc_sort::merge;
+use fc_sort::merge_in_place;
+use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn bits(list: &[TotalFloat]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn merge_scenarios() {
+    // Merging in place gives the same values as merge
+    let cases = vec![(tfvec![], tfvec![]),
+                     (tfvec![], tfvec![1.0, 2.0]),
+                     (tfvec![1.0, 2.0], tfvec![]),
+                     (tfvec![1.0, 3.0, 5.0], tfvec![2.0, 4.0]),
+                     (tfvec![4.0, 5.0], tfvec![NAN, 1.0, INF]),
+                     (tfvec![NAN, -INF, 0.0], tfvec![7.0, 8.0])];
+    for (base, batch) in cases {
+        let mut merged = base.clone();
+        merge_in_place(&mut merged, &batch);
+        assert_eq!(bits(&merged), bits(&merge(base, batch)));
+    }
+}
+
+#[test]
+fn merge_keeps_base_first() {
+    // Existing values come before equal new ones
+    let nan = |payload: u64| TotalFloat::from_bits(NAN.to_bits() | payload);
+    let mut base = vec![nan(1), tf!(-0.0), tf!(0.0), tf!(1.0)];
+    merge_in_place(&mut base, &[nan(2), tf!(0.0), tf!(-0.0)]);
+    assert_eq!(bits(&base), bits(&[nan(1), nan(2), tf!(-0.0), tf!(0.0),
+                                   tf!(0.0), tf!(-0.0), tf!(1.0)]));
+}
+
+#[test]
+fn merge_large_base() {
+    // A small batch folded into a large base, repeatedly
+    let mut base = merge_sort((0..10_000).map(|_| tf!(random::<f64>()))
+                                         .collect::<Vec<_>>());
+    let mut all = base.clone();
+    for _ in 0..10 {
+        let batch = merge_sort((0..100).map(|_| tf!(random::<f64>() * 2.0))
+                                       .collect::<Vec<_>>());
+        merge_in_place(&mut base, &batch);
+        all.extend_from_slice(&batch);
+    }
+    assert_eq!(base, merge_sort(all));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "merge_in_place batch is not sorted")]
+fn merge_unsorted_batch() {
+    // Debug builds catch an unsorted batch
+    merge_in_place(&mut tfvec![1.0], &tfvec![3.0, 2.0]);
+}
+
+#[test]
+fn insert() {
+    // Values go after any equal to them
+    let mut list = tfvec![];
+    assert_eq!(insert_sorted(&mut list, tf!(2.0)), 0);
+    assert_eq!(insert_sorted(&mut list, tf!(1.0)), 0);
+    assert_eq!(insert_sorted(&mut list, tf!(3.0)), 2);
+    assert_eq!(insert_sorted(&mut list, tf!(-0.0)), 0);
+    assert_eq!(insert_sorted(&mut list, tf!(0.0)), 1);
+    assert_eq!(insert_sorted(&mut list, tf!(NAN)), 0);
+    assert_eq!(insert_sorted(&mut list, tf!(-NAN)), 1);
+    assert_eq!(list, tfvec![NAN, NAN, -0.0, 0.0, 1.0, 2.0, 3.0]);
+    assert!(list[2].is_sign_negative());
+    assert!(list[1].is_sign_negative());
+}
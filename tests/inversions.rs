@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::count_inversions;
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_counting;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+// Counts inversions by comparing every pair.
+fn brute_force(input: &[TotalFloat]) -> u64 {
+    let mut inversions = 0;
+    for i in 0..input.len() {
+        for j in i + 1..input.len() {
+            if input[i] > input[j] {
+                inversions += 1;
+            }
+        }
+    }
+    inversions
+}
+
+#[test]
+fn sorted_and_reversed() {
+    // Sorted input has none, and reversed input every pair
+    let sorted: Vec<_> = (0..1000).map(|x| tf!(x as f64)).collect();
+    assert_eq!(count_inversions(&sorted), 0);
+    let mut reversed = sorted;
+    reversed.reverse();
+    assert_eq!(count_inversions(&reversed), 1000 * 999 / 2);
+    assert_eq!(count_inversions(&tfvec![]), 0);
+    assert_eq!(count_inversions(&tfvec![1.0]), 0);
+}
+
+#[test]
+fn equal_values() {
+    // Equal values, NaNs and zeros included, are not inversions
+    assert_eq!(count_inversions(&tfvec![NAN, -NAN, NAN]), 0);
+    assert_eq!(count_inversions(&tfvec![0.0, -0.0, 0.0]), 0);
+    assert_eq!(count_inversions(&tfvec![1.0, NAN, 1.0, -INF]), 3);
+}
+
+#[test]
+fn matches_brute_force() {
+    // Small random lists, with many repeated values
+    for _ in 0..200 {
+        let n = random::<u32>() as usize % 40;
+        let list: Vec<_> = (0..n)
+            .map(|_| match random::<u32>() % 8 {
+                0 => tf!(NAN),
+                x => tf!(x as f64),
+            })
+            .collect();
+        let (sorted, inversions) = merge_sort_counting(list.clone());
+        assert_eq!(inversions, brute_force(&list));
+        assert_eq!(count_inversions(&list), inversions);
+        let bits = |list: &[TotalFloat]| -> Vec<u64> {
+            list.iter().map(|x| x.to_bits()).collect()
+        };
+        assert_eq!(bits(&sorted), bits(&merge_sort(list)));
+    }
+}
+
+#[test]
+fn runs_and_gallops() {
+    // Long runs, each way, so the merges gallop and descending runs reverse
+    for _ in 0..20 {
+        let mut list = Vec::new();
+        while list.len() < 1500 {
+            let len = random::<u32>() as usize % 200;
+            let start = random::<u32>() % 1000;
+            let run = (0..len as u32).map(|x| tf!((start + x) as f64));
+            if random() {
+                list.extend(run);
+            } else {
+                list.extend(run.rev());
+            }
+        }
+        let (sorted, inversions) = merge_sort_counting(list.clone());
+        assert_eq!(inversions, brute_force(&list));
+        assert_eq!(sorted, merge_sort(list));
+    }
+}
@@ -0,0 +1,146 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::TotalFloat;
+use fc_sort::TotalIteratorExt;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn empty_iterators() {
+    // Nothing to return
+    let empty: [f64; 0] = [];
+    assert_eq!(empty.iter().total_min(), None);
+    assert_eq!(empty.iter().total_max(), None);
+    assert_eq!(empty.iter().total_minmax(), None);
+    assert_eq!(empty.iter().total_extrema_by_key(|x| **x), None);
+    assert_eq!(Vec::<TotalFloat>::new().into_iter().total_max(), None);
+}
+
+#[test]
+fn all_nan() {
+    // NaN is the only candidate, so is both the least and greatest
+    assert!(vec![NAN].into_iter().total_max().unwrap().is_nan());
+    assert!(vec![NAN, NAN].into_iter().total_min().unwrap().is_nan());
+    let (min, max) = tfvec![NAN, NAN].into_iter().total_minmax().unwrap();
+    assert!(min.is_nan() && max.is_nan());
+}
+
+#[test]
+fn mixed_inputs() {
+    // NaN counts as least
+    let list = [3.0, NAN, -1.0, INF, N_INF, 0.5];
+    assert!(list.iter().total_min().unwrap().is_nan());
+    assert_eq!(list.iter().total_max(), Some(&INF));
+    assert_eq!(vec![NAN, 1.0].into_iter().total_max(), Some(1.0));
+
+    // The same over TotalFloat, in a single pass
+    let (min, max) = tfvec![3.0, -1.0, N_INF, 0.5]
+        .into_iter()
+        .total_minmax()
+        .unwrap();
+    assert_eq!((min, max), (tf!(N_INF), tf!(3.0)));
+}
+
+#[test]
+fn ties() {
+    // The first minimum and last maximum are returned, as for Iterator
+    let list = [(0, 1.0), (1, -0.0), (2, 0.0), (3, 1.0)];
+    let (min, max) = list.iter().total_extrema_by_key(|x| x.1).unwrap();
+    assert_eq!((min.0, max.0), (1, 3));
+    let (min, max) = [NAN, -NAN].iter().total_minmax().unwrap();
+    assert!(min.is_sign_positive() && max.is_sign_negative());
+}
+
+#[test]
+fn extrema_by_key() {
+    // Items are compared by the key, which is computed once per item
+    let words = ["apple", "fig", "banana", "kiwi"];
+    let mut calls = 0;
+    let (shortest, longest) = words.iter()
+        .total_extrema_by_key(|w| {
+            calls += 1;
+            w.len() as f64
+        })
+        .unwrap();
+    assert_eq!((*shortest, *longest), ("fig", "banana"));
+    assert_eq!(calls, words.len());
+
+    // A NaN key counts as least
+    let keyed = [("a", 1.0), ("b", NAN), ("c", 2.0)];
+    let (min, max) = keyed.iter().total_extrema_by_key(|x| x.1).unwrap();
+    assert_eq!((min.0, max.0), ("b", "c"));
+}
+
+#[test]
+fn sorted_in_a_chain() {
+    // filter, then sort, then take, over f64 items
+    let data = [5.0, NAN, -2.0, 8.0, 1.0, -7.0, 3.0];
+    let smallest: Vec<_> = data.iter()
+        .cloned()
+        .filter(|x| !x.is_nan())
+        .sorted_total()
+        .take(3)
+        .collect();
+    assert_eq!(smallest, tfvec![-7.0, -2.0, 1.0]);
+
+    // And over TotalFloat items, by reference
+    let list = tfvec![5.0, NAN, -2.0, 8.0];
+    let sorted: Vec<_> = list.iter().sorted_total().collect();
+    assert_eq!(sorted, tfvec![NAN, -2.0, 5.0, 8.0]);
+}
+
+#[test]
+fn sorted_descending() {
+    // NaNs come last when sorted in descending order
+    let data = [5.0, NAN, -2.0, 8.0, 1.0];
+    let largest: Vec<_> = data.iter()
+        .filter(|x| **x > 0.0 || x.is_nan())
+        .sorted_total_desc()
+        .take(3)
+        .collect();
+    assert_eq!(largest, tfvec![8.0, 5.0, 1.0]);
+    assert!(data.iter().sorted_total_desc().last().unwrap().is_nan());
+}
+
+#[test]
+fn sorted_descending_is_stable() {
+    // Equal values keep their order, rather than being reversed
+    let nan = f64::from_bits(NAN.to_bits() | 1);
+    let data = [NAN, 0.0, nan, -0.0, 1.0];
+    let sorted: Vec<_> = data.iter()
+        .sorted_total_desc()
+        .map(|x| x.to_bits())
+        .collect();
+    let expected: Vec<_> = [1.0, 0.0, -0.0, NAN, nan]
+        .iter()
+        .map(|x| x.to_bits())
+        .collect();
+    assert_eq!(sorted, expected);
+}
+
+#[test]
+fn sorted_by_key() {
+    // Items need not be floats, or even Clone, and equal keys keep order
+    let words = vec![String::from("kiwi"),
+                     String::from("fig"),
+                     String::from("banana"),
+                     String::from("plum"),
+                     String::from("apple")];
+    let sorted: Vec<String> = words.into_iter()
+        .filter(|w| w != "apple")
+        .sorted_total_by_key(|w| w.len() as f64)
+        .take(3)
+        .collect();
+    assert_eq!(sorted, ["fig", "kiwi", "plum"]);
+
+    // A NaN key sorts first
+    let keyed = [("a", 1.0), ("b", NAN), ("c", -1.0)];
+    let order: Vec<_> = keyed.iter()
+        .sorted_total_by_key(|x| x.1)
+        .map(|x| x.0)
+        .collect();
+    assert_eq!(order, ["b", "c", "a"]);
+}
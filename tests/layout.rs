@@ -0,0 +1,135 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::merge_sort;
+use fc_sort::unwrap_vec;
+use fc_sort::wrap_slice;
+use fc_sort::wrap_slice_mut;
+use fc_sort::wrap_vec;
+use fc_sort::TotalFloat;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::mem;
+
+const NAN: f64 = f64::NAN;
+const INF: f64 = f64::INFINITY;
+
+#[test]
+fn same_size_and_alignment() {
+    // TotalFloat must be layout compatible with f64
+    assert_eq!(mem::size_of::<TotalFloat>(), mem::size_of::<f64>());
+    assert_eq!(mem::align_of::<TotalFloat>(), mem::align_of::<f64>());
+    assert_eq!(mem::size_of::<[TotalFloat; 7]>(), mem::size_of::<[f64; 7]>());
+}
+
+#[test]
+fn reference_views() {
+    // Reading through a reinterpreted reference
+    let x = 2.5;
+    assert_eq!(*TotalFloat::from_ref(&x), tf!(2.5));
+
+    // Writing through a reinterpreted mutable reference
+    let mut y = 1.0;
+    *TotalFloat::from_mut(&mut y) += 2.0;
+    assert_eq!(y, 3.0);
+}
+
+#[test]
+fn slice_views() {
+    // The view should have the same length and values as the original
+    let data = [3.0, NAN, -1.0, INF];
+    let view = TotalFloat::slice_from(&data);
+    assert_eq!(view.len(), data.len());
+    assert_eq!(view, &tfvec![3.0, NAN, -1.0, INF][..]);
+
+    // And should point at the same memory
+    assert_eq!(view.as_ptr() as *const f64, data.as_ptr());
+}
+
+#[test]
+fn sort_through_view() {
+    // Sorting a reinterpreted mutable view sorts the original data in place
+    let mut data = vec![3.0, NAN, -1.0, INF, 0.5];
+    TotalFloat::slice_from_mut(&mut data).sort();
+    assert_eq!(data[1..], [-1.0, 0.5, 3.0, INF]);
+    assert!(data[0].is_nan());
+
+    // merge_sort over a copy of a view agrees with the in place sort
+    let data = [3.0, NAN, -1.0, INF, 0.5];
+    let sorted = merge_sort(TotalFloat::slice_from(&data).to_vec());
+    assert_eq!(sorted, tfvec![NAN, -1.0, 0.5, 3.0, INF]);
+}
+
+#[test]
+fn as_ref_and_as_mut() {
+    // Generic code written against AsRef/AsMut accepts TotalFloat
+    fn double<T: AsRef<f64>>(x: T) -> f64 {
+        x.as_ref() * 2.0
+    }
+    fn increment<T: AsMut<f64>>(mut x: T) -> T {
+        *x.as_mut() += 1.0;
+        x
+    }
+    assert_eq!(double(tf!(1.5)), 3.0);
+    assert_eq!(increment(tf!(1.5)), tf!(2.5));
+}
+
+#[test]
+fn set_lookup_by_f64() {
+    // An f64 can be used to probe sets of TotalFloat through a reinterpreted
+    // reference, without constructing a TotalFloat.
+    let btree: BTreeSet<_> = tfvec![1.0, NAN, 2.5].into_iter().collect();
+    let hash: HashSet<_> = tfvec![1.0, NAN, 2.5].into_iter().collect();
+
+    for key in &[1.0, NAN, 2.5] {
+        assert!(btree.contains(TotalFloat::from_ref(key)));
+        assert!(hash.contains(TotalFloat::from_ref(key)));
+    }
+    assert!(!btree.contains(TotalFloat::from_ref(&3.0)));
+    assert!(!hash.contains(TotalFloat::from_ref(&3.0)));
+}
+
+#[test]
+fn wrap_and_unwrap_vec() {
+    // Wrapping reuses the allocation, so the pointer and capacity survive
+    let mut data = Vec::with_capacity(16);
+    data.extend_from_slice(&[3.0, NAN, -1.0]);
+    let ptr = data.as_ptr();
+
+    let wrapped = wrap_vec(data);
+    assert_eq!(wrapped, tfvec![3.0, NAN, -1.0]);
+    assert_eq!(wrapped.capacity(), 16);
+    assert_eq!(wrapped.as_ptr() as *const f64, ptr);
+
+    // As does unwrapping again
+    let unwrapped = unwrap_vec(wrapped);
+    assert_eq!(unwrapped.len(), 3);
+    assert_eq!(unwrapped.capacity(), 16);
+    assert_eq!(unwrapped.as_ptr(), ptr);
+    assert_eq!(unwrapped[2], -1.0);
+}
+
+#[test]
+fn sort_through_wrapped_vec() {
+    // merge_sort consumes the wrapped Vec, and unwrapping gives back f64s
+    let data = vec![3.0, NAN, -1.0, INF, 0.5];
+    let sorted = unwrap_vec(merge_sort(wrap_vec(data)));
+    assert_eq!(sorted[1..], [-1.0, 0.5, 3.0, INF]);
+    assert!(sorted[0].is_nan());
+}
+
+#[test]
+fn sort_through_wrapped_slice() {
+    // Sorting the wrapped view is observable through the original allocation
+    let mut data = vec![3.0, NAN, -1.0, INF, 0.5];
+    let ptr = data.as_ptr();
+    wrap_slice_mut(&mut data).sort();
+    assert_eq!(data[1..], [-1.0, 0.5, 3.0, INF]);
+    assert!(data[0].is_nan());
+    assert_eq!(data.as_ptr(), ptr);
+
+    // The shared view reads the same memory
+    let view = wrap_slice(&data);
+    assert_eq!(view.as_ptr() as *const f64, ptr);
+    assert_eq!(view[4], tf!(INF));
+}
@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate fc_sort;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+#[cfg(feature = "std")]
+fn unary_methods_match_f64() {
+    // Each wrapper should agree with the f64 method it forwards to
+    for &x in &[0.0, -0.0, 0.5, -2.75, 16.0, 1e10, INF, N_INF, NAN] {
+        let t = tf!(x);
+        assert_eq!(t.abs(), x.abs());
+        assert_eq!(t.sqrt(), x.sqrt());
+        assert_eq!(t.exp(), x.exp());
+        assert_eq!(t.ln(), x.ln());
+        assert_eq!(t.log10(), x.log10());
+        assert_eq!(t.floor(), x.floor());
+        assert_eq!(t.ceil(), x.ceil());
+        assert_eq!(t.round(), x.round());
+        assert_eq!(t.trunc(), x.trunc());
+        assert_eq!(t.fract(), x.fract());
+        assert_eq!(t.recip(), x.recip());
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn methods_with_arguments() {
+    assert_eq!(tf!(2.0).powi(10), tf!(1024.0));
+    assert_eq!(tf!(4.0).powf(tf!(0.5)), tf!(2.0));
+    assert_eq!(tf!(2.0).mul_add(tf!(3.0), tf!(1.0)), tf!(7.0));
+    assert!(tf!(-1.0).powf(tf!(0.5)).is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn pipeline_stays_total() {
+    // A chain of methods that, without the wrappers, would have dropped out
+    // to f64 at the first call.
+    let list: Vec<_> = tfvec![16.0, -4.0, 2.25, 0.0]
+        .into_iter()
+        .map(|x| x.sqrt().recip().abs().powi(2))
+        .collect();
+
+    // Sqrt of -4.0 is NaN, so sorts first
+    assert_eq!(fc_sort::merge_sort(list),
+               tfvec![NAN, 1.0 / 16.0, 1.0 / 2.25, INF]);
+}
+
+#[test]
+fn min_and_max_use_total_order() {
+    // NaN never wins max, and always wins min
+    assert_eq!(tf!(NAN).max(tf!(1.0)), tf!(1.0));
+    assert_eq!(tf!(1.0).max(tf!(NAN)), tf!(1.0));
+    assert_eq!(tf!(N_INF).max(tf!(NAN)), tf!(N_INF));
+    assert_eq!(tf!(NAN).min(tf!(1.0)), tf!(NAN));
+    assert_eq!(tf!(1.0).min(tf!(NAN)), tf!(NAN));
+    assert_eq!(tf!(NAN).max(tf!(NAN)), tf!(NAN));
+
+    // Ordinary values
+    assert_eq!(tf!(2.0).max(tf!(3.0)), tf!(3.0));
+    assert_eq!(tf!(2.0).min(tf!(3.0)), tf!(2.0));
+    assert_eq!(tf!(INF).min(tf!(3.0)), tf!(3.0));
+}
+
+#[test]
+fn clamp_to_unit_interval() {
+    let (lo, hi) = (tf!(0.0), tf!(1.0));
+
+    // In range values are unchanged
+    assert_eq!(tf!(0.5).clamp(lo, hi), tf!(0.5));
+
+    // Out of range values, including infinities, are pulled to the bounds
+    assert_eq!(tf!(-3.0).clamp(lo, hi), tf!(0.0));
+    assert_eq!(tf!(3.0).clamp(lo, hi), tf!(1.0));
+    assert_eq!(tf!(INF).clamp(lo, hi), tf!(1.0));
+    assert_eq!(tf!(N_INF).clamp(lo, hi), tf!(0.0));
+
+    // NaN is less than everything, so clamps to lo
+    assert_eq!(tf!(NAN).clamp(lo, hi), tf!(0.0));
+    assert!(!tf!(NAN).clamp(lo, hi).is_nan());
+
+    // -0.0 is equal to lo, so is within the range and returned unchanged
+    assert!(tf!(-0.0).clamp(lo, hi).is_negative_zero());
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn clamp_inverted_bounds() {
+    // lo > hi is caught in debug builds
+    tf!(0.5).clamp(tf!(1.0), tf!(0.0));
+}
+
+#[test]
+fn free_functions_on_f64() {
+    use fc_sort::{total_clamp, total_max, total_min};
+
+    // The same semantics apply to raw f64s
+    assert_eq!(total_max(NAN, 1.0), 1.0);
+    assert_eq!(total_max(1.0, NAN), 1.0);
+    assert!(total_min(NAN, 1.0).is_nan());
+    assert!(total_min(N_INF, NAN).is_nan());
+    assert_eq!(total_min(2.0, 3.0), 2.0);
+    assert_eq!(total_max(2.0, 3.0), 3.0);
+
+    assert_eq!(total_clamp(NAN, 0.0, 1.0), 0.0);
+    assert_eq!(total_clamp(INF, 0.0, 1.0), 1.0);
+    assert_eq!(total_clamp(N_INF, 0.0, 1.0), 0.0);
+    assert_eq!(total_clamp(0.25, 0.0, 1.0), 0.25);
+    assert!(total_clamp(-0.0, 0.0, 1.0).is_sign_negative());
+}
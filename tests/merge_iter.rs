@@ -0,0 +1,159 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::kmerge;
+use fc_sort::merge;
+use fc_sort::merge3;
+use fc_sort::merge_iter;
+use fc_sort::merge_k_tagged;
+use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn nan(payload: u64) -> TotalFloat {
+    TotalFloat::from_bits(NAN.to_bits() | payload)
+}
+
+fn bits<I: IntoIterator<Item = TotalFloat>>(list: I) -> Vec<u64> {
+    list.into_iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn lazy() {
+    // Only as much of the inputs as is needed is taken
+    let evens = (0..).map(|i| tf!(2.0 * i as f64));
+    let odds = (0..).map(|i| tf!(2.0 * i as f64 + 1.0));
+    let first: Vec<_> = merge_iter(evens, odds).take(3).collect();
+    assert_eq!(first, tfvec![0.0, 1.0, 2.0]);
+}
+
+#[test]
+fn matches_merge() {
+    // Forwards, backwards, and from both ends, the values are those of merge
+    let a = vec![nan(1), nan(2), tf!(-0.0), tf!(1.0), tf!(1.0), tf!(INF)];
+    let b = vec![nan(3), tf!(0.0), tf!(0.5), tf!(1.0)];
+    let merged = bits(merge(a.clone(), b.clone()));
+    assert_eq!(bits(merge_iter(a.clone(), b.clone())), merged);
+
+    let mut reversed = bits(merge_iter(a.clone(), b.clone()).rev());
+    reversed.reverse();
+    assert_eq!(reversed, merged);
+
+    for split in 0..merged.len() + 1 {
+        let mut iter = merge_iter(a.clone(), b.clone());
+        let mut front = bits(iter.by_ref().take(split));
+        let mut back = bits(iter.rev());
+        back.reverse();
+        front.append(&mut back);
+        assert_eq!(front, merged);
+    }
+}
+
+#[test]
+fn nan_blocks() {
+    // NaNs from the first input come before those from the second
+    let a = vec![nan(1), nan(2), tf!(1.0)];
+    let b = vec![nan(3), nan(4), tf!(0.0)];
+    let expected = bits(vec![nan(1), nan(2), nan(3), nan(4), tf!(0.0),
+                             tf!(1.0)]);
+    assert_eq!(bits(merge_iter(a.clone(), b.clone())), expected);
+    let mut reversed = bits(merge_iter(a, b).rev());
+    reversed.reverse();
+    assert_eq!(reversed, expected);
+}
+
+#[test]
+fn size_hint() {
+    // The length is exact for exact inputs, and counts values held
+    let mut iter = merge_iter(tfvec![1.0, 2.0], tfvec![0.0]);
+    assert_eq!(iter.len(), 3);
+    iter.next();
+    assert_eq!(iter.len(), 2);
+    iter.next_back();
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(tf!(1.0)));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+
+    let unbounded = merge_iter((0..).map(|i| tf!(i as f64)), tfvec![1.0]);
+    assert_eq!(unbounded.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn kmerge_ranges() {
+    // A hundred ranges, some empty, merge to the sort of their values
+    let ranges: Vec<Vec<TotalFloat>> = (0..100)
+        .map(|i| (0..i % 7).map(|j| tf!((i * j % 13) as f64)).collect())
+        .map(merge_sort)
+        .collect();
+    let all = ranges.concat();
+    let merged: Vec<_> = kmerge(ranges.clone()).collect();
+    assert_eq!(merged, merge_sort(all.clone()));
+    assert_eq!(kmerge(ranges).size_hint(), (all.len(), Some(all.len())));
+
+    // With no inputs, or only empty ones, there is nothing to merge
+    assert_eq!(kmerge(Vec::<Vec<TotalFloat>>::new()).next(), None);
+    assert_eq!(kmerge(vec![tfvec![], tfvec![]]).next(), None);
+}
+
+#[test]
+fn kmerge_stable() {
+    // Equal values, NaNs included, come from earlier inputs first
+    let inputs = vec![vec![nan(1), tf!(-0.0), tf!(2.0)],
+                      vec![],
+                      vec![nan(2), tf!(0.0)],
+                      vec![nan(3), tf!(-0.0), tf!(1.0)]];
+    let expected = bits(vec![nan(1), nan(2), nan(3), tf!(-0.0), tf!(0.0),
+                             tf!(-0.0), tf!(1.0), tf!(2.0)]);
+    assert_eq!(bits(kmerge(inputs)), expected);
+}
+
+#[test]
+fn kmerge_lazy() {
+    // Unbounded inputs can be merged, as only the next value of each is held
+    let multiples = (1..4).map(|k| (0..).map(move |i| tf!((i * k) as f64)));
+    let mut merged = kmerge(multiples);
+    assert_eq!(merged.next(), Some(tf!(0.0)));
+    assert_eq!(merged.size_hint(), (usize::MAX, None));
+    let next: Vec<_> = merged.take(6).collect();
+    assert_eq!(next, tfvec![0.0, 0.0, 1.0, 2.0, 2.0, 3.0]);
+}
+
+#[test]
+fn three_way() {
+    // merge3 matches merging pairwise, whichever list runs out first
+    let lists = [tfvec![NAN, 1.0, 4.0, 4.0],
+                 tfvec![-0.0, 1.0, 2.0],
+                 tfvec![NAN, 0.0, 1.0, 9.0, INF],
+                 tfvec![]];
+    for a in &lists {
+        for b in &lists {
+            for c in &lists {
+                let (a, b, c) = (a.clone(), b.clone(), c.clone());
+                let pairwise = merge(merge(a.clone(), b.clone()), c.clone());
+                assert_eq!(bits(merge3(a, b, c)), bits(pairwise));
+            }
+        }
+    }
+}
+
+#[test]
+fn tagged() {
+    // A value in all three feeds comes from each in turn
+    let feeds = vec![tfvec![1.0, 2.0, 5.0],
+                     tfvec![2.0, 3.0],
+                     tfvec![0.0, 2.0, 2.0, 6.0]];
+    let tags: Vec<(f64, usize)> = merge_k_tagged(feeds.clone())
+        .into_iter()
+        .map(|(x, i)| (x.inner, i))
+        .collect();
+    assert_eq!(tags, [(0.0, 2), (1.0, 0), (2.0, 0), (2.0, 1), (2.0, 2),
+                      (2.0, 2), (3.0, 1), (5.0, 0), (6.0, 2)]);
+    let values: Vec<_> = merge_k_tagged(feeds.clone()).into_iter()
+        .map(|(x, _)| x)
+        .collect();
+    assert_eq!(values, kmerge(feeds).collect::<Vec<_>>());
+    assert_eq!(merge_k_tagged(vec![]), vec![]);
+}
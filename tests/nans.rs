@@ -0,0 +1,154 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::merge_sort_dropping_nans;
+use fc_sort::merge_sort_nan;
+use fc_sort::nan_count;
+use fc_sort::partition_nans;
+use fc_sort::strip_nans_in_place;
+use fc_sort::try_merge;
+use fc_sort::try_merge_sort;
+use fc_sort::NanFound;
+use fc_sort::NanPlacement;
+use fc_sort::NanRejected;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn counting() {
+    // NaNs of any payload are counted
+    assert_eq!(nan_count(&tfvec![]), 0);
+    assert_eq!(nan_count(&tfvec![1.0, INF, -0.0]), 0);
+    assert_eq!(nan_count(&tfvec![NAN, 1.0, -NAN, 2.0]), 2);
+}
+
+#[test]
+fn survivors_keep_their_order() {
+    // The values left are in their original, unsorted, order
+    let list = tfvec![3.0, NAN, -1.0, NAN, 2.0, INF, -NAN];
+    assert_eq!(partition_nans(list.clone()), (tfvec![3.0, -1.0, 2.0, INF], 3));
+
+    let mut list = list;
+    assert_eq!(strip_nans_in_place(&mut list), 3);
+    assert_eq!(list, tfvec![3.0, -1.0, 2.0, INF]);
+}
+
+#[test]
+fn all_nan() {
+    // Every value is removed
+    let list = tfvec![NAN, NAN, -NAN];
+    assert_eq!(partition_nans(list.clone()), (tfvec![], 3));
+    assert_eq!(merge_sort_dropping_nans(list.clone()), (tfvec![], 3));
+    let mut list = list;
+    assert_eq!(strip_nans_in_place(&mut list), 3);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn sorting_without_nans() {
+    // The values left are sorted
+    let list = tfvec![3.0, NAN, -1.0, 0.0, NAN];
+    assert_eq!(merge_sort_dropping_nans(list), (tfvec![-1.0, 0.0, 3.0], 2));
+    assert_eq!(merge_sort_dropping_nans(tfvec![]), (tfvec![], 0));
+}
+
+// A list with NaNs of distinguishable payloads at the start, middle and end.
+fn with_nans() -> Vec<TotalFloat> {
+    vec![TotalFloat::from_bits(NAN.to_bits() | 1),
+         tf!(3.0),
+         tf!(-INF),
+         TotalFloat::from_bits(NAN.to_bits() | 2),
+         tf!(1.0),
+         TotalFloat::from_bits((-NAN).to_bits())]
+}
+
+fn payloads(list: &[TotalFloat]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn placement_first() {
+    // NaNs first, in their original order
+    let list = with_nans();
+    let (sorted, count) = merge_sort_nan(list.clone(), NanPlacement::First)
+        .unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(payloads(&sorted[..3]),
+               payloads(&[list[0], list[3], list[5]]));
+    assert_eq!(sorted[3..], tfvec![-INF, 1.0, 3.0][..]);
+}
+
+#[test]
+fn placement_last() {
+    // NaNs last, in their original order
+    let list = with_nans();
+    let (sorted, count) = merge_sort_nan(list.clone(), NanPlacement::Last)
+        .unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(sorted[..3], tfvec![-INF, 1.0, 3.0][..]);
+    assert_eq!(payloads(&sorted[3..]),
+               payloads(&[list[0], list[3], list[5]]));
+}
+
+#[test]
+fn placement_drop() {
+    // NaNs removed and counted
+    let (sorted, count) = merge_sort_nan(with_nans(), NanPlacement::Drop)
+        .unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(sorted, tfvec![-INF, 1.0, 3.0]);
+}
+
+#[test]
+fn placement_error() {
+    // The first NaN is found wherever it is
+    let list = with_nans();
+    let error = |list: &[TotalFloat]| {
+        merge_sort_nan(list.to_vec(), NanPlacement::Error).unwrap_err()
+    };
+    assert_eq!(error(&list), NanFound { index: 0 });
+    assert_eq!(error(&list[1..]), NanFound { index: 2 });
+    assert_eq!(error(&list[4..]), NanFound { index: 1 });
+    assert_eq!(merge_sort_nan(list[1..3].to_vec(), NanPlacement::Error),
+               Ok((tfvec![-INF, 3.0], 0)));
+}
+
+#[test]
+fn try_clean() {
+    // Input without NaNs is sorted or merged as usual
+    let list = tfvec![3.0, -0.0, -INF, 1.0];
+    assert_eq!(try_merge_sort(list), Ok(tfvec![-INF, -0.0, 1.0, 3.0]));
+    assert_eq!(try_merge_sort(tfvec![]), Ok(tfvec![]));
+    assert_eq!(try_merge(tfvec![1.0, 3.0], tfvec![2.0, INF]),
+               Ok(tfvec![1.0, 2.0, 3.0, INF]));
+}
+
+#[test]
+fn try_nan_first() {
+    // The first NaN is reported, and the input returned untouched
+    let list = with_nans();
+    let error = try_merge_sort(list.clone()).unwrap_err();
+    assert_eq!((error.index, error.bits), (0, list[0].to_bits()));
+    assert_eq!(payloads(&error.input), payloads(&list));
+
+    let error = try_merge(list.clone(), tfvec![1.0]).unwrap_err();
+    assert_eq!((error.index, error.bits), (0, list[0].to_bits()));
+    assert_eq!(payloads(&error.input.0), payloads(&list));
+}
+
+#[test]
+fn try_nan_in_second_input() {
+    // The index counts on from the end of the first input
+    let a = tfvec![-1.0, 2.0];
+    let b = vec![tf!(0.0), TotalFloat::from_bits((-NAN).to_bits())];
+    match try_merge(a.clone(), b.clone()) {
+        Err(NanRejected { index, bits, input }) => {
+            assert_eq!((index, bits), (3, (-NAN).to_bits()));
+            assert_eq!(input.0, a);
+            assert_eq!(payloads(&input.1), payloads(&b));
+        }
+        Ok(_) => panic!("NaN not rejected"),
+    }
+}
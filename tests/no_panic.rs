@@ -0,0 +1,81 @@
+// The no_panic attribute fails to link any function that the optimizer cannot
+// prove panic-free, so these only check anything in release builds:
+// cargo test --release --features no-panic --test no_panic
+#![cfg(all(feature = "no-panic", not(debug_assertions)))]
+
+extern crate fc_sort;
+extern crate no_panic;
+
+use fc_sort::{is_sorted, NanGreatest, RawFloat, TotalFloat, TotalFloat32,
+              TotalFloatWith};
+use no_panic::no_panic;
+use std::cmp::Ordering;
+
+#[no_panic]
+fn cmp(a: TotalFloat, b: TotalFloat) -> Ordering {
+    a.cmp(&b)
+}
+
+#[no_panic]
+fn cmp_nan_greatest(a: TotalFloatWith<NanGreatest>,
+                    b: TotalFloatWith<NanGreatest>)
+                    -> Ordering {
+    a.cmp(&b)
+}
+
+#[no_panic]
+fn cmp32(a: TotalFloat32, b: TotalFloat32) -> Ordering {
+    a.cmp(&b)
+}
+
+#[no_panic]
+fn partial_cmp(a: TotalFloat, b: TotalFloat) -> Option<Ordering> {
+    a.partial_cmp(&b)
+}
+
+#[no_panic]
+fn total_cmp(a: f64, b: f64) -> Ordering {
+    fc_sort::total_cmp(&a, &b)
+}
+
+// The fallback used for float types other than f32 and f64.
+#[no_panic]
+fn default_cmp(a: Wrapped, b: Wrapped) -> Ordering {
+    a.cmp_with_nan(b, Ordering::Less)
+}
+
+#[no_panic]
+fn sorted(list: &[TotalFloat]) -> bool {
+    is_sorted(list)
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+struct Wrapped(f64);
+
+impl RawFloat for Wrapped {
+    type Bits = u64;
+    fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+    fn to_bits(self) -> u64 {
+        self.0.to_bits()
+    }
+    fn from_bits(bits: u64) -> Wrapped {
+        Wrapped(f64::from_bits(bits))
+    }
+}
+
+#[test]
+fn comparisons_link() {
+    // Linking at all is the test, these just keep the functions alive
+    let (one, nan) = (TotalFloat::new(1.0), TotalFloat::NAN);
+    assert_eq!(cmp(nan, one), Ordering::Less);
+    assert_eq!(cmp_nan_greatest(nan.with_policy(), one.with_policy()),
+               Ordering::Greater);
+    assert_eq!(cmp32(TotalFloat32::new(1.0), TotalFloat32::NAN),
+               Ordering::Greater);
+    assert_eq!(partial_cmp(one, one), Some(Ordering::Equal));
+    assert_eq!(total_cmp(-0.0, 0.0), Ordering::Equal);
+    assert_eq!(default_cmp(Wrapped(f64::NAN), Wrapped(1.0)), Ordering::Less);
+    assert!(sorted(&[nan, one]));
+}
@@ -0,0 +1,44 @@
+// Exercises the core API from a crate without std, as the library itself is
+// built. The test harness still needs std, so it is linked explicitly, but
+// nothing here uses it through the prelude.
+#![no_std]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate alloc;
+extern crate std;
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use fc_sort::merge;
+use fc_sort::merge_sort;
+use fc_sort::NotNan;
+use fc_sort::TotalFloat;
+use fc_sort::TotalFloat32;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn sort_without_std() {
+    // Sorting and merging only need alloc
+    let sorted: Vec<TotalFloat> = merge_sort(tfvec![3.0, NAN, -0.0, N_INF]);
+    assert_eq!(sorted, tfvec![NAN, N_INF, -0.0, 3.0]);
+    assert_eq!(merge(tfvec![1.0, 3.0], tfvec![2.0]), tfvec![1.0, 2.0, 3.0]);
+    assert_eq!(merge_sort(tf32vec![2.0, 1.0]), tf32vec![1.0, 2.0]);
+}
+
+#[test]
+fn core_api_without_std() {
+    // Construction, comparison, arithmetic and conversion are all in core
+    assert!(TotalFloat::NAN < TotalFloat::NEG_INFINITY);
+    assert!(tf!(INF) > TotalFloat::MAX);
+    assert_eq!(tf!(1.5) + tf!(2.0), tf!(3.5));
+    assert_eq!(tf!(-2.0).abs(), tf!(2.0));
+    assert_eq!(tf!(4.0).recip(), tf!(0.25));
+    assert_eq!("-inf".parse::<TotalFloat>(), Ok(tf!(N_INF)));
+    assert_eq!(i32::try_from(tf!(7.0)), Ok(7));
+    assert_eq!(TotalFloat::from(TotalFloat32::from(0.5)), tf!(0.5));
+    assert!(NotNan::new(NAN).is_err());
+}
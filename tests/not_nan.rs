@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::{merge_sort_not_nan, NanError, NotNan, TotalFloat};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn nn(x: f64) -> NotNan {
+    NotNan::new(x).unwrap()
+}
+
+#[test]
+fn construction() {
+    // Anything but NaN is accepted
+    assert_eq!(NotNan::new(1.5).map(NotNan::get), Ok(1.5));
+    assert_eq!(NotNan::new(INF).map(NotNan::get), Ok(INF));
+    assert_eq!(NotNan::try_from(-0.0).map(NotNan::get), Ok(-0.0));
+
+    // NaN is rejected, however it was produced
+    assert_eq!(NotNan::new(NAN), Err(NanError));
+    let zero = NotNan::new(0.0).unwrap().get();
+    assert_eq!(NotNan::new(zero / zero), Err(NanError));
+    assert_eq!(NotNan::try_from(f64::from_bits(0xfff80090e200a000)),
+               Err(NanError));
+}
+
+#[test]
+fn checked_arithmetic() {
+    // Ordinary arithmetic succeeds
+    assert_eq!(nn(1.5) + nn(2.0), Ok(nn(3.5)));
+    assert_eq!(nn(1.5) * 2.0, Ok(nn(3.0)));
+    assert_eq!(nn(1.0) / nn(0.0), Ok(nn(INF)));
+    assert_eq!(-nn(2.0), nn(-2.0));
+
+    // Whereas anything producing a NaN surfaces an error
+    assert_eq!(nn(INF) - nn(INF), Err(NanError));
+    assert_eq!(nn(INF) + N_INF, Err(NanError));
+    assert_eq!(nn(0.0) / nn(0.0), Err(NanError));
+    assert_eq!(nn(0.0) * INF, Err(NanError));
+    assert_eq!(nn(INF) % nn(1.0), Err(NanError));
+
+    // And NaN can't sneak in through an f64 operand
+    assert_eq!(nn(1.0) + NAN, Err(NanError));
+}
+
+#[test]
+fn total_ordering_and_hashing() {
+    // Ordering and equality need no special casing
+    assert!(nn(N_INF) < nn(-1.0));
+    assert!(nn(1.0) < nn(INF));
+    assert_eq!(nn(-0.0), nn(0.0));
+
+    // The zeros hash the same, as they are equal
+    let set: HashSet<_> =
+        vec![nn(0.0), nn(-0.0), nn(1.0)].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn conversions() {
+    // NotNan converts infallibly to f64 and to TotalFloat
+    let x = nn(2.5);
+    assert_eq!(f64::from(x), 2.5);
+    assert_eq!(TotalFloat::from(x), tf!(2.5));
+    let t: TotalFloat = nn(-0.0).into();
+    assert!(t.is_negative_zero());
+}
+
+#[test]
+fn sorting() {
+    let list = vec![nn(3.0), nn(INF), nn(-1.0), nn(N_INF), nn(0.0)];
+    assert_eq!(merge_sort_not_nan(list),
+               vec![nn(N_INF), nn(-1.0), nn(0.0), nn(3.0), nn(INF)]);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "num-traits")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate num_traits;
+
+use fc_sort::TotalFloat;
+use num_traits::Float;
+use num_traits::FromPrimitive;
+use num_traits::Num;
+use num_traits::NumCast;
+use num_traits::One;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+// Generic code needing both floating point maths and a total order, which
+// f64 alone cannot satisfy.
+fn sorted_magnitudes<T: Float + Ord>(mut values: Vec<T>) -> (Vec<T>, T) {
+    values.sort();
+    let norm = values.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+    (values, norm)
+}
+
+#[test]
+fn generic_float_and_ord() {
+    // The values sort totally, and Float maths is available on them
+    let (sorted, norm) = sorted_magnitudes(tfvec![4.0, -3.0, 0.0]);
+    assert_eq!(sorted, tfvec![-3.0, 0.0, 4.0]);
+    assert_eq!(norm, tf!(5.0));
+
+    // Including with NaN, which sorts first and makes the norm NaN
+    let (sorted, norm) = sorted_magnitudes(tfvec![1.0, NAN, -1.0]);
+    assert_eq!(sorted, tfvec![NAN, -1.0, 1.0]);
+    assert!(norm.is_nan());
+}
+
+#[test]
+fn identities() {
+    // Zero and One
+    assert_eq!(TotalFloat::zero(), tf!(0.0));
+    assert!(tf!(-0.0).is_zero());
+    assert!(!tf!(NAN).is_zero());
+    assert_eq!(TotalFloat::one(), tf!(1.0));
+}
+
+#[test]
+fn num_and_signed() {
+    // Parsing with a radix, and the signed operations
+    assert_eq!(TotalFloat::from_str_radix("1.5", 10).unwrap(), tf!(1.5));
+    assert!(TotalFloat::from_str_radix("x", 10).is_err());
+    assert_eq!(Signed::abs(&tf!(-2.0)), tf!(2.0));
+    assert_eq!(Signed::signum(&tf!(-2.0)), tf!(-1.0));
+    assert_eq!(Signed::abs_sub(&tf!(1.0), &tf!(3.0)), tf!(0.0));
+    assert!(tf!(2.0).is_positive());
+    assert!(tf!(-2.0).is_negative());
+}
+
+#[test]
+fn primitive_conversions() {
+    // To and from primitives, and casts
+    assert_eq!(TotalFloat::from_i64(-3), Some(tf!(-3.0)));
+    assert_eq!(TotalFloat::from_u8(7), Some(tf!(7.0)));
+    assert_eq!(tf!(2.9).to_i64(), Some(2));
+    assert_eq!(tf!(NAN).to_i64(), None);
+    assert_eq!(tf!(-1.0).to_u32(), None);
+    assert_eq!(<TotalFloat as NumCast>::from(5u16), Some(tf!(5.0)));
+    assert_eq!(num_traits::cast::<_, u8>(tf!(200.0)), Some(200));
+}
+
+#[test]
+fn float_delegates_to_f64() {
+    // Constants and methods give the same values as for f64
+    assert!(<TotalFloat as Float>::nan().is_nan());
+    assert_eq!(<TotalFloat as Float>::infinity(), tf!(INF));
+    assert_eq!(<TotalFloat as Float>::max_value(), tf!(f64::MAX));
+    assert_eq!(Float::hypot(tf!(3.0), tf!(4.0)), tf!(5.0));
+    assert_eq!(Float::powi(tf!(2.0), 10), tf!(1024.0));
+    assert_eq!(Float::integer_decode(tf!(1.0)), Float::integer_decode(1.0));
+    assert_eq!(Float::sin_cos(tf!(0.0)), (tf!(0.0), tf!(1.0)));
+
+    // Float::max follows f64 in ignoring NaN, unlike Ord::max
+    assert_eq!(Float::max(tf!(NAN), tf!(1.0)), tf!(1.0));
+    assert_eq!(Ord::max(tf!(NAN), tf!(1.0)), tf!(1.0));
+    assert_eq!(Float::min(tf!(NAN), tf!(1.0)), tf!(1.0));
+    assert_eq!(Ord::min(tf!(NAN), tf!(1.0)), tf!(NAN));
+}
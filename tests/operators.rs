@@ -0,0 +1,167 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn add_sub_mul_div_rem() {
+    // Each operator should give the same result as the f64 operator
+    assert_eq!(tf!(1.5) + tf!(2.0), tf!(3.5));
+    assert_eq!(tf!(1.5) - tf!(2.0), tf!(-0.5));
+    assert_eq!(tf!(1.5) * tf!(2.0), tf!(3.0));
+    assert_eq!(tf!(1.5) / tf!(2.0), tf!(0.75));
+    assert_eq!(tf!(7.5) % tf!(2.0), tf!(1.5));
+}
+
+#[test]
+fn mixed_operands() {
+    // A TotalFloat on the left can be combined with a plain f64 on the right
+    assert_eq!(tf!(1.5) + 2.0, tf!(3.5));
+    assert_eq!(tf!(1.5) - 2.0, tf!(-0.5));
+    assert_eq!(tf!(1.5) * 2.0, tf!(3.0));
+    assert_eq!(tf!(1.5) / 2.0, tf!(0.75));
+    assert_eq!(tf!(7.5) % 2.0, tf!(1.5));
+}
+
+#[test]
+fn infinity_plus_negative_infinity() {
+    // IEEE says inf + (-inf) is NaN, which as a TotalFloat equals any NaN
+    assert!((tf!(INF) + tf!(N_INF)).is_nan());
+    assert_eq!(TotalFloat::INFINITY + TotalFloat::NEG_INFINITY,
+               TotalFloat::NAN);
+    assert_eq!(tf!(INF) + N_INF, tf!(NAN));
+}
+
+#[test]
+fn zero_divided_by_zero() {
+    // IEEE says 0.0 / 0.0 is NaN
+    assert!((tf!(0.0) / tf!(0.0)).is_nan());
+    assert_eq!(TotalFloat::ZERO / 0.0, TotalFloat::NAN);
+
+    // Whereas a nonzero value divided by zero is a signed infinity
+    assert_eq!(tf!(1.0) / 0.0, tf!(INF));
+    assert_eq!(tf!(-1.0) / 0.0, tf!(N_INF));
+}
+
+#[test]
+fn results_remain_sortable() {
+    // The results of arithmetic are TotalFloats, so NaN results sort first
+    let list = vec![tf!(2.0) * 3.0, tf!(INF) - tf!(INF), tf!(1.0) + 1.0];
+    assert_eq!(fc_sort::merge_sort(list), tfvec![NAN, 2.0, 6.0]);
+}
+
+#[test]
+fn negation() {
+    // Negation flips the sign of finite values and infinities
+    assert_eq!(-tf!(2.5), tf!(-2.5));
+    assert_eq!(-tf!(INF), tf!(N_INF));
+
+    // Negating -0.0 produces +0.0
+    assert!((-tf!(-0.0)).is_sign_positive());
+    assert!((-tf!(0.0)).is_sign_negative());
+
+    // Negating NaN stays NaN
+    assert!((-tf!(NAN)).is_nan());
+}
+
+#[test]
+fn compound_assignment() {
+    // Each compound assignment, with a TotalFloat on the right
+    let mut x = tf!(1.5);
+    x += tf!(2.0);
+    assert_eq!(x, tf!(3.5));
+    x -= tf!(0.5);
+    assert_eq!(x, tf!(3.0));
+    x *= tf!(4.0);
+    assert_eq!(x, tf!(12.0));
+    x /= tf!(8.0);
+    assert_eq!(x, tf!(1.5));
+    x %= tf!(1.0);
+    assert_eq!(x, tf!(0.5));
+
+    // And the same again with a plain f64 on the right
+    let mut x = tf!(1.5);
+    x += 2.0;
+    assert_eq!(x, tf!(3.5));
+    x -= 0.5;
+    assert_eq!(x, tf!(3.0));
+    x *= 4.0;
+    assert_eq!(x, tf!(12.0));
+    x /= 8.0;
+    assert_eq!(x, tf!(1.5));
+    x %= 1.0;
+    assert_eq!(x, tf!(0.5));
+}
+
+#[test]
+fn fold_sum() {
+    // Accumulate a tfvec through a fold using AddAssign
+    let total = tfvec![1.0, 2.5, -0.5, 4.0]
+        .into_iter()
+        .fold(tf!(0.0), |mut acc, x| {
+            acc += x;
+            acc
+        });
+    assert_eq!(total, tf!(7.0));
+
+    // Accumulating mixed signs and a negation
+    let total = tfvec![1.0, 2.0, 3.0]
+        .into_iter()
+        .fold(tf!(0.0), |acc, x| -acc + x);
+    assert_eq!(total, tf!(2.0));
+
+    // A NaN anywhere in the fold poisons the result
+    let total = tfvec![1.0, NAN, 3.0]
+        .into_iter()
+        .fold(tf!(0.0), |mut acc, x| {
+            acc += x;
+            acc
+        });
+    assert_eq!(total, tf!(NAN));
+}
+
+#[test]
+fn sum_and_product() {
+    // By value and by reference
+    let list = tfvec![1.0, 2.0, 3.0, 4.0];
+    assert_eq!(list.iter().sum::<TotalFloat>(), tf!(10.0));
+    assert_eq!(list.iter().product::<TotalFloat>(), tf!(24.0));
+    assert_eq!(list.clone().into_iter().sum::<TotalFloat>(), tf!(10.0));
+    assert_eq!(list.into_iter().product::<TotalFloat>(), tf!(24.0));
+}
+
+#[test]
+fn empty_sum_and_product() {
+    // The empty sum is 0.0 and the empty product is 1.0
+    let list: Vec<TotalFloat> = tfvec![];
+    assert_eq!(list.iter().sum::<TotalFloat>(), tf!(0.0));
+    assert_eq!(list.iter().product::<TotalFloat>(), tf!(1.0));
+}
+
+#[test]
+fn sum_with_specials() {
+    // Infinities behave as they do for f64
+    assert_eq!(tfvec![1.0, INF, 2.0].iter().sum::<TotalFloat>(), tf!(INF));
+    assert_eq!(tfvec![1.0, N_INF].iter().product::<TotalFloat>(), tf!(N_INF));
+
+    // And the invalid operations produce NaN
+    assert_eq!(tfvec![INF, N_INF].iter().sum::<TotalFloat>(), tf!(NAN));
+    assert_eq!(tfvec![INF, 0.0].iter().product::<TotalFloat>(), tf!(NAN));
+
+    // NaN propagates
+    assert_eq!(tfvec![1.0, NAN, 2.0].iter().sum::<TotalFloat>(), tf!(NAN));
+    assert_eq!(tfvec![1.0, NAN, 2.0].iter().product::<TotalFloat>(), tf!(NAN));
+}
+
+#[test]
+fn sum_of_sorted_equals_sum_of_input() {
+    // Integer valued floats are summed exactly regardless of order
+    let list = tfvec![5.0, -3.0, 8.0, 1.0, -7.0, 2.0];
+    let sorted = fc_sort::merge_sort(list.clone());
+    assert_eq!(sorted.iter().sum::<TotalFloat>(),
+               list.iter().sum::<TotalFloat>());
+}
@@ -0,0 +1,43 @@
+extern crate fc_sort;
+
+use fc_sort::sort_options;
+use fc_sort::NonePlacement;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+// Bit patterns of the Some values, with None kept apart.
+fn bits(list: &[Option<f64>]) -> Vec<Option<u64>> {
+    list.iter().map(|x| x.map(f64::to_bits)).collect()
+}
+
+fn input() -> Vec<Option<f64>> {
+    vec![Some(2.0), None, Some(NAN), Some(-0.0), None, Some(-INF),
+         Some(0.0), Some(-NAN), None, Some(1.0)]
+}
+
+#[test]
+fn nones_first() {
+    // Nones lead, before the NaNs
+    assert_eq!(bits(&sort_options(input(), NonePlacement::First)),
+               bits(&[None, None, None, Some(NAN), Some(-NAN), Some(-INF),
+                      Some(-0.0), Some(0.0), Some(1.0), Some(2.0)]));
+}
+
+#[test]
+fn nones_last() {
+    // Nones trail, and NaNs still lead the Somes
+    assert_eq!(bits(&sort_options(input(), NonePlacement::Last)),
+               bits(&[Some(NAN), Some(-NAN), Some(-INF), Some(-0.0),
+                      Some(0.0), Some(1.0), Some(2.0), None, None, None]));
+}
+
+#[test]
+fn nones_dropped() {
+    // Only the Somes are left, NaNs included
+    assert_eq!(bits(&sort_options(input(), NonePlacement::Drop)),
+               bits(&[Some(NAN), Some(-NAN), Some(-INF), Some(-0.0),
+                      Some(0.0), Some(1.0), Some(2.0)]));
+    assert_eq!(sort_options(vec![None, None], NonePlacement::Drop), []);
+    assert_eq!(sort_options(vec![], NonePlacement::First), []);
+}
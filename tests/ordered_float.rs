@@ -0,0 +1,59 @@
+#![cfg(feature = "ordered-float")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate ordered_float;
+
+use fc_sort::merge_sort;
+use fc_sort::NanGreatest;
+use fc_sort::TotalFloat;
+use fc_sort::TotalFloatWith;
+use ordered_float::NotNan;
+use ordered_float::OrderedFloat;
+use std::convert::TryFrom;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn ordered_float_round_trip() {
+    // Values, including NaN payloads and -0.0, survive a round trip
+    let nan = f64::from_bits(0xfff0_0000_0000_0001);
+    for &x in &[1.5, -0.0, INF, N_INF, nan] {
+        let total = TotalFloat::from(OrderedFloat(x));
+        assert_eq!(total.to_bits(), x.to_bits());
+        assert_eq!(OrderedFloat::from(total).0.to_bits(), x.to_bits());
+    }
+}
+
+#[test]
+fn not_nan_conversions() {
+    // NotNan converts into TotalFloat, and back unless the value is NaN
+    let value = NotNan::new(2.5).unwrap();
+    assert_eq!(TotalFloat::from(value), tf!(2.5));
+    assert_eq!(NotNan::try_from(tf!(2.5)), Ok(value));
+    assert!(NotNan::try_from(tf!(NAN)).is_err());
+    assert_eq!(NotNan::try_from(tf!(N_INF)).map(|x| x.into_inner()),
+               Ok(N_INF));
+}
+
+#[test]
+fn nan_sort_position_differs() {
+    // OrderedFloat places NaN greatest, and TotalFloat places it least
+    let mut ordered = vec![OrderedFloat(1.0), OrderedFloat(NAN)];
+    ordered.sort();
+    assert!(ordered[1].0.is_nan());
+
+    let total = merge_sort(ordered.iter()
+        .cloned()
+        .map(TotalFloat::from)
+        .collect());
+    assert!(total[0].is_nan());
+
+    // NanGreatest matches OrderedFloat's placement
+    let greatest = merge_sort(ordered.into_iter()
+        .map(TotalFloatWith::<NanGreatest>::from)
+        .collect());
+    assert!(greatest[1].is_nan());
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "rayon")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_par;
+use fc_sort::merge_sort_par_in_place;
+use fc_sort::merge_sort_par_with_threshold;
+use fc_sort::unwrap_vec;
+use fc_sort::wrap_vec;
+use rand::random;
+
+const NAN: f64 = f64::NAN;
+
+// Random values with plenty of duplicates, zeros of both signs and NaNs with
+// differing payloads, so that any instability shows up in the bits.
+fn random_list(n: usize) -> Vec<f64> {
+    let specials = [0.0, -0.0, 1.0, -1.0];
+    (0..n)
+        .map(|_| match random::<u32>() % 4 {
+            0 => f64::from_bits(NAN.to_bits() | (random::<u64>() % 1024)),
+            1 => specials[random::<u32>() as usize % specials.len()],
+            _ => random::<f64>(),
+        })
+        .collect()
+}
+
+fn bits(list: Vec<f64>) -> Vec<u64> {
+    list.into_iter().map(f64::to_bits).collect()
+}
+
+#[test]
+fn matches_sequential() {
+    // Above the default threshold the sort is parallel
+    for &n in &[0, 1, 1000, 20_000, 100_000] {
+        let list = wrap_vec(random_list(n));
+        let expected = bits(unwrap_vec(merge_sort(list.clone())));
+        assert_eq!(bits(unwrap_vec(merge_sort_par(list))), expected);
+    }
+}
+
+#[test]
+fn matches_sequential_small_threshold() {
+    // Tiny thresholds split and merge all the way down
+    for &threshold in &[0, 1, 2, 3, 7, 64] {
+        for n in 0..100 {
+            let list = wrap_vec(random_list(n));
+            let expected = bits(unwrap_vec(merge_sort(list.clone())));
+            let sorted = merge_sort_par_with_threshold(list, threshold);
+            assert_eq!(bits(unwrap_vec(sorted)), expected);
+        }
+    }
+    let list = wrap_vec(random_list(10_000));
+    let expected = bits(unwrap_vec(merge_sort(list.clone())));
+    let sorted = merge_sort_par_with_threshold(list, 5);
+    assert_eq!(bits(unwrap_vec(sorted)), expected);
+}
+
+#[test]
+fn in_place() {
+    // Sorts a slice in the middle of a larger list
+    let mut list = wrap_vec(random_list(50_000));
+    let expected = merge_sort(list[100..40_000].to_vec());
+    merge_sort_par_in_place(&mut list[100..40_000]);
+    assert_eq!(bits(unwrap_vec(list[100..40_000].to_vec())),
+               bits(unwrap_vec(expected)));
+}
+
+#[test]
+fn all_nan() {
+    // NaNs keep their order whichever thread sorts them
+    let list: Vec<_> = (0..30_000u64)
+        .map(|i| tf!(f64::from_bits(NAN.to_bits() | i)))
+        .collect();
+    assert_eq!(merge_sort_par_with_threshold(list.clone(), 100)
+                   .iter()
+                   .map(|x| x.to_bits())
+                   .collect::<Vec<_>>(),
+               (0..30_000).map(|i| NAN.to_bits() | i).collect::<Vec<_>>());
+}
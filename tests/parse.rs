@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn parse_finite() {
+    // Ordinary numbers parse as they would for f64
+    assert_eq!("1.5".parse::<TotalFloat>(), Ok(tf!(1.5)));
+    assert_eq!("-2e3".parse::<TotalFloat>(), Ok(tf!(-2000.0)));
+    assert_eq!("7".parse::<TotalFloat>(), Ok(tf!(7.0)));
+}
+
+#[test]
+fn parse_special_tokens() {
+    // Each of the special spellings, in a variety of cases
+    for token in &["nan", "NaN", "NAN"] {
+        assert_eq!(token.parse::<TotalFloat>(), Ok(tf!(NAN)));
+    }
+    for token in &["inf", "+inf", "Inf", "infinity", "INFINITY", "+Infinity"] {
+        assert_eq!(token.parse::<TotalFloat>(), Ok(tf!(INF)));
+    }
+    for token in &["-inf", "-INF", "-infinity", "-Infinity"] {
+        assert_eq!(token.parse::<TotalFloat>(), Ok(tf!(N_INF)));
+    }
+}
+
+#[test]
+fn parse_error_preserves_input() {
+    // The error should hold on to the offending input
+    let err = "1.2.3".parse::<TotalFloat>().unwrap_err();
+    assert_eq!(err.input(), "1.2.3");
+
+    let err = "infinit".parse::<TotalFloat>().unwrap_err();
+    assert_eq!(err.input(), "infinit");
+
+    let err = "".parse::<TotalFloat>().unwrap_err();
+    assert_eq!(err.input(), "");
+}
+
+#[test]
+fn display_round_trip() {
+    // Formatting and then parsing should produce an equal TotalFloat
+    for &x in &[0.0, -0.0, 1.5, -3.25, 1e300, 5e-324, INF, N_INF, NAN] {
+        let x = tf!(x);
+        let parsed = format!("{}", x).parse::<TotalFloat>().unwrap();
+        assert_eq!(parsed, x);
+        assert_eq!(parsed.is_sign_negative(), x.is_sign_negative());
+    }
+}
+
+#[test]
+fn negative_zero_round_trip() {
+    // Negative zero keeps its sign through a round trip
+    let parsed = format!("{}", tf!(-0.0)).parse::<TotalFloat>().unwrap();
+    assert_eq!(parsed, tf!(0.0));
+    assert!(parsed.is_sign_negative());
+}
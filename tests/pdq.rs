@@ -0,0 +1,92 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::is_sorted;
+use fc_sort::merge_sort;
+use fc_sort::pdq_sort;
+use fc_sort::wrap_vec;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+// Sorts with both algorithms, checking both results are sorted, equal under
+// the total order, and hold the same values bit for bit.
+fn check(list: Vec<TotalFloat>) {
+    let mut sorted = list.clone();
+    pdq_sort(&mut sorted);
+    let expected = merge_sort(list);
+    assert!(is_sorted(&sorted));
+    assert_eq!(sorted, expected);
+    let bits = |list: &[TotalFloat]| {
+        let mut bits: Vec<u64> = list.iter().map(|x| x.to_bits()).collect();
+        bits.sort();
+        bits
+    };
+    assert_eq!(bits(&sorted), bits(&expected));
+}
+
+// Random values with plenty of duplicates, zeros of both signs, infinities
+// and NaNs with differing payloads.
+fn random_list(n: usize) -> Vec<TotalFloat> {
+    let specials = [0.0, -0.0, 1.0, -1.0, INF, -INF];
+    wrap_vec((0..n)
+        .map(|_| match random::<u32>() % 4 {
+            0 => f64::from_bits(NAN.to_bits() | (random::<u64>() % 16)),
+            1 => specials[random::<u32>() as usize % specials.len()],
+            _ => random::<f64>(),
+        })
+        .collect())
+}
+
+#[test]
+fn random_inputs() {
+    // Every short length, where insertion sort and the pivots meet, and some
+    // long ones
+    for n in 0..200 {
+        check(random_list(n));
+    }
+    for &n in &[1000, 10_000, 100_000] {
+        check(random_list(n));
+    }
+}
+
+#[test]
+fn all_equal() {
+    // Equal values, including NaNs with different payloads
+    for &n in &[0, 1, 50, 10_000] {
+        check(vec![TotalFloat::new(1.0); n]);
+        check((0..n as u64)
+            .map(|i| TotalFloat::from_bits(NAN.to_bits() | (i % 7)))
+            .collect());
+    }
+}
+
+#[test]
+fn patterns() {
+    // Inputs that trip up naive pivot choices
+    for &n in &[10, 100, 1000, 10_000] {
+        let ascending: Vec<_> = (0..n).map(|i| TotalFloat::new(i as f64))
+            .collect();
+        let mut descending = ascending.clone();
+        descending.reverse();
+        // Organ pipe, up then down
+        let mut organ_pipe = ascending[..n / 2].to_vec();
+        organ_pipe.extend(descending[n / 2..].iter());
+        // Sawtooth, and few distinct values
+        let sawtooth: Vec<_> = (0..n)
+            .map(|i| TotalFloat::new((i % 17) as f64))
+            .collect();
+        let few: Vec<_> = (0..n)
+            .map(|i| TotalFloat::new((i * 7 % 3) as f64))
+            .collect();
+        // Sorted but for a value at each end
+        let mut nearly = ascending.clone();
+        nearly.swap(0, n - 1);
+        let lists = [ascending, descending, organ_pipe, sawtooth, few, nearly];
+        for list in lists.iter() {
+            check(list.clone());
+        }
+    }
+}
@@ -0,0 +1,85 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::{merge_sort, NanGreatest, NanLeast, TotalFloat, TotalFloatWith};
+use std::cmp::Ordering;
+use std::mem;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+type NanLast = TotalFloatWith<NanGreatest>;
+
+fn nan_last(list: &[f64]) -> Vec<NanLast> {
+    list.iter().map(|&x| NanLast::from(x)).collect()
+}
+
+#[test]
+fn default_policy_is_nan_least() {
+    // TotalFloat is the NanLeast flavour, and is what TotalFloatWith defaults
+    // to when no policy is given.
+    let a: TotalFloatWith = tf!(1.0);
+    let b: TotalFloatWith<NanLeast> = a;
+    assert_eq!(b, tf!(1.0));
+    assert!(TotalFloat::NAN < TotalFloat::NEG_INFINITY);
+}
+
+#[test]
+fn nan_greatest_ordering() {
+    // NaN is greater than everything, including infinity
+    assert!(NanLast::NAN > NanLast::INFINITY);
+    assert!(NanLast::NAN > NanLast::from(0.0));
+    assert_eq!(NanLast::NAN.cmp(&NanLast::from(-NAN)), Ordering::Equal);
+
+    // Other values are unaffected
+    assert!(NanLast::NEG_INFINITY < NanLast::from(0.0));
+    assert_eq!(NanLast::from(-0.0), NanLast::from(0.0));
+
+    // Including in cross-type comparisons
+    assert!(NanLast::from(INF) < NAN);
+    assert!(NAN > NanLast::from(INF));
+}
+
+#[test]
+fn sort_under_both_policies() {
+    let data = [3.0, NAN, -1.0, INF, NAN, N_INF, 0.0];
+
+    // The NaN block is at the start under NanLeast
+    let least = merge_sort(data.iter().map(|&x| tf!(x)).collect());
+    assert_eq!(least, tfvec![NAN, NAN, N_INF, -1.0, 0.0, 3.0, INF]);
+    assert!(least[..2].iter().all(|x| x.is_nan()));
+
+    // And at the end under NanGreatest
+    let greatest = merge_sort(nan_last(&data));
+    assert_eq!(greatest,
+               nan_last(&[N_INF, -1.0, 0.0, 3.0, INF, NAN, NAN]));
+    assert!(greatest[5..].iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn policy_aware_helpers() {
+    // min, max, clamp and signum all follow the policy
+    assert_eq!(NanLast::NAN.max(NanLast::from(1.0)), NanLast::NAN);
+    assert_eq!(NanLast::NAN.min(NanLast::from(1.0)), NanLast::from(1.0));
+    assert_eq!(NanLast::NAN.clamp(NanLast::ZERO, NanLast::from(1.0)),
+               NanLast::from(1.0));
+    assert_eq!(NanLast::NAN.signum_total(), NanLast::from(1.0));
+    assert_eq!(NanLast::NAN.total_bits_key(), u64::MAX);
+    assert!(NanLast::INFINITY.total_bits_key() < u64::MAX);
+}
+
+#[test]
+fn conversions_are_free() {
+    // The policy takes up no space
+    assert_eq!(mem::size_of::<NanLast>(), mem::size_of::<f64>());
+    assert_eq!(mem::size_of::<NanLast>(), mem::size_of::<TotalFloat>());
+
+    // Converting preserves the bit pattern exactly
+    let nan = TotalFloat::from_bits(0x7ff800a004001000);
+    let converted: NanLast = nan.into();
+    assert_eq!(converted.to_bits(), 0x7ff800a004001000);
+    let back: TotalFloat = converted.into();
+    assert_eq!(back.to_bits(), 0x7ff800a004001000);
+    assert_eq!(tf!(2.5).with_policy::<NanGreatest>(), NanLast::from(2.5));
+}
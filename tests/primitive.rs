@@ -0,0 +1,84 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::sort_f32;
+use fc_sort::sort_f32_desc;
+use fc_sort::sort_f64;
+use fc_sort::sort_f64_desc;
+use fc_sort::total_cmp;
+use rand::random;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+// Sorts a list as f64, returning the bit patterns for comparison, so that the
+// NaNs and zeros can be told apart.
+fn sorted(mut list: Vec<f64>, desc: bool) -> Vec<u64> {
+    if desc { sort_f64_desc(&mut list) } else { sort_f64(&mut list) }
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+fn bits(list: &[f64]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn sort_scenarios() {
+    // The scenarios of merge_sort, on plain f64
+    assert_eq!(sorted(vec![], false), bits(&[]));
+    assert_eq!(sorted(vec![1.0], false), bits(&[1.0]));
+    assert_eq!(sorted(vec![2.0, 1.0], false), bits(&[1.0, 2.0]));
+    assert_eq!(sorted(vec![3.4, 1.2, 8.4, 3.4, 2.3], false),
+               bits(&[1.2, 2.3, 3.4, 3.4, 8.4]));
+    assert_eq!(sorted(vec![INF, 4.4, 2.1, N_INF, 5.2, INF], false),
+               bits(&[N_INF, 2.1, 4.4, 5.2, INF, INF]));
+}
+
+#[test]
+fn special_values() {
+    // NaNs first, zeros in their original order, and the reverse descending
+    let list = vec![0.0, -NAN, 3.4, -0.0, N_INF, NAN, 0.0, INF];
+    assert_eq!(sorted(list.clone(), false),
+               bits(&[-NAN, NAN, N_INF, 0.0, -0.0, 0.0, 3.4, INF]));
+    assert_eq!(sorted(list, true),
+               bits(&[INF, 3.4, 0.0, -0.0, 0.0, N_INF, -NAN, NAN]));
+}
+
+#[test]
+fn pseudo_random() {
+    // A large random list, sorted in its own allocation
+    let mut list: Vec<f64> = (0..10_000).map(|_| random::<f64>() - 0.5)
+                                        .collect();
+    let mut expected = list.clone();
+    expected.sort_by(total_cmp);
+    let ptr = list.as_ptr();
+    sort_f64(&mut list);
+    assert_eq!(list.as_ptr(), ptr);
+    assert_eq!(list, expected);
+
+    sort_f64_desc(&mut list);
+    expected.reverse();
+    assert_eq!(list, expected);
+}
+
+#[test]
+fn f32_slices() {
+    // The same on plain f32
+    let mut list = [0.0, -f32::NAN, 3.4, -0.0, f32::NEG_INFINITY, 0.0];
+    let ptr = list.as_ptr();
+    sort_f32(&mut list);
+    assert_eq!(list.as_ptr(), ptr);
+    let bits32 = |list: &[f32]| -> Vec<u32> {
+        list.iter().map(|x| x.to_bits()).collect()
+    };
+    assert_eq!(bits32(&list),
+               bits32(&[-f32::NAN, f32::NEG_INFINITY, 0.0, -0.0, 0.0, 3.4]));
+    sort_f32_desc(&mut list);
+    assert_eq!(bits32(&list),
+               bits32(&[3.4, 0.0, -0.0, 0.0, f32::NEG_INFINITY, -f32::NAN]));
+
+    let mut list: Vec<f32> = (0..10_000).map(|_| random::<f32>()).collect();
+    sort_f32(&mut list);
+    assert!(list.windows(2).all(|w| w[0] <= w[1]));
+}
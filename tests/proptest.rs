@@ -0,0 +1,150 @@
+#![cfg(feature = "proptest")]
+
+extern crate fc_sort;
+#[macro_use]
+extern crate proptest;
+
+use fc_sort::merge_sort;
+use fc_sort::sort_unique;
+use fc_sort::sorted_difference;
+use fc_sort::sorted_intersection;
+use fc_sort::sorted_is_subset;
+use fc_sort::sorted_symmetric_difference;
+use fc_sort::sorted_union;
+use fc_sort::proptest_support::finite_total_float;
+use fc_sort::proptest_support::total_float;
+use fc_sort::proptest_support::total_float_vec;
+use fc_sort::total_cmp;
+use fc_sort::unwrap_vec;
+use fc_sort::wrap_vec;
+use fc_sort::TotalFloat;
+use proptest::collection::vec;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+// A strategy for sorted lists of few distinct values, so that there are many
+// duplicates, including NaNs and both zeros.
+fn small_sorted_vec() -> impl Strategy<Value = Vec<TotalFloat>> {
+    let values = [f64::NAN, -f64::NAN, -0.0, 0.0, 1.0, 2.0, f64::INFINITY];
+    vec((0..values.len()).prop_map(move |i| TotalFloat::new(values[i])), 0..40)
+        .prop_map(merge_sort)
+}
+
+// The number of times each value appears in a list.
+fn counts(list: &[TotalFloat]) -> BTreeMap<TotalFloat, usize> {
+    let mut counts = BTreeMap::new();
+    for x in list {
+        *counts.entry(*x).or_insert(0) += 1;
+    }
+    counts
+}
+
+// A sorted list with each value repeated the given number of times.
+fn from_counts<I>(counts: I) -> Vec<TotalFloat>
+    where I: IntoIterator<Item = (TotalFloat, usize)>
+{
+    counts.into_iter()
+        .flat_map(|(x, n)| std::iter::repeat_n(x, n))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn sort_is_idempotent(v in total_float_vec(0..200)) {
+        // Sorting a sorted list leaves it as it was
+        let once = merge_sort(v);
+        prop_assert_eq!(merge_sort(once.clone()), once);
+    }
+
+    #[test]
+    fn agrees_with_sort_by_total_cmp(v in total_float_vec(0..200)) {
+        // merge_sort and the standard sort give equal output
+        let mut expected = unwrap_vec(v.clone());
+        expected.sort_by(total_cmp);
+        prop_assert_eq!(merge_sort(v), wrap_vec(expected));
+    }
+
+    #[test]
+    fn agrees_bitwise_with_stable_sort(v in total_float_vec(0..200)) {
+        // merge_sort is stable, so equal NaNs and zeros keep their order
+        let bits = |list: &[f64]| -> Vec<u64> {
+            list.iter().map(|x| x.to_bits()).collect()
+        };
+        let mut expected = unwrap_vec(v.clone());
+        expected.sort_by(total_cmp);
+        prop_assert_eq!(bits(&unwrap_vec(merge_sort(v))), bits(&expected));
+    }
+
+    #[test]
+    fn set_ops_agree_with_btree_set(a in small_sorted_vec(),
+                                    b in small_sorted_vec()) {
+        // Without duplicates, the operations are those of BTreeSet
+        let (a, b) = (sort_unique(a), sort_unique(b));
+        let set_a: BTreeSet<_> = a.iter().cloned().collect();
+        let set_b: BTreeSet<_> = b.iter().cloned().collect();
+        let collect = |set: Vec<&TotalFloat>| -> Vec<TotalFloat> {
+            set.into_iter().cloned().collect()
+        };
+        prop_assert_eq!(sorted_union(&a, &b),
+                        collect(set_a.union(&set_b).collect()));
+        prop_assert_eq!(sorted_intersection(&a, &b),
+                        collect(set_a.intersection(&set_b).collect()));
+        prop_assert_eq!(sorted_difference(&a, &b),
+                        collect(set_a.difference(&set_b).collect()));
+        prop_assert_eq!(sorted_symmetric_difference(&a, &b),
+                        collect(set_a.symmetric_difference(&set_b).collect()));
+        prop_assert_eq!(sorted_is_subset(&a, &b), set_a.is_subset(&set_b));
+    }
+
+    #[test]
+    fn set_ops_count_as_multisets(a in small_sorted_vec(),
+                                  b in small_sorted_vec()) {
+        // With duplicates, counts combine by max, min and difference
+        let (count_a, count_b) = (counts(&a), counts(&b));
+        let count = |counts: &BTreeMap<TotalFloat, usize>, x| {
+            counts.get(x).cloned().unwrap_or(0)
+        };
+        let keys: BTreeSet<_> = count_a.keys().chain(count_b.keys()).collect();
+        let combine = |f: fn(usize, usize) -> usize| {
+            from_counts(keys.iter()
+                .map(|x| (**x, f(count(&count_a, x), count(&count_b, x)))))
+        };
+        prop_assert_eq!(sorted_union(&a, &b), combine(|x, y| x.max(y)));
+        prop_assert_eq!(sorted_intersection(&a, &b), combine(|x, y| x.min(y)));
+        prop_assert_eq!(sorted_difference(&a, &b),
+                        combine(|x, y| x.saturating_sub(y)));
+        prop_assert_eq!(sorted_symmetric_difference(&a, &b),
+                        combine(|x, y| x.max(y) - x.min(y)));
+        prop_assert_eq!(sorted_is_subset(&a, &b),
+                        keys.iter().all(|x| count(&count_a, x) <=
+                                            count(&count_b, x)));
+    }
+
+    #[test]
+    fn finite_values_are_finite(x in finite_total_float()) {
+        // The finite strategy never gives an infinity or a NaN
+        prop_assert!(x.is_finite());
+    }
+
+    #[test]
+    fn any_value_is_its_own_equal(x in total_float()) {
+        // Every bit pattern, including NaN, is reflexively equal
+        prop_assert_eq!(x, x);
+    }
+}
+
+#[test]
+fn shrinks_towards_zero() {
+    // Fully simplifying a value tree ends at 0.0, and an empty list
+    let mut runner = TestRunner::deterministic();
+    let mut value = total_float().new_tree(&mut runner).unwrap();
+    while value.simplify() {}
+    assert_eq!(value.current().to_bits(), 0);
+
+    let mut list = total_float_vec(0..10).new_tree(&mut runner).unwrap();
+    while list.simplify() {}
+    assert!(list.current().is_empty());
+}
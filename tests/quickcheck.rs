@@ -0,0 +1,68 @@
+#![cfg(feature = "quickcheck")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate quickcheck;
+
+use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
+use quickcheck::Arbitrary;
+use quickcheck::Gen;
+use quickcheck::QuickCheck;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+// merge_sort output is ordered, and is a permutation of the input (compared
+// by bit pattern, so that distinct NaNs and zeros are kept apart).
+fn sorted_permutation(input: Vec<TotalFloat>) -> bool {
+    let sorted = merge_sort(input.clone());
+    let ordered = sorted.windows(2).all(|w| w[0] <= w[1]);
+    let mut before: Vec<u64> = input.iter().map(|x| x.to_bits()).collect();
+    let mut after: Vec<u64> = sorted.iter().map(|x| x.to_bits()).collect();
+    before.sort();
+    after.sort();
+    ordered && before == after
+}
+
+#[test]
+fn merge_sort_property() {
+    // Checked against many generated lists
+    QuickCheck::new()
+        .tests(500)
+        .quickcheck(sorted_permutation as fn(Vec<TotalFloat>) -> bool);
+}
+
+#[test]
+fn generates_special_values() {
+    // Each of the special kinds of value turns up in a modest sample
+    let mut g = Gen::new(100);
+    let sample: Vec<TotalFloat> =
+        (0..2000).map(|_| TotalFloat::arbitrary(&mut g)).collect();
+    assert!(sample.iter().any(|x| x.is_nan() && x.is_sign_negative()));
+    assert!(sample.iter().any(|x| x.is_nan() && x.is_sign_positive()));
+    assert!(sample.iter().any(|x| x.to_bits() == (-0.0f64).to_bits()));
+    assert!(sample.iter().any(|x| x.to_bits() == 0));
+    assert!(sample.iter().any(|x| x.is_subnormal()));
+    assert!(sample.iter().any(|x| x.inner == INF));
+    assert!(sample.iter().any(|x| x.inner == -INF));
+    assert!(sample.iter().any(|x| x.is_normal()));
+}
+
+#[test]
+fn shrinking() {
+    // NaN shrinks to the canonical NaN, which does not shrink further
+    let nan = TotalFloat::from_bits(0xfff0_0000_0000_0001);
+    let shrunk: Vec<_> = nan.shrink().collect();
+    assert_eq!(shrunk.len(), 1);
+    assert_eq!(shrunk[0].to_bits(), NAN.to_bits());
+    assert_eq!(shrunk[0].shrink().count(), 0);
+
+    // Infinities and -0.0 shrink to 0.0, which does not shrink further
+    assert_eq!(tf!(INF).shrink().collect::<Vec<_>>(), tfvec![0.0]);
+    assert_eq!(tf!(-0.0).shrink().next().map(|x| x.to_bits()), Some(0));
+    assert_eq!(tf!(0.0).shrink().count(), 0);
+
+    // Finite values shrink towards zero
+    assert!(tf!(1234.5).shrink().all(|x| x.abs() < 1234.5));
+}
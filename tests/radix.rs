@@ -0,0 +1,70 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort;
+use fc_sort::radix_sort;
+use fc_sort::NanGreatest;
+use fc_sort::TotalFloat;
+use fc_sort::TotalFloatWith;
+use rand::random;
+
+const NAN: f64 = f64::NAN;
+
+fn bits<P: fc_sort::NanPolicy>(list: &[TotalFloatWith<P>]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+// Random bit patterns, so that every class of value turns up, with a bias
+// towards NaNs, zeros, subnormals and duplicates that random bits rarely give.
+fn random_list(n: usize) -> Vec<TotalFloat> {
+    (0..n)
+        .map(|_| {
+            let bits = match random::<u32>() % 6 {
+                0 => NAN.to_bits() | (random::<u64>() % 64),
+                1 => (random::<u64>() % 2) << 63,
+                2 => (random::<u64>() >> 12) | ((random::<u64>() % 2) << 63),
+                3 => (random::<u64>() % 8).wrapping_mul(0x0123_4567_89ab_cdef),
+                _ => random(),
+            };
+            TotalFloat::from_bits(bits)
+        })
+        .collect()
+}
+
+#[test]
+fn matches_merge_sort() {
+    // Bit for bit, so equal values keep their order as well
+    for n in 0..100 {
+        let list = random_list(n);
+        assert_eq!(bits(&radix_sort(list.clone())), bits(&merge_sort(list)));
+    }
+    for &n in &[1000, 100_000] {
+        let list = random_list(n);
+        assert_eq!(bits(&radix_sort(list.clone())), bits(&merge_sort(list)));
+    }
+}
+
+#[test]
+fn nan_greatest() {
+    // NaNs sorted last under that policy, still in their original order
+    let list: Vec<TotalFloatWith<NanGreatest>> = random_list(10_000)
+        .into_iter()
+        .map(|x| x.with_policy())
+        .collect();
+    assert_eq!(bits(&radix_sort(list.clone())), bits(&merge_sort(list)));
+}
+
+#[test]
+fn shared_bytes() {
+    // Keys differing in a single byte, so that the other passes are skipped
+    for byte in 0..8 {
+        let list: Vec<TotalFloat> = (0..1000)
+            .map(|_| {
+                let digit = (random::<u64>() % 256) << (8 * byte);
+                let bits = 1.5f64.to_bits() ^ digit;
+                TotalFloat::from_bits(bits)
+            })
+            .collect();
+        assert_eq!(bits(&radix_sort(list.clone())), bits(&merge_sort(list)));
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "rand")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort;
+use fc_sort::random_tfvec;
+use fc_sort::NanGreatest;
+use fc_sort::TotalFloat;
+use fc_sort::TotalFloat32;
+use fc_sort::TotalFloatWith;
+use rand::distr::Distribution;
+use rand::distr::Uniform;
+use rand::RngExt;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn standard_distribution() {
+    // Standard samples lie in [0, 1) for both widths and under both policies
+    let mut rng = rand::rng();
+    for _ in 0..1000 {
+        let x: TotalFloat = rng.random();
+        assert!(tf!(0.0) <= x && x < tf!(1.0));
+        let y: TotalFloat32 = rng.random();
+        assert!(tf32!(0.0) <= y && y < tf32!(1.0));
+        let z: TotalFloatWith<NanGreatest> = rng.random();
+        assert!(!z.is_nan());
+    }
+}
+
+#[test]
+fn random_range_respects_bounds() {
+    // Samples are never NaN, and lie within the bounds under the total order
+    let mut rng = rand::rng();
+    for _ in 0..1000 {
+        let x = rng.random_range(tf!(-2.5)..tf!(-0.0));
+        assert!(!x.is_nan());
+        assert!(tf!(-2.5) <= x && x <= tf!(-0.0));
+
+        let y = rng.random_range(tf!(1.0)..=tf!(1.5));
+        assert!(tf!(1.0) <= y && y <= tf!(1.5));
+    }
+}
+
+#[test]
+fn uniform_distribution() {
+    // A reusable Uniform distribution, including with borrowed bounds
+    let bounds = tfvec![10.0, 20.0];
+    let (low, high) = (&bounds[0], &bounds[1]);
+    let uniform = Uniform::new(low, high).unwrap();
+    let mut rng = rand::rng();
+    assert!(uniform.sample_iter(&mut rng)
+        .take(1000)
+        .all(|x: TotalFloat| *low <= x && x <= *high));
+
+    // NaN and non-finite bounds are rejected
+    assert!(Uniform::new(tf!(NAN), tf!(1.0)).is_err());
+    assert!(Uniform::new(tf!(0.0), tf!(INF)).is_err());
+    assert!(Uniform::new(tf!(1.0), tf!(0.0)).is_err());
+}
+
+#[test]
+fn random_list_sorts() {
+    // random_tfvec gives the requested number of values, which sort
+    let list = random_tfvec(&mut rand::rng(), 100);
+    assert_eq!(list.len(), 100);
+    let list = merge_sort(list);
+    assert!(list.iter().zip(list.iter().skip(1)).all(|(a, b)| a <= b));
+}
@@ -0,0 +1,38 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::ranks;
+use fc_sort::RankMethod;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn each_method() {
+    // Ties between the 3.0s, with the NaN ranked least
+    let data = tfvec![3.0, 1.0, 3.0, NAN];
+    assert_eq!(ranks(&data, RankMethod::Min), [3.0, 2.0, 3.0, 1.0]);
+    assert_eq!(ranks(&data, RankMethod::Max), [4.0, 2.0, 4.0, 1.0]);
+    assert_eq!(ranks(&data, RankMethod::Dense), [3.0, 2.0, 3.0, 1.0]);
+    assert_eq!(ranks(&data, RankMethod::Ordinal), [3.0, 2.0, 4.0, 1.0]);
+    assert_eq!(ranks(&data, RankMethod::Average), [3.5, 2.0, 3.5, 1.0]);
+}
+
+#[test]
+fn nans_and_zeros_tie() {
+    // NaNs of any payload share a rank, as do -0.0 and +0.0
+    let data = tfvec![INF, -NAN, 0.0, NAN, -0.0, 5.0];
+    assert_eq!(ranks(&data, RankMethod::Average),
+               [6.0, 1.5, 3.5, 1.5, 3.5, 5.0]);
+    assert_eq!(ranks(&data, RankMethod::Dense),
+               [4.0, 1.0, 2.0, 1.0, 2.0, 3.0]);
+    assert_eq!(ranks(&data, RankMethod::Ordinal),
+               [6.0, 1.0, 3.0, 2.0, 4.0, 5.0]);
+}
+
+#[test]
+fn empty_and_single() {
+    // Trivial inputs
+    assert!(ranks(&tfvec![], RankMethod::Average).is_empty());
+    assert_eq!(ranks(&tfvec![7.0], RankMethod::Max), [1.0]);
+}
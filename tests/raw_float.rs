@@ -0,0 +1,111 @@
+extern crate fc_sort;
+
+use fc_sort::merge_sort;
+use fc_sort::NanGreatest;
+use fc_sort::NanLeast;
+use fc_sort::NanPolicy;
+use fc_sort::RawFloat;
+use fc_sort::TotalFloatWith;
+use std::cmp::Ordering;
+
+// A minimal user-defined IEEE 754 half precision float, stored as its bits.
+#[derive(Copy, Clone, Debug)]
+struct Half(u16);
+
+impl Half {
+    const NAN: Half = Half(0x7e00);
+    const INFINITY: Half = Half(0x7c00);
+    const NEG_INFINITY: Half = Half(0xfc00);
+
+    // Widens to f32, which can represent every half exactly.
+    fn to_f32(self) -> f32 {
+        let sign = if self.0 & 0x8000 == 0 { 1.0 } else { -1.0 };
+        let exponent = i32::from((self.0 >> 10) & 0x1f);
+        let mantissa = f32::from(self.0 & 0x3ff);
+        match exponent {
+            0 => sign * mantissa * 2f32.powi(-24),
+            0x1f if mantissa == 0.0 => sign * f32::INFINITY,
+            0x1f => f32::NAN,
+            _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+        }
+    }
+}
+
+impl PartialEq for Half {
+    fn eq(&self, other: &Half) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for Half {
+    fn partial_cmp(&self, other: &Half) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl RawFloat for Half {
+    type Bits = u16;
+    fn is_nan(self) -> bool {
+        self.to_f32().is_nan()
+    }
+    fn to_bits(self) -> u16 {
+        self.0
+    }
+    fn from_bits(bits: u16) -> Half {
+        Half(bits)
+    }
+}
+
+fn halves<P: NanPolicy>(bits: &[u16]) -> Vec<TotalFloatWith<P, Half>> {
+    bits.iter().map(|&b| TotalFloatWith::new(Half(b))).collect()
+}
+
+fn bits<P: NanPolicy>(list: &[TotalFloatWith<P, Half>]) -> Vec<u16> {
+    list.iter().map(|x| x.inner.0).collect()
+}
+
+// A generic function only assuming the RawFloat bound.
+fn sorted_bits<F: RawFloat>(list: Vec<F>) -> Vec<F::Bits> {
+    let wrapped = list.into_iter().map(TotalFloatWith::<NanLeast, F>::new);
+    merge_sort(wrapped.collect())
+        .into_iter()
+        .map(|x| x.inner.to_bits())
+        .collect()
+}
+
+#[test]
+fn half_sorts() {
+    // 2.0, -1.0, 0.5, 1.0 and -0.0 sort by value
+    let list = halves::<NanLeast>(&[0x4000, 0xbc00, 0x3800, 0x3c00, 0x8000]);
+    assert_eq!(bits(&merge_sort(list)),
+               [0xbc00, 0x8000, 0x3800, 0x3c00, 0x4000]);
+}
+
+#[test]
+fn half_specials() {
+    // NaN and the infinities are placed as for the primitive floats
+    let list = [0x3c00, Half::INFINITY.0, Half::NAN.0, Half::NEG_INFINITY.0];
+    assert_eq!(bits(&merge_sort(halves::<NanLeast>(&list))),
+               [Half::NAN.0, Half::NEG_INFINITY.0, 0x3c00, Half::INFINITY.0]);
+    assert_eq!(bits(&merge_sort(halves::<NanGreatest>(&list))),
+               [Half::NEG_INFINITY.0, 0x3c00, Half::INFINITY.0, Half::NAN.0]);
+}
+
+#[test]
+fn half_nan_equality() {
+    // NaNs with different payloads are equal to each other, but not to others
+    let nan1 = TotalFloatWith::<NanLeast, Half>::new(Half(0x7e01));
+    let nan2 = TotalFloatWith::<NanLeast, Half>::new(Half(0xfe10));
+    assert_eq!(nan1, nan2);
+    assert!(nan1 < TotalFloatWith::new(Half::NEG_INFINITY));
+}
+
+#[test]
+fn generic_over_raw_float() {
+    // The same generic code sorts every RawFloat implementation
+    assert_eq!(sorted_bits(vec![2.0f64, f64::NAN, -1.0]),
+               [f64::NAN.to_bits(), (-1.0f64).to_bits(), 2.0f64.to_bits()]);
+    assert_eq!(sorted_bits(vec![2.0f32, -1.0]),
+               [(-1.0f32).to_bits(), 2.0f32.to_bits()]);
+    assert_eq!(sorted_bits(vec![Half(0x4000), Half(0xbc00)]), [0xbc00, 0x4000]);
+}
@@ -0,0 +1,119 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort;
+use fc_sort::try_merge_sort_in_place_reserving;
+use fc_sort::try_merge_sort_reserving;
+use fc_sort::TotalFloat;
+use rand::random;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+// An allocator refusing allocations larger than a limit set for each thread,
+// so that tests running in parallel do not limit each other's.
+struct Capped;
+
+thread_local! {
+    static LIMIT: Cell<usize> = const { Cell::new(usize::MAX) };
+}
+
+fn over_limit(size: usize) -> bool {
+    LIMIT.try_with(|limit| size > limit.get()).unwrap_or(false)
+}
+
+unsafe impl GlobalAlloc for Capped {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if over_limit(layout.size()) {
+            return std::ptr::null_mut();
+        }
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+    unsafe fn realloc(&self,
+                      ptr: *mut u8,
+                      layout: Layout,
+                      new_size: usize)
+                      -> *mut u8 {
+        if over_limit(new_size) {
+            return std::ptr::null_mut();
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Capped = Capped;
+
+// Runs f refusing allocations of more than the given number of bytes.
+fn with_limit<T, F: FnOnce() -> T>(bytes: usize, f: F) -> T {
+    LIMIT.with(|limit| limit.set(bytes));
+    let result = f();
+    LIMIT.with(|limit| limit.set(usize::MAX));
+    result
+}
+
+fn random_list(n: usize) -> Vec<TotalFloat> {
+    (0..n).map(|_| TotalFloat::new(random())).collect()
+}
+
+#[test]
+fn matches_merge_sort() {
+    // Exactly the result of merge_sort, with NaNs and signed zeros
+    for &n in &[0, 1, 2, 31, 100, 10_000] {
+        let mut list = random_list(n);
+        if n > 2 {
+            list[0] = TotalFloat::new(-0.0);
+            list[1] = TotalFloat::new(f64::NAN);
+            list[2] = TotalFloat::new(0.0);
+        }
+        let sorted = try_merge_sort_reserving(list.clone()).unwrap();
+        let expected = merge_sort(list);
+        assert_eq!(sorted.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+                   expected.iter().map(|x| x.to_bits()).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn scratch_refused() {
+    // The error is returned, and the input is left as it was
+    let bits = |list: &[TotalFloat]| -> Vec<u64> {
+        list.iter().map(|x| x.to_bits()).collect()
+    };
+    let list = random_list(10_000);
+    let mut copy = list.clone();
+    let result = with_limit(1 << 10, || {
+        try_merge_sort_in_place_reserving(&mut copy)
+    });
+    assert!(result.is_err());
+    assert_eq!(bits(&copy), bits(&list));
+    let result = with_limit(1 << 10, || try_merge_sort_reserving(copy));
+    let error = result.unwrap_err();
+    assert_eq!(bits(&error.input), bits(&list));
+    assert!(error.to_string()
+        .starts_with("could not reserve the scratch buffer: "));
+}
+
+#[test]
+fn presorted_needs_no_scratch() {
+    // Sorted and reversed input is put in order without allocating
+    let sorted = (0..10_000).map(|x| TotalFloat::new(x as f64));
+    let sorted = sorted.collect::<Vec<_>>();
+    let mut reversed = sorted.clone();
+    reversed.reverse();
+    let result = with_limit(0, || try_merge_sort_reserving(reversed));
+    assert_eq!(result.unwrap(), sorted);
+    let copy = sorted.clone();
+    let result = with_limit(0, || try_merge_sort_reserving(copy));
+    assert_eq!(result.unwrap(), sorted);
+}
+
+#[test]
+fn scratch_within_limit() {
+    // A limit just large enough for the scratch buffer
+    let list = random_list(1000);
+    let expected = merge_sort(list.clone());
+    let result = with_limit(1000 * 8, || try_merge_sort_reserving(list));
+    assert_eq!(result.unwrap(), expected);
+}
@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::try_sort_results;
+use fc_sort::try_sorted_by_key;
+
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn sorted_results() {
+    // Without errors, the values are sorted
+    let values: Vec<Result<f64, String>> = vec![Ok(2.0), Ok(NAN), Ok(-1.0)];
+    assert_eq!(try_sort_results(values), Ok(tfvec![NAN, -1.0, 2.0]));
+
+    // Parsing then sorting
+    let parsed = "3.5 -2 1e2 0".split(' ').map(|x| x.parse::<f64>());
+    assert_eq!(try_sort_results(parsed), Ok(tfvec![-2.0, 0.0, 3.5, 100.0]));
+}
+
+#[test]
+fn first_error() {
+    // The first error is returned as it was, and nothing after it is read
+    let mut read = 0;
+    let values = vec![Ok(1.0), Err("first"), Ok(2.0), Err("second")];
+    let results = values.into_iter().inspect(|_| read += 1);
+    assert_eq!(try_sort_results(results), Err("first"));
+    assert_eq!(read, 2);
+
+    let parsed = "3.5 x 1e2".split(' ').map(|x| x.parse::<f64>());
+    assert!(try_sort_results(parsed).is_err());
+}
+
+#[test]
+fn fallible_keys() {
+    // Values are sorted stably by their keys, each computed once
+    let words = vec!["2.5 b", "nan c", "-1 d", "2.5 a", "NaN e"];
+    let mut calls = 0;
+    let sorted = try_sorted_by_key(words.clone(), |word| {
+        calls += 1;
+        word.split(' ').next().unwrap().parse::<f64>()
+    });
+    assert_eq!(sorted, Ok(vec!["nan c", "NaN e", "-1 d", "2.5 b", "2.5 a"]));
+    assert_eq!(calls, 5);
+
+    // The first failing key stops the sort
+    let mut calls = 0;
+    let sorted = try_sorted_by_key(words, |word| {
+        calls += 1;
+        if word.starts_with('-') { Err(*word) } else { Ok(1.0) }
+    });
+    assert_eq!(sorted, Err("-1 d"));
+    assert_eq!(calls, 3);
+}
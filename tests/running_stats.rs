@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::NanHandling;
+use fc_sort::RunningStats;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn stats_of(values: &[TotalFloat], nans: NanHandling) -> RunningStats {
+    let mut stats = RunningStats::new(nans);
+    for &x in values {
+        stats.push(x);
+    }
+    stats
+}
+
+fn close(a: TotalFloat, b: f64) -> bool {
+    (a.inner - b).abs() <= 1e-9 * b.abs().max(1.0)
+}
+
+#[test]
+fn against_naive() {
+    // Random data, against two-pass computations
+    let values: Vec<_> = (0..10_000)
+        .map(|_| tf!(random::<f64>() * 200.0 - 50.0))
+        .collect();
+    let stats = stats_of(&values, NanHandling::Propagate);
+
+    let n = values.len() as f64;
+    let mean = values.iter().map(|x| x.inner).sum::<f64>() / n;
+    let variance = values.iter()
+        .map(|x| (x.inner - mean) * (x.inner - mean))
+        .sum::<f64>() / n;
+    assert_eq!(stats.count(), values.len());
+    assert_eq!(stats.nan_count(), 0);
+    assert_eq!(stats.min(), values.iter().min().cloned());
+    assert_eq!(stats.max(), values.iter().max().cloned());
+    assert!(close(stats.mean().unwrap(), mean));
+    assert!(close(stats.variance().unwrap(), variance));
+    #[cfg(feature = "std")]
+    assert!(close(stats.stddev().unwrap(), variance.sqrt()));
+}
+
+#[test]
+fn merge_equals_concatenation() {
+    // Merging the statistics of parts is pushing the whole
+    let values: Vec<_> = (0..5_000).map(|_| tf!(random::<f64>() * 1e3))
+        .collect();
+    let whole = stats_of(&values, NanHandling::Ignore);
+    for &split in [0, 1, 1_234, 4_999, 5_000].iter() {
+        let mut merged = stats_of(&values[..split], NanHandling::Ignore);
+        merged.merge(&stats_of(&values[split..], NanHandling::Ignore));
+        assert_eq!(merged.count(), whole.count());
+        assert_eq!(merged.min(), whole.min());
+        assert_eq!(merged.max(), whole.max());
+        assert!(close(merged.mean().unwrap(), whole.mean().unwrap().inner));
+        assert!(close(merged.variance().unwrap(),
+                      whole.variance().unwrap().inner));
+    }
+}
+
+#[test]
+fn nan_policies() {
+    // NaNs are counted apart, and either propagate or are left out
+    let values = tfvec![2.0, NAN, 4.0, NAN];
+    let ignore = stats_of(&values, NanHandling::Ignore);
+    assert_eq!(ignore.count(), 4);
+    assert_eq!(ignore.nan_count(), 2);
+    assert_eq!(ignore.min(), Some(tf!(2.0)));
+    assert_eq!(ignore.max(), Some(tf!(4.0)));
+    assert_eq!(ignore.mean(), Some(tf!(3.0)));
+    assert_eq!(ignore.variance(), Some(tf!(1.0)));
+
+    let propagate = stats_of(&values, NanHandling::Propagate);
+    assert_eq!(propagate.nan_count(), 2);
+    assert!(propagate.min().unwrap().is_nan());
+    assert_eq!(propagate.max(), Some(tf!(4.0)));
+    assert!(propagate.mean().unwrap().is_nan());
+    assert!(propagate.variance().unwrap().is_nan());
+
+    // Merged NaNs propagate too
+    let mut merged = stats_of(&tfvec![1.0], NanHandling::Propagate);
+    merged.merge(&ignore);
+    assert!(merged.mean().unwrap().is_nan());
+}
+
+#[test]
+fn empty_and_special() {
+    // No values gives no statistics, and only NaNs gives NaN or nothing
+    let empty = RunningStats::new(NanHandling::Propagate);
+    assert_eq!(empty.count(), 0);
+    assert_eq!(empty.min(), None);
+    assert_eq!(empty.max(), None);
+    assert_eq!(empty.mean(), None);
+    assert_eq!(empty.variance(), None);
+
+    let nans = tfvec![NAN];
+    assert!(stats_of(&nans, NanHandling::Propagate).max().unwrap().is_nan());
+    assert_eq!(stats_of(&nans, NanHandling::Ignore).max(), None);
+    assert_eq!(stats_of(&nans, NanHandling::Ignore).mean(), None);
+
+    let stats = stats_of(&tfvec![1.0, INF], NanHandling::Ignore);
+    assert_eq!(stats.max(), Some(tf!(INF)));
+    assert_eq!(stats.mean(), Some(tf!(INF)));
+}
@@ -0,0 +1,65 @@
+extern crate fc_sort;
+
+use fc_sort::merge_sort_by;
+use fc_sort::merge_sort_by_total_key;
+
+const NAN: f64 = f64::NAN;
+
+// Sorts (key, index) pairs by key alone, returning the indices.
+fn sorted_indices(keys: &[f64]) -> Vec<usize> {
+    let pairs: Vec<(f64, usize)> = keys.iter().cloned().zip(0..).collect();
+    merge_sort_by_total_key(pairs, |p| p.0)
+        .into_iter()
+        .map(|p| p.1)
+        .collect()
+}
+
+// Checks the indices are in key order, and in index order between equal keys.
+fn assert_stable(keys: &[f64]) {
+    let indices = sorted_indices(keys);
+    for w in indices.windows(2) {
+        let (a, b) = (keys[w[0]], keys[w[1]]);
+        assert!(fc_sort::total_cmp(&a, &b).then(w[0].cmp(&w[1])).is_lt());
+    }
+}
+
+#[test]
+fn descending_runs_with_ties() {
+    // A descending run with equal neighbours is not reversed whole
+    assert_eq!(sorted_indices(&[5.0, 4.0, 4.0, 3.0]), [3, 1, 2, 0]);
+    assert_eq!(sorted_indices(&[3.0, 2.0, 2.0, 2.0, 1.0, 0.0]),
+               [5, 4, 1, 2, 3, 0]);
+    // Equal NaNs and zeros are equal neighbours too
+    assert_eq!(sorted_indices(&[1.0, 0.0, -0.0, NAN, NAN]), [3, 4, 1, 2, 0]);
+}
+
+#[test]
+fn long_runs_stay_stable() {
+    // Runs longer than the insertion cutoff, ascending and descending, with
+    // many duplicates across them
+    let mut keys = Vec::new();
+    for run in 0..10 {
+        for i in 0..100 {
+            let key = ((i / 3) % 20) as f64;
+            keys.push(if run % 2 == 0 { key } else { 20.0 - key });
+        }
+    }
+    assert_stable(&keys);
+
+    // Strictly descending runs of distinct values, separated by plateaus
+    let mut keys = Vec::new();
+    for run in 0..20 {
+        keys.extend((0..50).rev().map(|i| i as f64));
+        keys.extend(std::iter::repeat_n(run as f64, 40));
+    }
+    assert_stable(&keys);
+}
+
+#[test]
+fn many_short_runs() {
+    // A sawtooth of many runs sorts like any other input
+    let keys: Vec<f64> = (0..5000).map(|i| (i % 37) as f64).collect();
+    assert_stable(&keys);
+    let sorted = merge_sort_by(keys.clone(), fc_sort::total_cmp);
+    assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+}
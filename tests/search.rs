@@ -0,0 +1,154 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::binary_search;
+use fc_sort::binary_search_f64;
+use fc_sort::count_in_range;
+use fc_sort::equal_range;
+use fc_sort::equal_range_f64;
+use fc_sort::lower_bound;
+use fc_sort::lower_bound_f64;
+use fc_sort::nearest;
+use fc_sort::slice_in_range;
+use fc_sort::upper_bound;
+use fc_sort::upper_bound_f64;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn matches_std_semantics() {
+    // Found values give their index, and missing ones their insertion point
+    let list = tfvec![N_INF, -3.0, 1.5, 2.0, 7.0, INF];
+    assert_eq!(binary_search(&list, 1.5), Ok(2));
+    assert_eq!(binary_search(&list, N_INF), Ok(0));
+    assert_eq!(binary_search(&list, INF), Ok(5));
+    assert_eq!(binary_search(&list, 0.0), Err(2));
+    assert_eq!(binary_search(&list, 9.0), Err(5));
+    assert_eq!(binary_search(&tfvec![], 1.0), Err(0));
+}
+
+#[test]
+fn duplicates() {
+    // Any index of a run of equal values may be returned
+    let list = tfvec![1.0, 2.0, 2.0, 2.0, 2.0, 3.0];
+    let found = binary_search(&list, 2.0).unwrap();
+    assert!((1..=4).contains(&found));
+    assert_eq!(binary_search(&list, 2.5), Err(5));
+}
+
+#[test]
+fn nans_at_front() {
+    // Searching for NaN finds the block of NaNs, of any payload
+    let list = tfvec![NAN, -NAN, NAN, -1.0, 4.0];
+    let found = binary_search(&list, NAN).unwrap();
+    assert!(found <= 2);
+    assert!(binary_search(&list, f64::from_bits(0x7ff0_0000_0000_0001))
+                .unwrap() <= 2);
+    // Without NaNs, a NaN would be inserted at the front
+    assert_eq!(binary_search(&list[3..], NAN), Err(0));
+    // Other values are found past the NaNs
+    assert_eq!(binary_search(&list, 4.0), Ok(4));
+    assert_eq!(binary_search(&list, N_INF), Err(3));
+}
+
+#[test]
+fn both_zeros() {
+    // -0.0 and +0.0 are equal, so each finds the other
+    let list = tfvec![-1.0, -0.0, 1.0];
+    assert_eq!(binary_search(&list, 0.0), Ok(1));
+    assert_eq!(binary_search(&list, -0.0), Ok(1));
+    let list = tfvec![-1.0, 0.0, 1.0];
+    assert_eq!(binary_search(&list, -0.0), Ok(1));
+}
+
+#[test]
+fn f64_slices() {
+    // The f64 version applies the total order to the raw values
+    let list = [NAN, -0.0, 0.0, 2.0, 2.0, INF];
+    assert_eq!(binary_search_f64(&list, NAN), Ok(0));
+    assert!(binary_search_f64(&list, 0.0).unwrap() <= 2);
+    assert!([3, 4].contains(&binary_search_f64(&list, 2.0).unwrap()));
+    assert_eq!(binary_search_f64(&list, 3.0), Err(5));
+    assert_eq!(binary_search_f64(&list, N_INF), Err(1));
+}
+
+#[test]
+fn bounds_for_every_class() {
+    // Each distinct class of query against a duplicate-heavy slice
+    let list = tfvec![NAN, NAN, -0.0, 0.0, 1.0, 1.0, 1.0, INF];
+    let cases = [(NAN, 0, 2),
+                 (-NAN, 0, 2),
+                 (N_INF, 2, 2),
+                 (-1.0, 2, 2),
+                 (-0.0, 2, 4),
+                 (0.0, 2, 4),
+                 (0.5, 4, 4),
+                 (1.0, 4, 7),
+                 (2.0, 7, 7),
+                 (INF, 7, 8)];
+    for &(x, lower, upper) in cases.iter() {
+        assert_eq!(lower_bound(&list, x), lower);
+        assert_eq!(upper_bound(&list, x), upper);
+        assert_eq!(equal_range(&list, x), lower..upper);
+    }
+}
+
+#[test]
+fn bounds_of_f64_slices() {
+    // The f64 versions agree with the TotalFloat versions
+    let list = [NAN, NAN, -0.0, 0.0, 1.0, 1.0, 1.0, INF];
+    assert_eq!(lower_bound_f64(&list, 1.0), 4);
+    assert_eq!(upper_bound_f64(&list, 1.0), 7);
+    assert_eq!(equal_range_f64(&list, NAN), 0..2);
+    assert_eq!(equal_range_f64(&list, 0.0), 2..4);
+    assert_eq!(equal_range_f64(&[], 0.0), 0..0);
+}
+
+#[test]
+fn ranges() {
+    // Duplicates at the bounds are included below and excluded above
+    let list = tfvec![NAN, NAN, N_INF, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, INF];
+    assert_eq!(slice_in_range(&list, 2.0, 3.0), &tfvec![2.0, 2.0, 2.0][..]);
+    assert_eq!(count_in_range(&list, 1.0, 3.0), 4);
+    assert_eq!(count_in_range(&list, 1.5, 3.5), 5);
+    assert_eq!(count_in_range(&list, 2.0, 2.0), 0);
+    assert_eq!(count_in_range(&list, 3.0, 1.0), 0);
+
+    // Infinite bounds take in everything but NaNs, which need a NaN bound
+    assert_eq!(count_in_range(&list, N_INF, INF), 7);
+    assert_eq!(slice_in_range(&list, N_INF, INF)[0], tf!(N_INF));
+    assert_eq!(count_in_range(&list, NAN, INF), 9);
+    assert_eq!(count_in_range(&list, NAN, N_INF), 2);
+
+    // Ranges outside the data are empty
+    assert_eq!(count_in_range(&list, 4.0, 9.0), 0);
+    assert_eq!(count_in_range(&tfvec![1.0, 2.0], -9.0, -4.0), 0);
+    assert_eq!(count_in_range(&tfvec![], N_INF, INF), 0);
+}
+
+#[test]
+fn nearest_values() {
+    // The closest element, with ties going to the smaller
+    let list = tfvec![NAN, -2.0, 1.0, 3.0, 3.0, 7.0];
+    assert_eq!(nearest(&list, 1.2), Some((2, tf!(1.0))));
+    assert_eq!(nearest(&list, 2.0), Some((2, tf!(1.0))));
+    assert_eq!(nearest(&list, 2.5), Some((3, tf!(3.0))));
+    assert_eq!(nearest(&list, 3.0), Some((3, tf!(3.0))));
+    assert_eq!(nearest(&list, 5.0).map(|x| x.1), Some(tf!(3.0)));
+    assert_eq!(nearest(&list, 6.0), Some((5, tf!(7.0))));
+
+    // Outside the data, the nearest end, never the NaN
+    assert_eq!(nearest(&list, -9.0), Some((1, tf!(-2.0))));
+    assert_eq!(nearest(&list, N_INF), Some((1, tf!(-2.0))));
+    assert_eq!(nearest(&list, INF), Some((5, tf!(7.0))));
+    assert_eq!(nearest(&tfvec![1.0, INF], INF), Some((1, tf!(INF))));
+    assert_eq!(nearest(&tfvec![N_INF, 1.0], N_INF), Some((0, tf!(N_INF))));
+
+    // A NaN finds only a NaN, and NaNs alone are never nearest to a number
+    assert_eq!(nearest(&list, NAN).map(|x| x.0), Some(0));
+    assert_eq!(nearest(&tfvec![1.0], NAN), None);
+    assert_eq!(nearest(&tfvec![NAN], 1.0), None);
+    assert_eq!(nearest(&tfvec![], 1.0), None);
+}
@@ -0,0 +1,146 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::median;
+use fc_sort::quantile;
+use fc_sort::select_nth;
+use fc_sort::Interpolation;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn median_odd_and_even() {
+    // An odd length has a middle value, and an even one interpolates
+    assert_eq!(median(&mut tfvec![5.0, 1.0, 3.0]), Some(tf!(3.0)));
+    assert_eq!(median(&mut tfvec![4.0, 1.0, 3.0, 2.0]), Some(tf!(2.5)));
+    assert_eq!(median(&mut tfvec![7.0]), Some(tf!(7.0)));
+    assert_eq!(median(&mut tfvec![]), None);
+}
+
+#[test]
+fn each_interpolation() {
+    // The quantile 0.4 of five values lies at position 1.6
+    let data = tfvec![40.0, 10.0, 30.0, 20.0, 0.0];
+    let at = |interp| quantile(&mut data.clone(), 0.4, interp).unwrap();
+    assert_eq!(at(Interpolation::Lower), 10.0);
+    assert_eq!(at(Interpolation::Higher), 20.0);
+    assert_eq!(at(Interpolation::Nearest), 20.0);
+    assert_eq!(at(Interpolation::Midpoint), 15.0);
+    assert_eq!(at(Interpolation::Linear), 16.0);
+}
+
+#[test]
+fn nearest_rounds_halfway_to_even() {
+    // Positions 0.5 and 1.5 round to indices 0 and 2
+    let mut data = tfvec![1.0, 2.0];
+    assert_eq!(quantile(&mut data, 0.5, Interpolation::Nearest),
+               Some(tf!(1.0)));
+    let mut data = tfvec![1.0, 2.0, 3.0, 4.0];
+    assert_eq!(quantile(&mut data, 0.5, Interpolation::Nearest),
+               Some(tf!(3.0)));
+}
+
+#[test]
+fn extremes_and_invalid_q() {
+    // q = 0 and q = 1 give the least and the greatest values
+    let mut data = tfvec![3.0, N_INF, 9.0, INF];
+    assert_eq!(quantile(&mut data, 0.0, Interpolation::Linear),
+               Some(tf!(N_INF)));
+    assert_eq!(quantile(&mut data, 1.0, Interpolation::Linear),
+               Some(tf!(INF)));
+    assert_eq!(quantile(&mut data, 1.5, Interpolation::Linear), None);
+    assert_eq!(quantile(&mut data, -0.1, Interpolation::Linear), None);
+    assert_eq!(quantile(&mut data, NAN, Interpolation::Linear), None);
+}
+
+#[test]
+fn huge_span() {
+    // Bounds spanning more than f64::MAX interpolate without overflowing
+    let max = f64::MAX;
+    let at = |q: f64, interp: Interpolation| {
+        quantile(&mut tfvec![max, -max], q, interp).unwrap()
+    };
+    assert_eq!(median(&mut tfvec![-max, max]), Some(tf!(0.0)));
+    assert_eq!(at(0.5, Interpolation::Midpoint), tf!(0.0));
+    assert_eq!(at(0.9, Interpolation::Midpoint), tf!(0.0));
+    assert_eq!(at(0.5, Interpolation::Linear), tf!(0.0));
+    let linear = at(0.9, Interpolation::Linear);
+    assert!(linear.is_finite());
+    assert!((linear.inner / max - 0.8).abs() < 1e-12);
+    assert_eq!(at(0.9, Interpolation::Lower), tf!(-max));
+    assert_eq!(at(0.9, Interpolation::Higher), tf!(max));
+    assert_eq!(at(0.9, Interpolation::Nearest), tf!(max));
+}
+
+#[test]
+fn nans_occupy_the_low_end() {
+    // Low quantiles fall on the NaNs, and high ones are unaffected
+    let mut data = tfvec![4.0, NAN, 2.0, NAN, 3.0];
+    assert_eq!(median(&mut data), Some(tf!(2.0)));
+    assert!(quantile(&mut data, 0.1, Interpolation::Lower).unwrap().is_nan());
+    assert!(quantile(&mut data, 0.2, Interpolation::Linear).unwrap().is_nan());
+    assert_eq!(quantile(&mut data, 1.0, Interpolation::Linear),
+               Some(tf!(4.0)));
+}
+
+#[test]
+fn large_input() {
+    // A large shuffled input agrees with sorting
+    let mut data: Vec<_> = (0..100_001)
+        .map(|i| tf!((i * 7919 % 100_001) as f64))
+        .collect();
+    assert_eq!(median(&mut data), Some(tf!(50_000.0)));
+    assert_eq!(quantile(&mut data, 0.25, Interpolation::Linear),
+               Some(tf!(25_000.0)));
+}
+
+// Checks the partition returned by select_nth around index k.
+fn check_partition(data: &[f64], k: usize) {
+    let mut list: Vec<_> = data.iter().map(|&x| tf!(x)).collect();
+    let mut sorted = list.clone();
+    sorted.sort();
+    let (before, nth, after) = select_nth(&mut list, k);
+    assert_eq!(*nth, sorted[k]);
+    assert_eq!(before.len(), k);
+    assert!(before.iter().all(|x| x <= nth));
+    assert!(after.iter().all(|x| x >= nth));
+}
+
+#[test]
+fn select_first_and_last() {
+    // k = 0 gives the least value, and k = len - 1 the greatest
+    let data = [3.0, NAN, -1.0, INF, 0.0, N_INF];
+    check_partition(&data, 0);
+    check_partition(&data, data.len() - 1);
+    let mut list = tfvec![3.0, NAN, -1.0, INF];
+    assert!(select_nth(&mut list, 0).1.is_nan());
+    assert_eq!(*select_nth(&mut list, 3).1, INF);
+}
+
+#[test]
+fn select_with_duplicates() {
+    // Many repeated values, including mixed zeros
+    let data: Vec<f64> = (0..1000).map(|i| [1.0, -0.0, 0.0, 2.0][i % 4])
+        .collect();
+    for &k in [0, 1, 249, 250, 500, 999].iter() {
+        check_partition(&data, k);
+    }
+}
+
+#[test]
+fn select_all_nan() {
+    // Every value is NaN, so every index holds a NaN
+    let data = [NAN; 17];
+    for k in 0..data.len() {
+        check_partition(&data, k);
+    }
+}
+
+#[test]
+#[should_panic]
+fn select_out_of_bounds() {
+    // k must be within the slice
+    select_nth(&mut tfvec![1.0, 2.0], 2);
+}
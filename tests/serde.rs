@@ -0,0 +1,79 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate bincode;
+#[macro_use]
+extern crate serde;
+extern crate serde_json;
+
+use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Reading {
+    #[serde(with = "fc_sort::serde_special")]
+    value: TotalFloat,
+}
+
+#[test]
+fn json_array() {
+    // A JSON array of numbers deserializes straight into Vec<TotalFloat>
+    let list: Vec<TotalFloat> = serde_json::from_str("[3.0,1.0,2]").unwrap();
+    assert_eq!(list, tfvec![3.0, 1.0, 2.0]);
+
+    // And the sorted result serializes as plain numbers
+    let json = serde_json::to_string(&merge_sort(list)).unwrap();
+    assert_eq!(json, "[1.0,2.0,3.0]");
+}
+
+#[test]
+fn json_special_values() {
+    // Non-finite values are written as strings
+    let readings = [NAN, INF, N_INF, 1.5]
+        .iter()
+        .map(|&x| Reading { value: tf!(x) })
+        .collect::<Vec<_>>();
+    let json = serde_json::to_string(&readings).unwrap();
+    assert_eq!(json,
+               r#"[{"value":"NaN"},{"value":"inf"},{"value":"-inf"},"#
+                   .to_owned() + r#"{"value":1.5}]"#);
+
+    // And read back, comparing equal (including NaN) and sorting the same
+    let back: Vec<Reading> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, readings);
+    let sorted = merge_sort(back.iter().map(|r| r.value).collect());
+    assert_eq!(sorted, tfvec![NAN, N_INF, 1.5, INF]);
+}
+
+#[test]
+fn json_special_rejects_other_strings() {
+    // Only the documented spellings are accepted
+    assert!(serde_json::from_str::<Reading>(r#"{"value":"nan"}"#).is_err());
+    assert!(serde_json::from_str::<Reading>(r#"{"value":"2.0"}"#).is_err());
+    let reading: Reading = serde_json::from_str(r#"{"value":-4}"#).unwrap();
+    assert_eq!(reading.value, tf!(-4.0));
+}
+
+#[test]
+fn bincode_round_trip() {
+    // The binary format preserves every bit pattern, including NaN payloads
+    let list = vec![TotalFloat::from_bits(0x7ff8_0000_dead_beef),
+                    tf!(-0.0),
+                    tf!(INF),
+                    TotalFloat::from_bits(0xfff0_0000_0000_0001),
+                    tf!(2.5)];
+    let bytes = bincode::serialize(&list).unwrap();
+    let back: Vec<TotalFloat> = bincode::deserialize(&bytes).unwrap();
+    let bits = |l: &[TotalFloat]| {
+        l.iter().map(|x| x.to_bits()).collect::<Vec<_>>()
+    };
+    assert_eq!(bits(&back), bits(&list));
+
+    // And ordering behaves as before the round trip
+    assert_eq!(merge_sort(back), merge_sort(list));
+}
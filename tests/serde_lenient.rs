@@ -0,0 +1,81 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate fc_sort;
+extern crate bincode;
+#[macro_use]
+extern crate serde;
+extern crate serde_json;
+
+use fc_sort::merge_sort;
+use fc_sort::serde_lenient;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Reading {
+    #[serde(with = "fc_sort::serde_lenient")]
+    value: TotalFloat,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Series {
+    #[serde(with = "fc_sort::serde_lenient::vec")]
+    values: Vec<TotalFloat>,
+}
+
+#[test]
+fn mixed_array() {
+    // Numbers, numeric strings, special spellings and null are all accepted
+    let json = r#"{"values":[2, "2.75", null, "-Infinity", 0.5, "NaN",
+                             "Infinity", " -1e3 "]}"#;
+    let series: Series = serde_json::from_str(json).unwrap();
+    assert_eq!(series.values,
+               tfvec![2.0, 2.75, NAN, N_INF, 0.5, NAN, INF, -1e3]);
+
+    // And the NaNs sort first
+    assert_eq!(merge_sort(series.values),
+               tfvec![NAN, NAN, N_INF, -1e3, 0.5, 2.0, 2.75, INF]);
+}
+
+#[test]
+fn single_values() {
+    // The field adapter accepts the same forms
+    for &(json, expected) in &[(r#"{"value":"1.5"}"#, 1.5),
+                               (r#"{"value":null}"#, NAN),
+                               (r#"{"value":-7}"#, -7.0),
+                               (r#"{"value":"inf"}"#, INF)] {
+        let reading: Reading = serde_json::from_str(json).unwrap();
+        assert_eq!(reading.value, tf!(expected));
+    }
+}
+
+#[test]
+fn errors_report_index() {
+    // The index of the element which could not be read is reported
+    let mut json = serde_json::Deserializer::from_str(r#"[1, "2", "two"]"#);
+    let result: Result<Vec<TotalFloat>, _> =
+        serde_lenient::deserialize_vec(&mut json);
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("at index 2"), "{}", message);
+    assert!(message.contains("two"), "{}", message);
+}
+
+#[test]
+fn round_trip() {
+    // Values are written so that they can be read back
+    let series = Series { values: tfvec![NAN, 1.5, INF] };
+    let json = serde_json::to_string(&series).unwrap();
+    assert_eq!(json, r#"{"values":["NaN",1.5,"inf"]}"#);
+    assert_eq!(serde_json::from_str::<Series>(&json).unwrap(), series);
+}
+
+#[test]
+fn claimed_length_not_trusted() {
+    // A length prefix far beyond the data is an error, not an allocation
+    let bytes = (1u64 << 60).to_le_bytes();
+    assert!(bincode::deserialize::<Series>(&bytes).is_err());
+}
@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::sorted_difference;
+use fc_sort::sorted_intersection;
+use fc_sort::sorted_is_subset;
+use fc_sort::sorted_symmetric_difference;
+use fc_sort::sorted_union;
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn disjoint() {
+    // With nothing in common, the union and symmetric difference are merges
+    let a = tfvec![-INF, 1.0, 3.0];
+    let b = tfvec![2.0, 4.0];
+    assert_eq!(sorted_union(&a, &b), tfvec![-INF, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(sorted_symmetric_difference(&a, &b),
+               tfvec![-INF, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(sorted_intersection(&a, &b), tfvec![]);
+    assert_eq!(sorted_difference(&a, &b), a);
+    assert!(!sorted_is_subset(&a, &b));
+}
+
+#[test]
+fn duplicates() {
+    // Counts combine as a multiset's: max, min, and the differences
+    let a = tfvec![1.0, 1.0, 1.0, 2.0, 3.0, 3.0];
+    let b = tfvec![1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0];
+    assert_eq!(sorted_union(&a, &b),
+               tfvec![1.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0]);
+    assert_eq!(sorted_intersection(&a, &b), tfvec![1.0, 2.0, 3.0, 3.0]);
+    assert_eq!(sorted_difference(&a, &b), tfvec![1.0, 1.0]);
+    assert_eq!(sorted_difference(&b, &a), tfvec![2.0, 3.0, 4.0]);
+    assert_eq!(sorted_symmetric_difference(&a, &b),
+               tfvec![1.0, 1.0, 2.0, 3.0, 4.0]);
+    assert!(sorted_is_subset(&tfvec![1.0, 3.0, 3.0], &a));
+    assert!(!sorted_is_subset(&tfvec![3.0, 3.0, 3.0], &a));
+}
+
+#[test]
+fn nans_and_zeros() {
+    // All NaNs are one value, as are the zeros, kept from the first slice
+    let a = tfvec![NAN, NAN, -0.0];
+    let b = tfvec![-NAN, 0.0, 0.0];
+    let union = sorted_union(&a, &b);
+    assert_eq!(union, tfvec![NAN, NAN, 0.0, 0.0]);
+    assert!(union[0].is_sign_positive() && union[1].is_sign_positive());
+    assert!(union[2].is_sign_negative());
+    let both = sorted_intersection(&a, &b);
+    assert_eq!(both, tfvec![NAN, 0.0]);
+    assert!(both[0].is_sign_positive());
+    assert!(both[1].is_sign_negative());
+
+    // Only NaNs
+    let nans = tfvec![NAN, NAN, NAN];
+    assert_eq!(sorted_difference(&nans, &tfvec![-NAN]), tfvec![NAN, NAN]);
+    assert_eq!(sorted_union(&nans, &tfvec![1.0]), tfvec![NAN, NAN, NAN, 1.0]);
+    assert!(sorted_is_subset(&tfvec![NAN], &nans));
+    assert!(sorted_is_subset(&tfvec![], &tfvec![]));
+}
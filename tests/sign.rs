@@ -0,0 +1,54 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn is_negative_zero() {
+    // Only -0.0 is negative zero
+    assert!(tf!(-0.0).is_negative_zero());
+    assert!(!tf!(0.0).is_negative_zero());
+    assert!(!tf!(-1.0).is_negative_zero());
+    assert!(!tf!(-NAN).is_negative_zero());
+}
+
+#[test]
+fn copysign() {
+    // The magnitude comes from self and the sign from the argument
+    assert_eq!(tf!(2.5).copysign(tf!(-1.0)), tf!(-2.5));
+    assert_eq!(tf!(-2.5).copysign(tf!(1.0)), tf!(2.5));
+    assert_eq!(tf!(INF).copysign(tf!(-0.0)), tf!(N_INF));
+    assert!(tf!(0.0).copysign(tf!(-3.0)).is_negative_zero());
+
+    // NaN stays NaN, but takes on the sign
+    let nan = tf!(NAN).copysign(tf!(-1.0));
+    assert!(nan.is_nan() && nan.is_sign_negative());
+}
+
+#[test]
+fn signum_total() {
+    // Values either side of zero
+    assert_eq!(tf!(3.2).signum_total(), tf!(1.0));
+    assert_eq!(tf!(INF).signum_total(), tf!(1.0));
+    assert_eq!(tf!(-3.2).signum_total(), tf!(-1.0));
+    assert_eq!(tf!(N_INF).signum_total(), tf!(-1.0));
+
+    // Both zeros have a sign of +0.0
+    assert_eq!(tf!(0.0).signum_total(), tf!(0.0));
+    assert!(!tf!(-0.0).signum_total().is_negative_zero());
+
+    // NaN is least, so has a sign of -1.0
+    assert_eq!(tf!(NAN).signum_total(), tf!(-1.0));
+    assert_eq!(tf!(-NAN).signum_total(), tf!(-1.0));
+}
+
+#[test]
+fn chains_stay_total() {
+    // The results are TotalFloats, so can be chained and compared directly
+    let x: TotalFloat = tf!(-4.0).signum_total().copysign(tf!(2.0));
+    assert_eq!(x, TotalFloat::new(1.0));
+}
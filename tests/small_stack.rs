@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
+use std::thread;
+
+#[test]
+fn large_sort_on_small_stack() {
+    // Sorting ten million elements needs no more than a small, fixed stack
+    let sorted = thread::Builder::new()
+        .stack_size(256 * 1024)
+        .spawn(|| {
+            // A cheap permutation, so the input is far from sorted
+            let list: Vec<TotalFloat> = (0..10_000_000u64)
+                .map(|i| tf!((i * 7_919 % 10_000_019) as f64))
+                .collect();
+            merge_sort(list)
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(sorted.len(), 10_000_000);
+    assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+}
@@ -0,0 +1,114 @@
+extern crate fc_sort;
+
+use fc_sort::merge_by;
+use fc_sort::merge_sort_by;
+use fc_sort::merge_sort_by_cached_key;
+use fc_sort::merge_sort_by_cached_total_key;
+use fc_sort::merge_sort_by_key;
+use fc_sort::merge_sort_by_total_key;
+use std::cell::Cell;
+
+const NAN: f64 = f64::NAN;
+
+// A record carrying its original position, to observe stability.
+#[derive(Debug, Clone, PartialEq)]
+struct Reading {
+    value: f64,
+    index: usize,
+}
+
+fn readings(values: &[f64]) -> Vec<Reading> {
+    values.iter()
+        .enumerate()
+        .map(|(index, &value)| Reading { value, index })
+        .collect()
+}
+
+fn indices(list: &[Reading]) -> Vec<usize> {
+    list.iter().map(|r| r.index).collect()
+}
+
+#[test]
+fn by_comparator_is_stable() {
+    // Records with many equal keys keep their original relative order
+    let list = readings(&[2.0, 1.0, 2.0, 3.0, 1.0, 2.0, 1.0, 3.0]);
+    let sorted = merge_sort_by(list, |a, b| {
+        a.value.partial_cmp(&b.value).unwrap()
+    });
+    assert_eq!(indices(&sorted), [1, 4, 6, 0, 2, 5, 3, 7]);
+
+    // A reversed comparator keeps equal records in order too
+    let list = readings(&[2.0, 1.0, 2.0, 3.0, 1.0]);
+    let sorted = merge_sort_by(list, |a, b| {
+        b.value.partial_cmp(&a.value).unwrap()
+    });
+    assert_eq!(indices(&sorted), [3, 0, 2, 1, 4]);
+}
+
+#[test]
+fn by_total_key() {
+    // NaN keys are least, and equal keys keep their order
+    let list = readings(&[1.5, NAN, -0.0, 0.0, NAN, -3.0]);
+    let sorted = merge_sort_by_total_key(list, |r| r.value);
+    assert_eq!(indices(&sorted), [1, 4, 5, 2, 3, 0]);
+}
+
+#[test]
+fn merge_by_comparator() {
+    // Equal records from the first list come first
+    let a = readings(&[1.0, 2.0, 2.0]);
+    let b: Vec<_> = readings(&[0.0, 2.0, 5.0])
+        .into_iter()
+        .map(|r| Reading { index: r.index + 10, ..r })
+        .collect();
+    let merged = merge_by(a, b, |x, y| x.value.partial_cmp(&y.value).unwrap());
+    assert_eq!(indices(&merged), [10, 0, 1, 2, 11, 12]);
+}
+
+#[test]
+fn by_key() {
+    // Sorting by a derived key is stable
+    let words = vec!["ccc", "a", "bb", "dd", "e"];
+    assert_eq!(merge_sort_by_key(words, |w| w.len()),
+               ["a", "e", "bb", "dd", "ccc"]);
+}
+
+#[test]
+fn cached_key_computed_once() {
+    // The cached variants call the key function once per element
+    let calls = Cell::new(0);
+    let fields = vec!["2.5", "-1", "NaN", "2.5", "10", "x", "0"];
+    let parse = |s: &&str| {
+        calls.set(calls.get() + 1);
+        s.parse::<f64>().unwrap_or(0.0)
+    };
+    let sorted = merge_sort_by_cached_total_key(fields.clone(), parse);
+    assert_eq!(sorted, ["NaN", "-1", "x", "0", "2.5", "2.5", "10"]);
+    assert_eq!(calls.get(), fields.len());
+
+    calls.set(0);
+    let sorted = merge_sort_by_cached_key(fields.clone(), |s| {
+        calls.set(calls.get() + 1);
+        s.len()
+    });
+    assert_eq!(sorted, ["x", "0", "-1", "10", "2.5", "NaN", "2.5"]);
+    assert_eq!(calls.get(), fields.len());
+
+    // Whereas the uncached one calls it for every comparison
+    calls.set(0);
+    merge_sort_by_key(fields.clone(), |s| {
+        calls.set(calls.get() + 1);
+        s.len()
+    });
+    assert!(calls.get() > fields.len());
+}
+
+#[test]
+fn cached_key_permutation() {
+    // A larger permutation is applied correctly, keeping equal keys in order
+    let list = readings(&(0..1000).map(|i| ((i * 37) % 101) as f64)
+                                  .collect::<Vec<_>>());
+    let cached = merge_sort_by_cached_total_key(list.clone(), |r| r.value);
+    let plain = merge_sort_by_total_key(list, |r| r.value);
+    assert_eq!(cached, plain);
+}
@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_by;
+use fc_sort::merge_sort_with_stats;
+use fc_sort::SortStats;
+use fc_sort::TotalFloat;
+use rand::random;
+
+const NAN: f64 = f64::NAN;
+
+fn random_list(n: usize) -> Vec<TotalFloat> {
+    (0..n).map(|_| TotalFloat::new(random::<u32>() as f64)).collect()
+}
+
+#[test]
+fn empty_and_single() {
+    // Nothing to compare, move or allocate
+    assert_eq!(merge_sort_with_stats(tfvec![]).1, SortStats::default());
+    assert_eq!(merge_sort_with_stats(tfvec![1.0]).1, SortStats::default());
+}
+
+#[test]
+fn two_values() {
+    // One comparison, and a swap only if they are out of order
+    let (sorted, stats) = merge_sort_with_stats(tfvec![1.0, 2.0]);
+    assert_eq!(sorted, tfvec![1.0, 2.0]);
+    assert_eq!(stats, SortStats { comparisons: 1, ..SortStats::default() });
+    let (sorted, stats) = merge_sort_with_stats(tfvec![2.0, NAN]);
+    assert_eq!(sorted, tfvec![NAN, 2.0]);
+    assert_eq!(stats,
+               SortStats { comparisons: 1, moves: 2, ..SortStats::default() });
+}
+
+#[test]
+fn presorted() {
+    // A single scan of n - 1 comparisons, reversing descending input
+    let ascending = (0..100).map(|x| TotalFloat::new(x as f64)).collect();
+    assert_eq!(merge_sort_with_stats(ascending).1,
+               SortStats { comparisons: 99, ..SortStats::default() });
+    let descending = (0..100).rev().map(|x| TotalFloat::new(x as f64));
+    assert_eq!(merge_sort_with_stats(descending.collect()).1,
+               SortStats {
+                   comparisons: 99,
+                   moves: 100,
+                   ..SortStats::default()
+               });
+}
+
+#[test]
+fn insertion_sorted() {
+    // One run, extended by inserting the last value at the front
+    let (sorted, stats) = merge_sort_with_stats(tfvec![2.0, 3.0, 1.0]);
+    assert_eq!(sorted, tfvec![1.0, 2.0, 3.0]);
+    assert_eq!((stats.moves, stats.merges, stats.max_depth, stats.allocations),
+               (3, 0, 1, 1));
+}
+
+#[test]
+fn two_runs() {
+    // Two runs of the cutoff length, merged once, with the first moved twice
+    // and the second once
+    let list = (32..64).chain(0..32).map(|x| TotalFloat::new(x as f64));
+    let (sorted, stats) = merge_sort_with_stats(list.collect());
+    assert_eq!(sorted,
+               (0..64).map(|x| TotalFloat::new(x as f64)).collect::<Vec<_>>());
+    assert_eq!((stats.moves, stats.merges, stats.max_depth, stats.allocations),
+               (96, 1, 2, 1));
+}
+
+#[test]
+fn matches_merge_sort() {
+    // The same result, with as many comparisons as a counting comparator sees
+    for &n in &[10, 100, 1000] {
+        let list = random_list(n);
+        let mut comparisons = 0;
+        let expected = merge_sort_by(list.clone(), |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        let (sorted, stats) = merge_sort_with_stats(list.clone());
+        assert_eq!(sorted, merge_sort(list));
+        assert_eq!(sorted, expected);
+        assert_eq!(stats.comparisons, comparisons);
+    }
+}
+
+#[test]
+fn grows_with_length() {
+    // More values take more work
+    let stats = [10, 100, 1000, 10_000]
+        .iter()
+        .map(|&n| merge_sort_with_stats(random_list(n)).1)
+        .collect::<Vec<_>>();
+    for w in stats.windows(2) {
+        assert!(w[0].comparisons < w[1].comparisons);
+        assert!(w[0].moves < w[1].moves);
+        assert!(w[0].merges < w[1].merges || w[0].merges == 0);
+        assert!(w[0].max_depth <= w[1].max_depth);
+    }
+}
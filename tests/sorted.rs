@@ -0,0 +1,96 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::as_sorted;
+use fc_sort::is_sorted;
+use fc_sort::is_sorted_by;
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_desc;
+use fc_sort::SortedSlice;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+fn bits(list: &[TotalFloat]) -> Vec<u64> {
+    list.iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn is_sorted_follows_total_order() {
+    // NaNs first, zeros equal, and runs of equal values allowed
+    assert!(is_sorted::<fc_sort::NanLeast, f64>(&[]));
+    assert!(is_sorted(&tfvec![1.0]));
+    assert!(is_sorted(&tfvec![NAN, N_INF, -0.0, 0.0, -0.0, 2.0, 2.0, INF]));
+    assert!(!is_sorted(&tfvec![N_INF, NAN]));
+    assert!(!is_sorted(&tfvec![1.0, 3.0, 2.0]));
+}
+
+#[test]
+fn is_sorted_by_comparator() {
+    // A reversed comparator accepts descending input
+    let list = tfvec![INF, 3.0, 3.0, -1.0, NAN];
+    assert!(is_sorted_by(&list, |a: &TotalFloat, b| b.cmp(a)));
+    assert!(!is_sorted_by(&list, TotalFloat::cmp));
+    assert!(is_sorted_by(&["a", "b", "b", "c"], |a, b| a.cmp(b)));
+}
+
+#[test]
+fn ascending_input_untouched() {
+    // Presorted input keeps the order of equal values, down to their bits
+    let nan_a = f64::from_bits(0x7ff8_0000_0000_0001);
+    let nan_b = f64::from_bits(0xfff8_0000_0000_0002);
+    let list = tfvec![nan_a, nan_b, NAN, 0.0, -0.0, 0.0, 1.0];
+    assert_eq!(bits(&merge_sort(list.clone())), bits(&list));
+}
+
+#[test]
+fn descending_input_reversed() {
+    // Strictly descending input is reversed
+    let list = tfvec![INF, 5.0, 0.0, -2.0, N_INF, NAN];
+    assert_eq!(merge_sort(list), tfvec![NAN, N_INF, -2.0, 0.0, 5.0, INF]);
+
+    // Descending input with equal values is still sorted correctly
+    let list = tfvec![5.0, 3.0, 3.0, 0.0, -0.0, NAN, NAN];
+    assert_eq!(merge_sort(list), tfvec![NAN, NAN, 0.0, 0.0, 3.0, 3.0, 5.0]);
+}
+
+#[test]
+fn as_sorted_checks_order() {
+    // Only sorted slices can be viewed as sorted
+    assert!(as_sorted(&tfvec![NAN, N_INF, 0.0, -0.0, 1.0, INF]).is_some());
+    assert!(as_sorted(&tfvec![]).is_some());
+    assert!(as_sorted(&tfvec![1.0, 0.0]).is_none());
+    assert!(as_sorted(&tfvec![0.0, NAN]).is_none());
+}
+
+// Takes the greatest value of a slice that is known to be sorted.
+fn greatest(sorted: SortedSlice) -> Option<TotalFloat> {
+    sorted.max()
+}
+
+#[test]
+fn sorted_slice_queries() {
+    // The ends, searches and both directions of iteration
+    let list = merge_sort(tfvec![3.0, NAN, -1.0, 7.0, 3.0, -NAN]);
+    let sorted = as_sorted(&list).unwrap();
+    assert_eq!(sorted.len(), 6);
+    assert!(sorted.min().unwrap().is_nan());
+    assert_eq!(greatest(sorted), Some(tf!(7.0)));
+    assert_eq!(sorted.binary_search(-1.0), Ok(2));
+    assert_eq!(sorted.binary_search(5.0), Err(5));
+
+    let ascending: Vec<_> = sorted.iter().cloned().collect();
+    let mut descending: Vec<_> = sorted.iter_desc().cloned().collect();
+    assert_eq!(bits(&ascending), bits(&list));
+    assert_eq!(descending[..4], tfvec![7.0, 3.0, 3.0, -1.0][..]);
+    assert_eq!(descending, merge_sort_desc(list.clone()));
+    descending.reverse();
+    assert_eq!(bits(&descending), bits(&list));
+    assert_eq!(sorted.into_iter().count(), 6);
+
+    let empty = as_sorted(&[]).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!((empty.min(), empty.max()), (None, None));
+}
@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::merge;
+use fc_sort::merge_into;
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_by;
+use fc_sort::merge_slices;
+use fc_sort::TotalFloat;
+
+#[test]
+fn duplicate_keys_keep_order() {
+    // Many duplicate keys, each paired with its original index
+    let keys = [3, 1, 2, 3, 1, 1, 2, 3, 2, 1, 3, 2, 1, 2, 3, 1, 3];
+    let pairs: Vec<(u32, usize)> = keys.iter().cloned().zip(0..).collect();
+    let sorted = merge_sort_by(pairs, |a, b| a.0.cmp(&b.0));
+    for w in sorted.windows(2) {
+        assert!(w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1));
+    }
+}
+
+#[test]
+fn nan_payloads_keep_order() {
+    // Two NaNs with distinguishable payloads stay in their original order
+    let nan1 = TotalFloat::from_bits(0x7ff8_0000_0000_0001);
+    let nan2 = TotalFloat::from_bits(0xfff8_0000_0000_0002);
+    let list = vec![tf!(5.0), nan2, tf!(-1.0), tf!(2.0), nan1, tf!(0.5)];
+    let sorted = merge_sort(list);
+    assert_eq!(sorted[0].to_bits(), nan2.to_bits());
+    assert_eq!(sorted[1].to_bits(), nan1.to_bits());
+
+    // And likewise for zeros of different signs
+    let sorted = merge_sort(tfvec![1.0, 0.0, -3.0, -0.0, 0.0, -0.0]);
+    let signs: Vec<bool> = sorted[1..5].iter()
+        .map(|x| x.is_sign_negative())
+        .collect();
+    assert_eq!(signs, [false, true, false, true]);
+}
+
+#[test]
+fn merge_prefers_first_list() {
+    // Equal values from the first list come before those from the second
+    let a = vec![tf!(-0.0), tf!(1.0)];
+    let b = vec![tf!(0.0), tf!(1.0)];
+    let merged = merge_slices(&a, &b);
+    assert!(merged[0].is_sign_negative());
+    assert!(!merged[1].is_sign_negative());
+    assert_eq!(merged, merge(a, b));
+}
+
+#[test]
+fn merge_tails_keep_order() {
+    // Whichever list runs out first, the rest of the other keeps its order,
+    // including between equal values
+    let nan = |payload: u64| {
+        TotalFloat::from_bits(0x7ff8_0000_0000_0000 | payload)
+    };
+    let short = vec![nan(1), tf!(-1.0)];
+    let long = vec![nan(2), tf!(0.0), tf!(-0.0), tf!(0.0), nan(3), nan(4)];
+    let long = merge_sort(long);
+    let bits = |list: Vec<TotalFloat>| -> Vec<u64> {
+        list.iter().map(|x| x.to_bits()).collect()
+    };
+    let expected = bits(vec![nan(1), nan(2), nan(3), nan(4), tf!(-1.0),
+                             tf!(0.0), tf!(-0.0), tf!(0.0)]);
+    assert_eq!(bits(merge(short.clone(), long.clone())), expected);
+    assert_eq!(bits(merge_slices(&short, &long)), expected);
+
+    // With the lists the other way round, the long list's NaNs come first
+    let expected = bits(vec![nan(2), nan(3), nan(4), nan(1), tf!(-1.0),
+                             tf!(0.0), tf!(-0.0), tf!(0.0)]);
+    assert_eq!(bits(merge_slices(&long, &short)), expected);
+    assert_eq!(bits(merge(long.clone(), short)), expected);
+
+    // And a long tail of equal keys behind an exhausted list
+    let pairs: Vec<(u32, usize)> = (0..1000).map(|i| (7, i)).collect();
+    let merged = fc_sort::merge_by(vec![(1, 0)], pairs, |a, b| a.0.cmp(&b.0));
+    assert_eq!(merged[0], (1, 0));
+    assert!(merged[1..].iter().zip(0..).all(|(x, i)| *x == (7, i)));
+}
+
+#[test]
+fn merge_into_appends() {
+    // The merge is appended after whatever the buffer held
+    let mut out = tfvec![9.0, -9.0];
+    merge_into(&tfvec![-0.0, 2.0], &tfvec![f64::NAN, 0.0, 1.0], &mut out);
+    assert_eq!(out, tfvec![9.0, -9.0, f64::NAN, -0.0, 0.0, 1.0, 2.0]);
+    assert!(out[3].is_sign_negative());
+    assert!(out.capacity() >= 7);
+
+    // Merging nothing leaves the buffer as it was
+    merge_into(&[], &[], &mut out);
+    assert_eq!(out.len(), 7);
+    assert_eq!(merge_slices(&[], &tfvec![1.0]), tfvec![1.0]);
+}
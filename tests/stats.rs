@@ -0,0 +1,127 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::extrema;
+use fc_sort::extrema_non_nan;
+use fc_sort::max_of;
+use fc_sort::max_of_non_nan;
+use fc_sort::mean;
+use fc_sort::mean_with;
+use fc_sort::merge_sort;
+use fc_sort::min_of;
+use fc_sort::min_of_non_nan;
+use fc_sort::sum_compensated;
+use fc_sort::sum_compensated_with;
+use fc_sort::NanHandling;
+use fc_sort::TotalFloat;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn cancellation() {
+    // Naive summation loses the 1.0 entirely, compensated summation keeps it
+    let list = tfvec![1e16, 1.0, -1e16];
+    assert_eq!(list.iter().sum::<TotalFloat>(), tf!(0.0));
+    assert_eq!(sum_compensated(&list), tf!(1.0));
+}
+
+#[test]
+fn many_small_values() {
+    // A million tenths sum to a hundred thousand
+    let list = vec![tf!(0.1); 1_000_000];
+    assert_eq!(sum_compensated(&list), tf!(100_000.0));
+    assert_ne!(list.iter().sum::<TotalFloat>(), tf!(100_000.0));
+
+    // A sorted list fares no worse
+    let list = merge_sort(list);
+    assert_eq!(sum_compensated(&list), tf!(100_000.0));
+}
+
+#[test]
+fn empty_and_special() {
+    // An empty sum is zero, and an empty mean is None
+    assert_eq!(sum_compensated(&[]), tf!(0.0));
+    assert_eq!(mean(&[]), None);
+
+    // Infinities behave as they would for plain summation
+    assert_eq!(sum_compensated(&tfvec![1.0, INF, 2.0]), tf!(INF));
+    assert_eq!(sum_compensated(&tfvec![N_INF, 1.0]), tf!(N_INF));
+    assert!(sum_compensated(&tfvec![INF, N_INF]).is_nan());
+}
+
+#[test]
+fn nan_handling() {
+    // NaN propagates by default, and skipping reports the count
+    let list = tfvec![1.0, NAN, 2.0, NAN, 3.0];
+    assert!(sum_compensated(&list).is_nan());
+    assert_eq!(sum_compensated_with(&list, NanHandling::Propagate).1, 0);
+    assert_eq!(sum_compensated_with(&list, NanHandling::Ignore),
+               (tf!(6.0), 2));
+
+    // The mean only counts what was not skipped
+    assert!(mean(&list).unwrap().is_nan());
+    assert_eq!(mean_with(&list, NanHandling::Ignore), (Some(tf!(2.0)), 2));
+    assert_eq!(mean_with(&tfvec![NAN, NAN], NanHandling::Ignore), (None, 2));
+}
+
+#[test]
+fn mean_values() {
+    // The mean also benefits from compensation
+    assert_eq!(mean(&tfvec![1.0, 2.0, 3.0, 4.0]), Some(tf!(2.5)));
+    assert_eq!(mean(&tfvec![1e16, 1.0, -1e16, 3.0]), Some(tf!(1.0)));
+}
+
+#[test]
+fn extrema_of_slices() {
+    // Empty, singleton and mixed slices
+    assert_eq!(extrema(&[]), None);
+    assert_eq!(min_of(&[]), None);
+    assert_eq!(max_of(&[]), None);
+    assert_eq!(extrema(&tfvec![2.0]), Some((tf!(2.0), tf!(2.0))));
+    let list = tfvec![3.0, N_INF, 7.5, -0.0, INF, 1.0, 0.0];
+    assert_eq!(extrema(&list), Some((tf!(N_INF), tf!(INF))));
+    assert_eq!(min_of(&list), Some(tf!(N_INF)));
+    assert_eq!(max_of(&list), Some(tf!(INF)));
+
+    // Agrees with min and max for every length up to a few pairs
+    let list = tfvec![5.0, -2.0, 8.0, 0.5, -9.0, 4.0, 7.0];
+    for n in 1..list.len() + 1 {
+        let slice = &list[..n];
+        assert_eq!(extrema(slice), Some((min_of(slice).unwrap(),
+                                         max_of(slice).unwrap())));
+    }
+}
+
+#[test]
+fn extrema_ties() {
+    // The first least and the last greatest of equal values are returned
+    let list = tfvec![0.0, -0.0, 1.0, -0.0, 0.0];
+    let (min, max) = extrema(&list).unwrap();
+    assert_eq!(min.to_bits(), 0.0f64.to_bits());
+    assert_eq!(max.to_bits(), 1.0f64.to_bits());
+    let (min, max) = extrema(&tfvec![-0.0, 0.0, -0.0, 0.0]).unwrap();
+    assert_eq!(min.to_bits(), (-0.0f64).to_bits());
+    assert_eq!(max.to_bits(), 0.0f64.to_bits());
+}
+
+#[test]
+fn extrema_with_nans() {
+    // NaN is least, unless NaNs are skipped
+    let list = tfvec![NAN, 1.0, -3.0];
+    assert!(min_of(&list).unwrap().is_nan());
+    assert_eq!(max_of(&list), Some(tf!(1.0)));
+    assert!(extrema(&list).unwrap().0.is_nan());
+    assert_eq!(min_of_non_nan(&list), Some(tf!(-3.0)));
+    assert_eq!(max_of_non_nan(&list), Some(tf!(1.0)));
+    assert_eq!(extrema_non_nan(&list), Some((tf!(-3.0), tf!(1.0))));
+    assert_eq!(extrema_non_nan(&tfvec![NAN, 1.0]), Some((tf!(1.0), tf!(1.0))));
+
+    // All NaN
+    let list = tfvec![NAN, NAN];
+    assert!(max_of(&list).unwrap().is_nan());
+    assert_eq!(min_of_non_nan(&list), None);
+    assert_eq!(max_of_non_nan(&list), None);
+    assert_eq!(extrema_non_nan(&list), None);
+}
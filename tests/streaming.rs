@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort;
+use fc_sort::StreamingSorter;
+use fc_sort::TotalFloat;
+use rand::random;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+const NAN: f64 = f64::NAN;
+
+fn bits<I: IntoIterator<Item = TotalFloat>>(list: I) -> Vec<u64> {
+    list.into_iter().map(|x| x.to_bits()).collect()
+}
+
+// A random value, with NaNs and zeros of either sign, and many repeats.
+fn value() -> TotalFloat {
+    match random::<u32>() % 10 {
+        0 => TotalFloat::from_bits(NAN.to_bits() | random::<u32>() as u64),
+        1 => tf!(-0.0),
+        2 => tf!(0.0),
+        x => tf!((x % 5) as f64 - random::<u32>() as f64 % 3.0),
+    }
+}
+
+#[test]
+fn snapshots() {
+    // Interleaved pushes and batches, with a snapshot after each
+    let mut sorter = StreamingSorter::new();
+    let mut pushed = Vec::new();
+    for round in 0..200 {
+        if round % 3 == 0 {
+            let len = random::<u32>() % 100;
+            let batch: Vec<_> = (0..len).map(|_| value()).collect();
+            sorter.push_batch(&batch);
+            pushed.extend(batch);
+        } else {
+            let x = value();
+            sorter.push(x);
+            pushed.push(x);
+        }
+        assert_eq!(sorter.len(), pushed.len());
+        assert_eq!(bits(sorter.iter_sorted()),
+                   bits(merge_sort(pushed.clone())));
+    }
+    assert_eq!(bits(sorter.into_sorted_vec()), bits(merge_sort(pushed)));
+}
+
+#[test]
+fn single_pushes() {
+    // Values pushed one at a time, past the point they become a run
+    let mut sorter = StreamingSorter::new();
+    assert!(sorter.is_empty());
+    assert_eq!(sorter.iter_sorted().next(), None);
+    let pushed: Vec<_> = (0..1000).map(|_| value()).collect();
+    for x in &pushed {
+        sorter.push(*x);
+    }
+    assert_eq!(bits(sorter.iter_sorted()), bits(merge_sort(pushed.clone())));
+    assert_eq!(bits(sorter.into_sorted_vec()), bits(merge_sort(pushed)));
+    assert_eq!(StreamingSorter::new().into_sorted_vec(), tfvec![]);
+}
+
+#[test]
+fn equal_however_pushed() {
+    // The same values in different batches are equal, and hash alike
+    let hash = |sorter: &StreamingSorter| {
+        let mut hasher = DefaultHasher::new();
+        sorter.hash(&mut hasher);
+        hasher.finish()
+    };
+    let values: Vec<_> = (0..100).map(|_| value()).collect();
+    let mut batched = StreamingSorter::new();
+    batched.push_batch(&values[..60]);
+    batched.push_batch(&values[60..]);
+    let mut single = StreamingSorter::new();
+    for x in values.iter().rev() {
+        single.push(*x);
+    }
+    assert_eq!(batched, single);
+    assert_eq!(hash(&batched), hash(&single));
+    single.push(tf!(0.5));
+    assert_ne!(batched, single);
+}
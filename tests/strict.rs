@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::StrictTotalFloat;
+use fc_sort::TotalFloat;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn strict_cmp_refines_cmp() {
+    // Values unequal under cmp are ordered the same way by strict_cmp
+    let values = tfvec![NAN, N_INF, -1.0, -0.0, 0.0, 1.0, INF];
+    for a in &values {
+        for b in &values {
+            if a.cmp(b) != Ordering::Equal {
+                assert_eq!(a.strict_cmp(b), a.cmp(b));
+            }
+        }
+    }
+
+    // Ties are broken, with -0.0 before +0.0
+    assert_eq!(tf!(-0.0).strict_cmp(&tf!(0.0)), Ordering::Less);
+    assert_eq!(tf!(0.0).strict_cmp(&tf!(-0.0)), Ordering::Greater);
+    let nan1 = TotalFloat::from_bits(0x7ff8000000000001);
+    assert_ne!(nan1.strict_cmp(&tf!(NAN)), Ordering::Equal);
+    assert_eq!(nan1.strict_cmp(&nan1), Ordering::Equal);
+
+    // NaNs remain least
+    assert_eq!(nan1.strict_cmp(&tf!(N_INF)), Ordering::Less);
+}
+
+#[test]
+fn zeros_as_map_keys() {
+    // In strict mode both zeros are distinct keys, and keep their signs
+    let mut strict = BTreeMap::new();
+    strict.insert(StrictTotalFloat::new(0.0), "positive");
+    strict.insert(StrictTotalFloat::new(-0.0), "negative");
+    assert_eq!(strict.len(), 2);
+    let keys: Vec<_> = strict.keys().map(|k| k.to_bits()).collect();
+    assert_eq!(keys, [(-0.0f64).to_bits(), 0.0f64.to_bits()]);
+
+    // In the default mode they are merged
+    let mut merged = BTreeMap::new();
+    merged.insert(tf!(0.0), "positive");
+    merged.insert(tf!(-0.0), "negative");
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[&tf!(0.0)], "negative");
+}
+
+#[test]
+fn nan_payloads_as_keys() {
+    // Distinct NaN payloads are distinct strict keys, but one default key
+    let nans = [NAN.to_bits(), 0x7ff8000000000001, 0xfff8000000000000];
+    let strict: HashSet<_> = nans.iter()
+        .map(|&b| StrictTotalFloat::from(TotalFloat::from_bits(b)))
+        .collect();
+    assert_eq!(strict.len(), 3);
+    let merged: HashSet<_> = nans.iter()
+        .map(|&b| TotalFloat::from_bits(b))
+        .collect();
+    assert_eq!(merged.len(), 1);
+}
+
+#[test]
+fn hash_consistent_with_eq() {
+    // Equal strict values hash the same, and both zeros stay apart
+    let set: HashSet<_> = [0.0, -0.0, 0.0, 1.0, -0.0]
+        .iter()
+        .map(|&x| StrictTotalFloat::new(x))
+        .collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&StrictTotalFloat::new(-0.0)));
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "std")]
+
+extern crate fc_sort;
+extern crate rand;
+
+use std::num::NonZeroUsize;
+
+use fc_sort::merge_sort;
+use fc_sort::merge_sort_threads;
+use fc_sort::unwrap_vec;
+use fc_sort::wrap_vec;
+use rand::random;
+
+const NAN: f64 = f64::NAN;
+
+// Random values, about `nans` in 4 of which are NaNs with differing payloads,
+// so that any instability shows up in the bits.
+fn random_list(n: usize, nans: u32) -> Vec<f64> {
+    (0..n)
+        .map(|_| if random::<u32>() % 4 < nans {
+            f64::from_bits(NAN.to_bits() | (random::<u64>() % 1024))
+        } else if random::<bool>() {
+            (random::<u32>() % 10) as f64
+        } else {
+            random::<f64>()
+        })
+        .collect()
+}
+
+fn bits(list: Vec<f64>) -> Vec<u64> {
+    list.into_iter().map(f64::to_bits).collect()
+}
+
+// Checks that sorting on the given number of threads matches merge_sort.
+fn check(list: Vec<f64>, threads: usize) {
+    let list = wrap_vec(list);
+    let expected = bits(unwrap_vec(merge_sort(list.clone())));
+    let threads = NonZeroUsize::new(threads).unwrap();
+    assert_eq!(bits(unwrap_vec(merge_sort_threads(list, threads))), expected);
+}
+
+#[test]
+fn matches_sequential() {
+    // Including thread counts that do not evenly divide the length
+    for &threads in &[1, 2, 3, 5, 16] {
+        for &n in &[0, 1, 2, 100, 1001, 10_000] {
+            check(random_list(n, 1), threads);
+        }
+    }
+}
+
+#[test]
+fn fewer_values_than_threads() {
+    // Every length below the thread count
+    for &threads in &[2, 3, 16] {
+        for n in 0..threads {
+            check(random_list(n, 1), threads);
+        }
+    }
+}
+
+#[test]
+fn nan_heavy() {
+    // Mostly and entirely NaN inputs keep the order of their payloads
+    for &threads in &[1, 2, 3, 16] {
+        check(random_list(5000, 3), threads);
+        check(random_list(5000, 4), threads);
+    }
+}
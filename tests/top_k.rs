@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::merge_sort;
+use fc_sort::top_k_largest;
+use fc_sort::top_k_smallest;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+#[test]
+fn largest_and_smallest() {
+    // The results come in descending and ascending order respectively
+    let data = tfvec![4.0, -1.0, 9.0, NAN, 2.5, INF, 0.0, N_INF, 9.0];
+    assert_eq!(top_k_largest(data.clone(), 3), tfvec![INF, 9.0, 9.0]);
+    assert_eq!(top_k_smallest(data.clone(), 3), tfvec![NAN, N_INF, -1.0]);
+}
+
+#[test]
+fn nans_only_when_needed() {
+    // NaNs only make the largest values when there are too few others
+    let data = tfvec![NAN, 1.0, NAN, 2.0];
+    assert_eq!(top_k_largest(data.clone(), 2), tfvec![2.0, 1.0]);
+    assert_eq!(top_k_largest(data, 3), tfvec![2.0, 1.0, NAN]);
+}
+
+#[test]
+fn k_zero_and_k_too_large() {
+    // k = 0 gives nothing, and a k past the length gives everything
+    let data = tfvec![3.0, 1.0, 2.0];
+    assert_eq!(top_k_largest(data.clone(), 0), tfvec![]);
+    assert_eq!(top_k_smallest(data.clone(), 0), tfvec![]);
+    assert_eq!(top_k_largest(data.clone(), 10), tfvec![3.0, 2.0, 1.0]);
+    assert_eq!(top_k_smallest(data, 10), tfvec![1.0, 2.0, 3.0]);
+    assert_eq!(top_k_smallest(tfvec![], 5), tfvec![]);
+}
+
+#[test]
+fn huge_k() {
+    // Memory is bounded by the input, not by k
+    let data = tfvec![2.0, NAN, 1.0];
+    assert_eq!(top_k_largest(data.clone(), 1 << 40), tfvec![2.0, 1.0, NAN]);
+    assert_eq!(top_k_smallest(data.clone(), 1 << 40), tfvec![NAN, 1.0, 2.0]);
+    assert_eq!(top_k_largest(data.clone(), usize::MAX),
+               tfvec![2.0, 1.0, NAN]);
+    assert_eq!(top_k_smallest(data, usize::MAX), tfvec![NAN, 1.0, 2.0]);
+}
+
+#[test]
+fn agrees_with_sorting() {
+    // A longer input, against a full sort
+    let data: Vec<_> = (0..10_000).map(|i| tf!((i * 7919 % 10_007) as f64))
+        .collect();
+    let sorted = merge_sort(data.clone());
+    assert_eq!(top_k_smallest(data.iter().cloned(), 100), sorted[..100]);
+    let mut largest = sorted[sorted.len() - 100..].to_vec();
+    largest.reverse();
+    assert_eq!(top_k_largest(data, 100), largest);
+}
@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate fc_sort;
+
+use fc_sort::TotalFloat;
+use fc_sort::TotalVec;
+
+const INF: f64 = f64::INFINITY;
+const N_INF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+// Sorts the values through the container API.
+fn sorted(input: Vec<f64>) -> Vec<TotalFloat> {
+    let mut list = TotalVec::from(input);
+    list.sort();
+    assert!(list.is_sorted());
+    list.into_inner()
+}
+
+#[test]
+fn sort_scenarios() {
+    // The scenarios of the free function, run through TotalVec
+    assert_eq!(sorted(vec![]), tfvec![]);
+    assert_eq!(sorted(vec![1.0]), tfvec![1.0]);
+    assert_eq!(sorted(vec![2.0, 1.0]), tfvec![1.0, 2.0]);
+    assert_eq!(sorted(vec![3.0, 2.0, 5.3, 6.1, 8.4, 1.0]),
+               tfvec![1.0, 2.0, 3.0, 5.3, 6.1, 8.4]);
+    assert_eq!(sorted(vec![3.4, 1.2, 8.4, 3.4, 2.3]),
+               tfvec![1.2, 2.3, 3.4, 3.4, 8.4]);
+    assert_eq!(sorted(vec![INF, 4.4, 2.1, N_INF, 5.2, INF]),
+               tfvec![N_INF, 2.1, 4.4, 5.2, INF, INF]);
+    assert_eq!(sorted(vec![2.3, -9.0, -0.0, 4.2, 0.0, 9.1]),
+               tfvec![-9.0, -0.0, 0.0, 2.3, 4.2, 9.1]);
+    assert_eq!(sorted(vec![2.3, NAN, -9.0, NAN, 4.2]),
+               tfvec![NAN, NAN, -9.0, 2.3, 4.2]);
+}
+
+#[test]
+fn collect_and_push() {
+    // Collect from both f64 and TotalFloat iterators, then push either
+    let mut list: TotalVec = vec![3.0, 1.0].into_iter().collect();
+    list.push(2.0);
+    list.push(tf!(NAN));
+    assert!(!list.is_sorted());
+    assert_eq!(list.len(), 4);
+    list.sort();
+    assert_eq!(*list, *tfvec![NAN, 1.0, 2.0, 3.0]);
+
+    let again: TotalVec = list.iter().cloned().collect();
+    assert_eq!(again, list);
+}
+
+#[test]
+fn queries() {
+    // min and max follow the total order, and binary_search finds values
+    let mut list = TotalVec::from(vec![4.0, -1.0, NAN, 0.0, 9.5]);
+    assert!(list.min().unwrap().is_nan());
+    assert_eq!(list.max(), Some(tf!(9.5)));
+    list.sort();
+    assert_eq!(list.binary_search(0.0), Ok(2));
+    assert_eq!(list.binary_search(-0.0), Ok(2));
+    assert_eq!(list.binary_search(NAN), Ok(0));
+    assert_eq!(list.binary_search(5.0), Err(4));
+
+    // An empty list has no extrema, and is sorted
+    let empty = TotalVec::new();
+    assert_eq!(empty.min(), None);
+    assert_eq!(empty.max(), None);
+    assert!(empty.is_sorted());
+}
@@ -3,11 +3,12 @@ extern crate fc_sort;
 extern crate rand;
 
 use fc_sort::merge_sort;
+use fc_sort::TotalFloat;
 use rand::random;
 
-use std::f64::INFINITY as INF;
-use std::f64::NEG_INFINITY as N_INF;
-use std::f64::NAN;
+const INF: TotalFloat = TotalFloat::INFINITY;
+const N_INF: TotalFloat = TotalFloat::NEG_INFINITY;
+const NAN: TotalFloat = TotalFloat::NAN;
 
 #[test]
 fn empty_list() {
@@ -81,17 +82,15 @@ fn nan_with_different_mantissa() {
     // The program was specified to treat NaN == NaN, therefore all NaN values
     // will compare the same regardless of mantissa.
 
-    // Construct both as integers from a bitpattern For reference, a standard
-    // (0.0 / 0.0) NaN would be 0x7ff8000000000000.
-    let nan1: u64 = 0x7ff800a004001000;
-    let nan2: u64 = 0x7ff80090e200a000;
+    // Construct both from a bitpattern. For reference, a standard (0.0 / 0.0)
+    // NaN would be 0x7ff8000000000000.
+    let nan1 = TotalFloat::from_bits(0x7ff800a004001000);
+    let nan2 = TotalFloat::from_bits(0x7ff80090e200a000);
 
-    // Perform a pointer cast to turn both into floats
-    let nan1: f64 = unsafe { *(&nan1 as *const u64 as *const f64) };
-    let nan2: f64 = unsafe { *(&nan2 as *const u64 as *const f64) };
-
-    // Verify that as TotalFloats the NaNs compare equal
-    assert_eq!(tf!(nan1), tf!(nan2));
+    // Verify the bit patterns are preserved, but that the NaNs compare equal
+    assert_eq!(nan1.to_bits(), 0x7ff800a004001000);
+    assert_eq!(nan2.to_bits(), 0x7ff80090e200a000);
+    assert_eq!(nan1, nan2);
 
     // Demonstrate usage in sort, NAN is used in comparison because as
     // demonstrated above, both will compare equal to any NAN value, so the
@@ -99,3 +98,27 @@ fn nan_with_different_mantissa() {
     assert_eq!(merge_sort(tfvec![2.0, -4.2, INF, 2.1, nan1, 3.2, nan2]),
                tfvec![NAN, NAN, -4.2, 2.0, 2.1, 3.2, INF]);
 }
+
+#[test]
+fn special_constants_ordering() {
+    // The crate's ordering of the special values, from least to greatest
+    assert!(TotalFloat::NAN < TotalFloat::NEG_INFINITY);
+    assert!(TotalFloat::NEG_INFINITY < TotalFloat::MIN);
+    assert!(TotalFloat::MIN < TotalFloat::NEG_ZERO);
+    assert!(TotalFloat::ZERO < TotalFloat::MIN_POSITIVE);
+    assert!(TotalFloat::MIN_POSITIVE < TotalFloat::EPSILON);
+    assert!(TotalFloat::EPSILON < TotalFloat::MAX);
+    assert!(TotalFloat::MAX < TotalFloat::INFINITY);
+
+    // The two zeros are equal, as are all NaNs
+    assert_eq!(TotalFloat::NEG_ZERO, TotalFloat::ZERO);
+    assert_eq!(TotalFloat::NAN, tf!(-f64::NAN));
+
+    // Sorting the constants gives the same order
+    assert_eq!(merge_sort(vec![TotalFloat::MAX,
+                               TotalFloat::INFINITY,
+                               TotalFloat::ZERO,
+                               TotalFloat::NAN,
+                               TotalFloat::NEG_INFINITY]),
+               tfvec![NAN, N_INF, 0.0, f64::MAX, INF]);
+}
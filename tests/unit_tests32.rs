@@ -0,0 +1,152 @@
+#[macro_use]
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_sort32;
+use fc_sort::TotalFloat;
+use fc_sort::TotalFloat32;
+use rand::random;
+
+const INF: f32 = f32::INFINITY;
+const N_INF: f32 = f32::NEG_INFINITY;
+const NAN: f32 = f32::NAN;
+
+#[test]
+fn empty_list() {
+    // Sort an empty lists
+    assert_eq!(merge_sort32(tf32vec![]), tf32vec![]);
+}
+
+#[test]
+fn one_element_list() {
+    // Sort a single element list
+    assert_eq!(merge_sort32(tf32vec![1.0]), tf32vec![1.0]);
+}
+
+#[test]
+fn two_element_list() {
+    // Sort a two element list
+    assert_eq!(merge_sort32(tf32vec![2.0, 1.0]), tf32vec![1.0, 2.0]);
+}
+
+#[test]
+fn general_element_list() {
+    // Sort a list with an arbitrary number of elemets (here 6)
+    assert_eq!(merge_sort32(tf32vec![3.0, 2.0, 5.3, 6.1, 8.4, 1.0]),
+               tf32vec![1.0, 2.0, 3.0, 5.3, 6.1, 8.4]);
+}
+
+#[test]
+fn some_elements_same() {
+    // Sort a list where some of the elements are the same (here 3.4)
+    assert_eq!(merge_sort32(tf32vec![3.4, 1.2, 8.4, 3.4, 2.3]),
+               tf32vec![1.2, 2.3, 3.4, 3.4, 8.4])
+}
+
+#[test]
+fn some_elements_infinity() {
+    // Sort a list where some elements are infinity or negative infinity
+    assert_eq!(merge_sort32(tf32vec![INF, 4.4, 2.1, N_INF, 5.2, INF]),
+               tf32vec![N_INF, 2.1, 4.4, 5.2, INF, INF]);
+}
+
+#[test]
+fn some_elements_minus_zero() {
+    // Sort a list where some elements are negative zero
+    assert_eq!(merge_sort32(tf32vec![2.3, -9.0, -0.0, 4.2, 0.0, 9.1]),
+               tf32vec![-9.0, -0.0, 0.0, 2.3, 4.2, 9.1]);
+}
+
+#[test]
+fn some_elements_nan() {
+    // Sort a list where some elements are NAN
+    assert_eq!(merge_sort32(tf32vec![3.4, 1.2, 8.4, NAN, 2.3, N_INF]),
+               tf32vec![NAN, N_INF, 1.2, 2.3, 3.4, 8.4]);
+}
+
+#[test]
+fn pseudo_random() {
+    // Generate a random 100 element list (highly unlikely to be presorted).
+    let list = (0..100).map(|_| tf32!(random::<f32>())).collect();
+
+    // Sort the list (moves out data, so we shadow the old variable name).
+    let list = merge_sort32(list);
+
+    // Check the list is sorted by comparing each element to the following
+    // element, and checking that they all compare lesser or equal.
+    assert!(list.iter().zip(list.iter().skip(1)).all(|(a, b)| a <= b))
+}
+
+#[test]
+fn nan_with_different_mantissa() {
+    // All NaN values compare the same regardless of mantissa. For reference,
+    // a standard (0.0 / 0.0) f32 NaN would be 0x7fc00000.
+    let nan1 = TotalFloat32::from_bits(0x7fc0a001);
+    let nan2 = TotalFloat32::from_bits(0xffe00090);
+
+    // Verify the bit patterns are preserved, but that the NaNs compare equal
+    assert_eq!(nan1.to_bits(), 0x7fc0a001);
+    assert_eq!(nan2.to_bits(), 0xffe00090);
+    assert_eq!(nan1, nan2);
+
+    // Demonstrate usage in sort
+    assert_eq!(merge_sort32(vec![tf32!(2.0), nan1, tf32!(-4.2), nan2]),
+               tf32vec![NAN, NAN, -4.2, 2.0]);
+}
+
+#[test]
+fn lossless_widening() {
+    // Every f32, including the specials, widens to an equal TotalFloat
+    for &x in &[1.5f32, -0.0, 3.4e38, 1e-45, INF, N_INF, NAN] {
+        let wide = TotalFloat::from(tf32!(x));
+        assert_eq!(wide, tf!(f64::from(x)));
+        assert_eq!(wide.is_sign_negative(), x.is_sign_negative());
+    }
+}
+
+#[test]
+fn formatting() {
+    // Formatting is as if for f32
+    assert_eq!(format!("{}", tf32!(0.1)), "0.1");
+    assert_eq!(format!("{:?}", tf32!(NAN)), "NaN");
+    assert_eq!(format!("{:.2}", tf32!(1.005)), format!("{:.2}", 1.005f32));
+}
+
+#[test]
+fn arithmetic() {
+    // Operators work as for f32, staying in the wrapper
+    let mut x = tf32!(1.5) + tf32!(2.0) * 3.0f32;
+    assert_eq!(x, tf32!(7.5));
+    x -= 0.5f32;
+    x /= tf32!(2.0);
+    assert_eq!(x, tf32!(3.5));
+    assert_eq!(tf32!(7.0) % 4.0f32, tf32!(3.0));
+    assert_eq!((-tf32!(0.0)).to_bits(), (-0.0f32).to_bits());
+    assert!((TotalFloat32::INFINITY - TotalFloat32::INFINITY).is_nan());
+    let values = [tf32!(1.0), tf32!(2.0), tf32!(4.0)];
+    assert_eq!(values.iter().sum::<TotalFloat32>(), tf32!(7.0));
+    assert_eq!(values.iter().cloned().product::<TotalFloat32>(), tf32!(8.0));
+}
+
+#[test]
+fn constants_and_parsing() {
+    // The f32 constants, and the special spellings TotalFloat accepts
+    assert_eq!(TotalFloat32::MAX.inner, f32::MAX);
+    assert_eq!(TotalFloat32::MIN.inner, f32::MIN);
+    assert_eq!(TotalFloat32::EPSILON.inner, f32::EPSILON);
+    assert_eq!(TotalFloat32::NEG_ZERO, TotalFloat32::ZERO);
+    assert_eq!("0.1".parse::<TotalFloat32>(), Ok(tf32!(0.1)));
+    assert_eq!("-Infinity".parse::<TotalFloat32>(), Ok(tf32!(N_INF)));
+    assert!("NaN".parse::<TotalFloat32>().unwrap().is_nan());
+    assert!("one".parse::<TotalFloat32>().is_err());
+}
+
+#[test]
+fn reinterpreting() {
+    // References and slices of f32 are viewed in place
+    let mut floats = [2.0f32, NAN, 1.0];
+    assert_eq!(*TotalFloat32::from_ref(&floats[0]), tf32!(2.0));
+    *TotalFloat32::from_mut(&mut floats[2]) += 1.0f32;
+    assert_eq!(TotalFloat32::slice_from(&floats),
+               [tf32!(2.0), tf32!(NAN), tf32!(2.0)]);
+}
@@ -0,0 +1,217 @@
+extern crate fc_sort;
+extern crate rand;
+
+use fc_sort::merge_by;
+use fc_sort::merge_sort_by;
+use fc_sort::merge_sort_by_cached_key;
+use fc_sort::merge_sort_by_key;
+use fc_sort::merge_sort_in_place;
+use fc_sort::merge_sort_with_buffer;
+use rand::random;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+thread_local! {
+    // The ids of the values dropped on this thread, in order.
+    static DROPPED: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    // The number of comparisons allowed before the next one panics.
+    static CALLS_LEFT: Cell<usize> = const { Cell::new(usize::MAX) };
+}
+
+// A boxed value that records its id when dropped, so that leaks and double
+// drops can be told apart, and whose comparisons panic once none are left.
+#[derive(Debug)]
+struct Tracked {
+    id: usize,
+    value: Box<i32>,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPPED.with(|dropped| dropped.borrow_mut().push(self.id));
+    }
+}
+
+impl Ord for Tracked {
+    fn cmp(&self, other: &Tracked) -> Ordering {
+        count_call();
+        self.value.cmp(&other.value)
+    }
+}
+
+impl PartialOrd for Tracked {
+    fn partial_cmp(&self, other: &Tracked) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Tracked) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Tracked {}
+
+fn count_call() {
+    CALLS_LEFT.with(|left| {
+        if left.get() == 0 {
+            panic!("comparison limit reached");
+        }
+        left.set(left.get() - 1);
+    });
+}
+
+// Values with runs long enough to be merged and galloped over, and a shuffled
+// tail with duplicates.
+fn values() -> Vec<i32> {
+    (0..48)
+        .chain(0..48)
+        .chain((0..40).map(|_| (random::<u32>() % 20) as i32))
+        .collect()
+}
+
+fn tracked(values: &[i32]) -> Vec<Tracked> {
+    values.iter()
+        .enumerate()
+        .map(|(id, &x)| Tracked { id, value: Box::new(x) })
+        .collect()
+}
+
+fn ids(list: &[Tracked]) -> Vec<usize> {
+    let mut ids = list.iter().map(|x| x.id).collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+// The ids dropped since last asked, sorted.
+fn take_dropped() -> Vec<usize> {
+    let mut dropped = DROPPED.with(|dropped| dropped.replace(Vec::new()));
+    dropped.sort();
+    dropped
+}
+
+// Runs f with no limit on comparisons, returning how many it made.
+fn count_calls<F: FnOnce()>(f: F) -> usize {
+    CALLS_LEFT.with(|left| left.set(usize::MAX));
+    f();
+    usize::MAX - CALLS_LEFT.with(Cell::get)
+}
+
+// Runs f once for each comparison the sort makes, with that comparison
+// panicking, checking that each value is dropped exactly once.
+fn panic_at_each_call<F>(calls: usize, n: usize, f: F)
+    where F: Fn()
+{
+    for k in 0..calls {
+        take_dropped();
+        CALLS_LEFT.with(|left| left.set(k));
+        assert!(panic::catch_unwind(AssertUnwindSafe(&f)).is_err());
+        assert_eq!(take_dropped(), (0..n).collect::<Vec<_>>());
+    }
+    CALLS_LEFT.with(|left| left.set(usize::MAX));
+}
+
+#[test]
+fn sort_by() {
+    // The list and any half-merged scratch values are each dropped once
+    let values = values();
+    let calls = count_calls(|| {
+        drop(merge_sort_by(tracked(&values), |a, b| a.cmp(b)));
+    });
+    panic_at_each_call(calls, values.len(), || {
+        drop(merge_sort_by(tracked(&values), |a, b| a.cmp(b)));
+    });
+}
+
+#[test]
+fn sort_by_key() {
+    // As with a panicking comparator, with a panicking key
+    let values = values();
+    let key = |x: &Tracked| {
+        count_call();
+        *x.value
+    };
+    let calls = count_calls(|| drop(merge_sort_by_key(tracked(&values), key)));
+    panic_at_each_call(calls, values.len(), || {
+        drop(merge_sort_by_key(tracked(&values), key));
+    });
+    let calls = count_calls(|| {
+        drop(merge_sort_by_cached_key(tracked(&values), key));
+    });
+    panic_at_each_call(calls, values.len(), || {
+        drop(merge_sort_by_cached_key(tracked(&values), key));
+    });
+}
+
+#[test]
+fn merge() {
+    // Values taken from either list, and those left in them
+    let mut a = values();
+    let mut b = values();
+    a.sort();
+    b.sort();
+    let both = |a: &[i32], b: &[i32]| {
+        let mut b = tracked(b);
+        for x in &mut b {
+            x.id += a.len();
+        }
+        (tracked(a), b)
+    };
+    let n = a.len() + b.len();
+    let calls = count_calls(|| {
+        let (a, b) = both(&a, &b);
+        drop(merge_by(a, b, |x, y| x.cmp(y)));
+    });
+    panic_at_each_call(calls, n, || {
+        let (a, b) = both(&a, &b);
+        drop(merge_by(a, b, |x, y| x.cmp(y)));
+    });
+}
+
+#[test]
+fn in_place() {
+    // The slice still holds every value, ready to be dropped by its owner
+    let values = values();
+    let n = values.len();
+    let calls = count_calls(|| merge_sort_in_place(&mut tracked(&values)));
+    for k in 0..calls {
+        let mut list = tracked(&values);
+        take_dropped();
+        CALLS_LEFT.with(|left| left.set(k));
+        let sort = AssertUnwindSafe(|| merge_sort_in_place(&mut list));
+        assert!(panic::catch_unwind(sort).is_err());
+        CALLS_LEFT.with(|left| left.set(usize::MAX));
+        assert!(take_dropped().is_empty());
+        assert_eq!(ids(&list), (0..n).collect::<Vec<_>>());
+        drop(list);
+        assert_eq!(take_dropped(), (0..n).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn reused_buffer() {
+    // The scratch buffer never owns the values left in it
+    let values = values();
+    let n = values.len();
+    let mut scratch = Vec::new();
+    let calls = count_calls(|| {
+        merge_sort_with_buffer(&mut tracked(&values), &mut scratch);
+    });
+    for k in 0..calls {
+        let mut list = tracked(&values);
+        take_dropped();
+        CALLS_LEFT.with(|left| left.set(k));
+        let sort = AssertUnwindSafe(|| {
+            merge_sort_with_buffer(&mut list, &mut scratch);
+        });
+        assert!(panic::catch_unwind(sort).is_err());
+        CALLS_LEFT.with(|left| left.set(usize::MAX));
+        assert!(scratch.is_empty());
+        assert_eq!(ids(&list), (0..n).collect::<Vec<_>>());
+    }
+    take_dropped();
+}